@@ -104,6 +104,16 @@ fn bench_planned_f32(b: &mut Bencher, len: usize) {
 #[bench] fn planned32_composite_005472(b: &mut Bencher) { bench_planned_f32(b,  5472); }
 #[bench] fn planned32_composite_030270(b: &mut Bencher) { bench_planned_f32(b,  30270); }
 
+// products of distinct large primes
+#[bench] fn planned32_composite_001517(b: &mut Bencher) { bench_planned_f32(b,  01517); } // 37 * 41
+#[bench] fn planned32_composite_013117(b: &mut Bencher) { bench_planned_f32(b,  13117); } // 13 * 1009
+#[bench] fn planned32_composite_016637(b: &mut Bencher) { bench_planned_f32(b,  16637); } // 127 * 131
+
+// products of primes from 13 to 31
+#[bench] fn planned32_composite_000169(b: &mut Bencher) { bench_planned_f32(b,  00169); } // 13 * 13
+#[bench] fn planned32_composite_002873(b: &mut Bencher) { bench_planned_f32(b,  02873); } // 13 * 13 * 17
+#[bench] fn planned32_composite_173056(b: &mut Bencher) { bench_planned_f32(b,  173056); } // 13 * 13 * 1024
+
 // small mixed composites
 #[bench] fn planned32_composite_000018(b: &mut Bencher) { bench_planned_f32(b,  00018); }
 #[bench] fn planned32_composite_000360(b: &mut Bencher) { bench_planned_f32(b,  00360); }
@@ -178,6 +188,16 @@ fn bench_planned_f64(b: &mut Bencher, len: usize) {
 // small mixed composites times a large prime
 #[bench] fn planned64_composite_030270(b: &mut Bencher) { bench_planned_f64(b,  30270); }
 
+// products of distinct large primes
+#[bench] fn planned64_composite_001517(b: &mut Bencher) { bench_planned_f64(b,  01517); } // 37 * 41
+#[bench] fn planned64_composite_013117(b: &mut Bencher) { bench_planned_f64(b,  13117); } // 13 * 1009
+#[bench] fn planned64_composite_016637(b: &mut Bencher) { bench_planned_f64(b,  16637); } // 127 * 131
+
+// products of primes from 13 to 31
+#[bench] fn planned64_composite_000169(b: &mut Bencher) { bench_planned_f64(b,  00169); } // 13 * 13
+#[bench] fn planned64_composite_002873(b: &mut Bencher) { bench_planned_f64(b,  02873); } // 13 * 13 * 17
+#[bench] fn planned64_composite_173056(b: &mut Bencher) { bench_planned_f64(b,  173056); } // 13 * 13 * 1024
+
 // small mixed composites
 #[bench] fn planned64_composite_000018(b: &mut Bencher) { bench_planned_f64(b,  00018); }
 #[bench] fn planned64_composite_000360(b: &mut Bencher) { bench_planned_f64(b,  00360); }
@@ -209,6 +229,39 @@ fn bench_good_thomas(b: &mut Bencher, width: usize, height: usize) {
 #[bench] fn good_thomas_0256_243(b: &mut Bencher) { bench_good_thomas(b,  256, 243); }
 #[bench] fn good_thomas_2048_3(b: &mut Bencher) { bench_good_thomas(b,  2048, 3); }
 #[bench] fn good_thomas_2048_2187(b: &mut Bencher) { bench_good_thomas(b,  2048, 2187); }
+#[bench] fn good_thomas_0035_44(b: &mut Bencher) { bench_good_thomas(b,  35, 44); }
+#[bench] fn good_thomas_0032_77(b: &mut Bencher) { bench_good_thomas(b,  32, 77); }
+#[bench] fn good_thomas_0077_60(b: &mut Bencher) { bench_good_thomas(b,  77, 60); }
+#[bench] fn good_thomas_0091_132(b: &mut Bencher) { bench_good_thomas(b,  91, 132); }
+// coprime splits found by the planner's search, to compare with the default splits benched with mixed radix below
+#[bench] fn good_thomas_0016_81(b: &mut Bencher) { bench_good_thomas(b,  16, 81); }
+#[bench] fn good_thomas_0036_49(b: &mut Bencher) { bench_good_thomas(b,  36, 49); }
+#[bench] fn good_thomas_0025_144(b: &mut Bencher) { bench_good_thomas(b,  25, 144); }
+#[bench] fn good_thomas_0064_315(b: &mut Bencher) { bench_good_thomas(b,  64, 315); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the Good-Thomas algorithm. The f64 and f32 crossovers with Mixed-Radix are at different sizes
+fn bench_good_thomas_f64(b: &mut Bencher, width: usize, height: usize) {
+
+    let mut planner = rustfft::FftPlanner::new();
+    let width_fft = planner.plan_fft_forward(width);
+    let height_fft = planner.plan_fft_forward(height);
+
+    let fft : Arc<Fft<f64>> = Arc::new(GoodThomasAlgorithm::new(width_fft, height_fft));
+
+    let mut buffer = vec![Complex::zero(); width * height];
+    let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+    b.iter(|| {fft.process_with_scratch(&mut buffer, &mut scratch);} );
+}
+
+#[bench] fn good_thomas64_0035_44(b: &mut Bencher) { bench_good_thomas_f64(b,  35, 44); }
+#[bench] fn good_thomas64_0032_77(b: &mut Bencher) { bench_good_thomas_f64(b,  32, 77); }
+#[bench] fn good_thomas64_0077_60(b: &mut Bencher) { bench_good_thomas_f64(b,  77, 60); }
+#[bench] fn good_thomas64_0091_132(b: &mut Bencher) { bench_good_thomas_f64(b,  91, 132); }
+#[bench] fn good_thomas64_0016_81(b: &mut Bencher) { bench_good_thomas_f64(b,  16, 81); }
+#[bench] fn good_thomas64_0036_49(b: &mut Bencher) { bench_good_thomas_f64(b,  36, 49); }
+#[bench] fn good_thomas64_0025_144(b: &mut Bencher) { bench_good_thomas_f64(b,  25, 144); }
+#[bench] fn good_thomas64_0064_315(b: &mut Bencher) { bench_good_thomas_f64(b,  64, 315); }
 
 /// Times just the FFT setup (not execution)
 /// for a given length, specific to the Good-Thomas algorithm
@@ -256,6 +309,38 @@ fn bench_mixed_radix(b: &mut Bencher, width: usize, height: usize) {
 #[bench] fn mixed_radix_0256_243(b: &mut Bencher) { bench_mixed_radix(b,  256, 243); }
 #[bench] fn mixed_radix_2048_3(b: &mut Bencher) { bench_mixed_radix(b,  2048, 3); }
 #[bench] fn mixed_radix_2048_2187(b: &mut Bencher) { bench_mixed_radix(b,  2048, 2187); }
+#[bench] fn mixed_radix_0035_44(b: &mut Bencher) { bench_mixed_radix(b,  35, 44); }
+#[bench] fn mixed_radix_0032_77(b: &mut Bencher) { bench_mixed_radix(b,  32, 77); }
+#[bench] fn mixed_radix_0077_60(b: &mut Bencher) { bench_mixed_radix(b,  77, 60); }
+#[bench] fn mixed_radix_0091_132(b: &mut Bencher) { bench_mixed_radix(b,  91, 132); }
+#[bench] fn mixed_radix_0036_36(b: &mut Bencher) { bench_mixed_radix(b,  36, 36); }
+#[bench] fn mixed_radix_0042_42(b: &mut Bencher) { bench_mixed_radix(b,  42, 42); }
+#[bench] fn mixed_radix_0060_60(b: &mut Bencher) { bench_mixed_radix(b,  60, 60); }
+#[bench] fn mixed_radix_0140_144(b: &mut Bencher) { bench_mixed_radix(b,  140, 144); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the Mixed-Radix algorithm
+fn bench_mixed_radix_f64(b: &mut Bencher, width: usize, height: usize) {
+
+    let mut planner = rustfft::FftPlanner::new();
+    let width_fft = planner.plan_fft_forward(width);
+    let height_fft = planner.plan_fft_forward(height);
+
+    let fft : Arc<Fft<_>> = Arc::new(MixedRadix::new(width_fft, height_fft));
+
+    let mut buffer = vec![Complex{re: 0_f64, im: 0_f64}; fft.len()];
+    let mut scratch = vec![Complex{re: 0_f64, im: 0_f64}; fft.get_inplace_scratch_len()];
+    b.iter(|| {fft.process_with_scratch(&mut buffer, &mut scratch);} );
+}
+
+#[bench] fn mixed_radix64_0035_44(b: &mut Bencher) { bench_mixed_radix_f64(b,  35, 44); }
+#[bench] fn mixed_radix64_0032_77(b: &mut Bencher) { bench_mixed_radix_f64(b,  32, 77); }
+#[bench] fn mixed_radix64_0077_60(b: &mut Bencher) { bench_mixed_radix_f64(b,  77, 60); }
+#[bench] fn mixed_radix64_0091_132(b: &mut Bencher) { bench_mixed_radix_f64(b,  91, 132); }
+#[bench] fn mixed_radix64_0036_36(b: &mut Bencher) { bench_mixed_radix_f64(b,  36, 36); }
+#[bench] fn mixed_radix64_0042_42(b: &mut Bencher) { bench_mixed_radix_f64(b,  42, 42); }
+#[bench] fn mixed_radix64_0060_60(b: &mut Bencher) { bench_mixed_radix_f64(b,  60, 60); }
+#[bench] fn mixed_radix64_0140_144(b: &mut Bencher) { bench_mixed_radix_f64(b,  140, 144); }
 
 fn plan_butterfly_fft(len: usize) -> Arc<Fft<f32>> {
     match len {
@@ -387,6 +472,76 @@ fn bench_radix4(b: &mut Bencher, len: usize) {
 #[bench] fn radix4__1048576(b: &mut Bencher) { bench_radix4(b, 1048576); }
 //#[bench] fn radix4_16777216(b: &mut Bencher) { bench_radix4(b, 16777216); }
 
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the radix-8 algorithm
+fn bench_radix8(b: &mut Bencher, len: usize) {
+    assert!(len.is_power_of_two());
+
+    let fft = Radix8::new(len, FftDirection::Forward);
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process_outofplace_with_scratch(&mut signal, &mut spectrum, &mut []);} );
+}
+
+#[bench] fn radix8_______64(b: &mut Bencher) { bench_radix8(b, 64); }
+#[bench] fn radix8______256(b: &mut Bencher) { bench_radix8(b, 256); }
+#[bench] fn radix8_____1024(b: &mut Bencher) { bench_radix8(b, 1024); }
+#[bench] fn radix8____65536(b: &mut Bencher) { bench_radix8(b, 65536); }
+#[bench] fn radix8__1048576(b: &mut Bencher) { bench_radix8(b, 1048576); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the split-radix algorithm
+fn bench_split_radix(b: &mut Bencher, len: usize) {
+    assert!(len.is_power_of_two());
+
+    let fft = SplitRadix::new(len, FftDirection::Forward);
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process_outofplace_with_scratch(&mut signal, &mut spectrum, &mut []);} );
+}
+
+#[bench] fn split_radix_______64(b: &mut Bencher) { bench_split_radix(b, 64); }
+#[bench] fn split_radix______256(b: &mut Bencher) { bench_split_radix(b, 256); }
+#[bench] fn split_radix_____1024(b: &mut Bencher) { bench_split_radix(b, 1024); }
+#[bench] fn split_radix____65536(b: &mut Bencher) { bench_split_radix(b, 65536); }
+#[bench] fn split_radix__1048576(b: &mut Bencher) { bench_split_radix(b, 1048576); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the Stockham algorithm
+fn bench_stockham(b: &mut Bencher, len: usize) {
+    let fft = Stockham::new(len, FftDirection::Forward);
+
+    let mut signal = vec![Complex{re: 0_f32, im: 0_f32}; len];
+    let mut spectrum = signal.clone();
+    b.iter(|| {fft.process_outofplace_with_scratch(&mut signal, &mut spectrum, &mut []);} );
+}
+
+#[bench] fn stockham_____1728(b: &mut Bencher) { bench_stockham(b, 1728); }
+#[bench] fn stockham____13824(b: &mut Bencher) { bench_stockham(b, 13824); }
+#[bench] fn stockham____65536(b: &mut Bencher) { bench_stockham(b, 65536); }
+#[bench] fn stockham___221184(b: &mut Bencher) { bench_stockham(b, 221184); }
+#[bench] fn stockham__1769472(b: &mut Bencher) { bench_stockham(b, 1769472); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, specific to the six-step algorithm
+fn bench_six_step(b: &mut Bencher, width: usize, height: usize) {
+    let mut planner = rustfft::FftPlanner::new();
+    let width_fft = planner.plan_fft_forward(width);
+    let height_fft = planner.plan_fft_forward(height);
+    let fft : Arc<dyn Fft<f32>> = Arc::new(SixStep::new(width_fft, height_fft));
+
+    let mut buffer = vec![Complex::zero(); fft.len()];
+    let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+    b.iter(|| {fft.process_with_scratch(&mut buffer, &mut scratch);} );
+}
+
+#[bench] fn six_step___1048576(b: &mut Bencher) { bench_six_step(b, 1024, 1024); }
+#[bench] fn six_step___4194304(b: &mut Bencher) { bench_six_step(b, 2048, 2048); }
+#[bench] fn six_step___3000000(b: &mut Bencher) { bench_six_step(b, 1500, 2000); }
+#[bench] fn six_step__25000000(b: &mut Bencher) { bench_six_step(b, 5000, 5000); }
+
 fn get_mixed_radix_power2(len: usize) -> Arc<dyn Fft<f32>> {
     match len {
         8 => Arc::new(Butterfly8::new( FftDirection::Forward)),
@@ -523,3 +678,22 @@ fn bench_butterfly64(b: &mut Bencher, len: usize) {
 #[bench] fn butterfly64_128(b: &mut Bencher) { bench_butterfly64(b, 128); }
 #[bench] fn butterfly64_256(b: &mut Bencher) { bench_butterfly64(b, 256); }
 #[bench] fn butterfly64_512(b: &mut Bencher) { bench_butterfly64(b, 512); }
+
+/// Times just the FFT execution (not allocation and pre-calculation)
+/// for a given length, using the scalar planner
+fn bench_butterfly_scalar(b: &mut Bencher, len: usize) {
+
+    let mut planner = rustfft::FftPlannerScalar::new();
+    let fft: Arc<dyn Fft<f32>> = planner.plan_fft_forward(len);
+
+    let mut buffer = vec![Complex::zero(); len * 10];
+    let mut scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+    b.iter(|| { fft.process_with_scratch(&mut buffer, &mut scratch); });
+}
+
+#[bench] fn butterfly_scalar_09(b: &mut Bencher) { bench_butterfly_scalar(b, 9); }
+#[bench] fn butterfly_scalar_13(b: &mut Bencher) { bench_butterfly_scalar(b, 13); }
+#[bench] fn butterfly_scalar_31(b: &mut Bencher) { bench_butterfly_scalar(b, 31); }
+#[bench] fn butterfly_scalar_37(b: &mut Bencher) { bench_butterfly_scalar(b, 37); }
+#[bench] fn butterfly_scalar_47(b: &mut Bencher) { bench_butterfly_scalar(b, 47); }
+#[bench] fn butterfly_scalar_61(b: &mut Bencher) { bench_butterfly_scalar(b, 61); }
@@ -0,0 +1,24 @@
+// Generates the code for butterflies that are too long to write by hand. The generated files are included by `src/algorithm/butterflies.rs`.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+mod prime_butterflies;
+mod winograd;
+
+fn main() {
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+    fs::write(
+        out_dir.join("prime_butterflies.rs"),
+        prime_butterflies::generate_prime_butterflies(),
+    )
+    .unwrap();
+    fs::write(
+        out_dir.join("winograd_butterflies.rs"),
+        winograd::generate_winograd_butterflies(),
+    )
+    .unwrap();
+    println!("cargo:rerun-if-changed=build");
+}
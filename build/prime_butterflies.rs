@@ -0,0 +1,151 @@
+// Generates the scalar butterflies for odd prime sizes.
+//
+// These butterflies compute the DFT directly, in O(n^2) operations, but for short primes this is still faster than fancier algorithms.
+// They're derived in the same manner as the butterflies for length 3, 5 and 7: Pair input i with input len - i, and compute their sum and difference.
+// Output k is then input 0, plus the real part of twiddle (k * i) times the sums, plus or minus the imaginary part of twiddle (k * i) times the differences.
+// The code is O(n^2) lines long, so instead of writing it by hand, we generate it here.
+//
+// To add a butterfly for another prime, add it to PRIME_BUTTERFLIES. The planner won't use it until you also add it to `Recipe` in `src/plan.rs`.
+
+use std::fmt::Write;
+
+const PRIME_BUTTERFLIES: &[usize] = &[11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61];
+
+pub fn generate_prime_butterflies() -> String {
+    let mut code = String::new();
+    for &len in PRIME_BUTTERFLIES {
+        generate_butterfly(&mut code, len);
+    }
+    code
+}
+
+// Twiddle factors past the halfway point are conjugates of the ones before it, so we only store the first half.
+// Returns the index of the stored twiddle to use for `index`, and whether its imaginary part needs to be negated
+fn fold_twiddle_index(index: usize, len: usize) -> (usize, bool) {
+    let index = index % len;
+    if index > len / 2 {
+        (len - index, true)
+    } else {
+        (index, false)
+    }
+}
+
+fn generate_butterfly(code: &mut String, len: usize) {
+    let half_len = len / 2;
+    let name = format!("Butterfly{}", len);
+
+    // The struct stores one twiddle factor for each pair of inputs
+    writeln!(code, "pub struct {}<T> {{", name).unwrap();
+    for n in 1..=half_len {
+        writeln!(code, "    twiddle{}: Complex<T>,", n).unwrap();
+    }
+    writeln!(code, "    direction: FftDirection,").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(
+        code,
+        "boilerplate_fft_butterfly!({0}, {1}, |this: &{0}<_>| this.direction);",
+        name, len
+    )
+    .unwrap();
+
+    writeln!(code, "impl<T: FftNum> {}<T> {{", name).unwrap();
+    writeln!(code, "    pub fn new(direction: FftDirection) -> Self {{").unwrap();
+    writeln!(code, "        Self {{").unwrap();
+    for n in 1..=half_len {
+        writeln!(
+            code,
+            "            twiddle{}: twiddles::compute_twiddle({}, {}, direction),",
+            n, n, len
+        )
+        .unwrap();
+    }
+    writeln!(code, "            direction,").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "    #[inline(never)]").unwrap();
+    writeln!(code, "    unsafe fn perform_fft_contiguous(").unwrap();
+    writeln!(code, "        &self,").unwrap();
+    writeln!(code, "        input: RawSlice<Complex<T>>,").unwrap();
+    writeln!(code, "        output: RawSliceMut<Complex<T>>,").unwrap();
+    writeln!(code, "    ) {{").unwrap();
+
+    // Sums and differences of each pair of inputs. We load every input before storing any outputs, so this works in-place
+    writeln!(code, "        let x0 = input.load(0);").unwrap();
+    for n in 1..=half_len {
+        let m = len - n;
+        writeln!(
+            code,
+            "        let x{0}{1}p = input.load({0}) + input.load({1});",
+            n, m
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        let x{0}{1}n = input.load({0}) - input.load({1});",
+            n, m
+        )
+        .unwrap();
+    }
+    let mut sum = String::from("x0");
+    for n in 1..=half_len {
+        write!(sum, " + x{}{}p", n, len - n).unwrap();
+    }
+    writeln!(code, "        output.store({}, 0);", sum).unwrap();
+
+    // Output n and output len - n are built from the same terms: The "a" terms come from the sums, and the "b" terms come from the differences.
+    // The "b" terms are applied with opposite signs for the two outputs. We store each pair of outputs as soon as we've computed it, to keep as few values alive as possible
+    for n in 1..=half_len {
+        let pair_name = format!("{}{}", n, len - n);
+        for &(part, swapped) in &[("re", "im"), ("im", "re")] {
+            let mut a_terms = format!("x0.{}", part);
+            let mut b_terms = Vec::new();
+            for m in 1..=half_len {
+                let (twiddle, negate) = fold_twiddle_index(m * n, len);
+                write!(
+                    a_terms,
+                    " + self.twiddle{}.re * x{}{}p.{}",
+                    twiddle,
+                    m,
+                    len - m,
+                    part
+                )
+                .unwrap();
+                b_terms.push(format!(
+                    "{}self.twiddle{}.im * x{}{}n.{}",
+                    if negate { "-" } else { "" },
+                    twiddle,
+                    m,
+                    len - m,
+                    swapped
+                ));
+            }
+            writeln!(code, "        let b{}{}_a = {};", pair_name, part, a_terms).unwrap();
+            writeln!(
+                code,
+                "        let b{}{}_b = {};",
+                pair_name,
+                part,
+                b_terms.join(" + ")
+            )
+            .unwrap();
+        }
+        writeln!(
+            code,
+            "        output.store(Complex {{ re: b{0}re_a - b{0}re_b, im: b{0}im_a + b{0}im_b }}, {1});",
+            pair_name, n
+        )
+        .unwrap();
+        writeln!(
+            code,
+            "        output.store(Complex {{ re: b{0}re_a + b{0}re_b, im: b{0}im_a - b{0}im_b }}, {1});",
+            pair_name,
+            len - n
+        )
+        .unwrap();
+    }
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code).unwrap();
+}
@@ -0,0 +1,735 @@
+// Generates butterflies that use Winograd's minimal multiplication DFT algorithms.
+//
+// For a prime length p, pair input n with input p - n like the other butterflies do, and compute their sums u_n and differences v_n.
+// Output k is then input 0, plus the cosine terms sum(u_n * cos(nk)), plus i times the sine terms sum(v_n * sin(nk)).
+// Rader's algorithm reindexes n and k by powers of a primitive root, which turns the cosine terms into a cyclic convolution of length (p - 1) / 2,
+// and the sine terms into a negacyclic convolution of the same length.
+// Winograd computes those convolutions with as few multiplications as possible: Split the convolution modulus into cyclotomic polynomials,
+// convolve modulo each one with Karatsuba's algorithm, and recombine the results with the Chinese remainder theorem.
+// The twiddle factors are constant, so the rational constants from the polynomial arithmetic get folded into them at construction time,
+// leaving additions, and one multiplication by a real constant per emitted "m" variable.
+//
+// Squares of primes work the same way for the inputs and outputs that are coprime to the length. The rest form two DFTs of the prime length.
+
+use std::collections::BTreeMap;
+use std::f64::consts::PI;
+use std::fmt::Write;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+const WINOGRAD_BUTTERFLIES: &[usize] = &[3, 5, 7, 9, 11, 13];
+
+pub fn generate_winograd_butterflies() -> String {
+    let mut code = String::new();
+    for &len in WINOGRAD_BUTTERFLIES {
+        generate_butterfly(&mut code, len);
+    }
+    code
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn divides(divisor: usize, n: usize) -> bool {
+    n / divisor * divisor == n
+}
+
+fn is_prime(n: usize) -> bool {
+    n > 1 && (2..n).take_while(|d| d * d <= n).all(|d| !divides(d, n))
+}
+
+// Returns the smallest number whose powers cover every unit modulo n. These only exist for some n, including odd primes and their powers
+fn primitive_root(n: usize) -> usize {
+    let unit_count = (1..n).filter(|&k| gcd(k as i64, n as i64) == 1).count();
+    (2..n)
+        .find(|&g| {
+            let mut power = g;
+            let mut order = 1;
+            while power != 1 {
+                power = power * g % n;
+                order += 1;
+            }
+            order == unit_count
+        })
+        .unwrap()
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Rational {
+    num: i64,
+    den: i64,
+}
+impl Rational {
+    fn new(num: i64, den: i64) -> Self {
+        let divisor = gcd(num, den) * den.signum();
+        Self {
+            num: num / divisor,
+            den: den / divisor,
+        }
+    }
+    fn int(n: i64) -> Self {
+        Self { num: n, den: 1 }
+    }
+    fn is_zero(self) -> bool {
+        self.num == 0
+    }
+}
+impl Add for Rational {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        Self::new(
+            self.num * other.den + other.num * self.den,
+            self.den * other.den,
+        )
+    }
+}
+impl Sub for Rational {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+impl Mul for Rational {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.num * other.num, self.den * other.den)
+    }
+}
+impl Div for Rational {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        Self::new(self.num * other.den, self.den * other.num)
+    }
+}
+impl Neg for Rational {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            num: -self.num,
+            den: self.den,
+        }
+    }
+}
+
+// A polynomial with rational coefficients, lowest power first. The zero polynomial is empty
+type Poly = Vec<Rational>;
+
+fn trim(poly: &mut Poly) {
+    while let Some(c) = poly.last() {
+        if !c.is_zero() {
+            break;
+        }
+        poly.pop();
+    }
+}
+
+// x^n + constant
+fn binomial(n: usize, constant: i64) -> Poly {
+    let mut poly = vec![Rational::int(0); n + 1];
+    poly[0] = Rational::int(constant);
+    poly[n] = poly[n] + Rational::int(1);
+    poly
+}
+
+fn poly_divmod(dividend: &[Rational], divisor: &[Rational]) -> (Poly, Poly) {
+    let mut remainder = dividend.to_vec();
+    trim(&mut remainder);
+    let mut quotient = vec![Rational::int(0); remainder.len().saturating_sub(divisor.len() - 1)];
+    while remainder.len() >= divisor.len() {
+        let shift = remainder.len() - divisor.len();
+        let factor = *remainder.last().unwrap() / *divisor.last().unwrap();
+        quotient[shift] = factor;
+        for (i, &c) in divisor.iter().enumerate() {
+            remainder[shift + i] = remainder[shift + i] - factor * c;
+        }
+        trim(&mut remainder);
+    }
+    (quotient, remainder)
+}
+
+fn poly_mul(a: &[Rational], b: &[Rational]) -> Poly {
+    let mut product = vec![Rational::int(0); a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j] = product[i + j] + x * y;
+        }
+    }
+    product
+}
+
+// Finds the inverse of `poly` modulo `modulus` with the extended euclidean algorithm
+fn poly_inverse(poly: &[Rational], modulus: &[Rational]) -> Poly {
+    let (_, reduced) = poly_divmod(poly, modulus);
+    let (mut r0, mut r1) = (modulus.to_vec(), reduced);
+    let (mut s0, mut s1) = (Poly::new(), vec![Rational::int(1)]);
+    while !r1.is_empty() {
+        let (quotient, remainder) = poly_divmod(&r0, &r1);
+        let mut s2 = poly_mul(&quotient, &s1);
+        s2.resize(s2.len().max(s0.len()), Rational::int(0));
+        for (i, &c) in s0.iter().enumerate() {
+            s2[i] = c - s2[i];
+        }
+        for c in s2.iter_mut().skip(s0.len()) {
+            *c = -*c;
+        }
+        r0 = r1;
+        r1 = remainder;
+        s0 = s1;
+        s1 = s2;
+    }
+    assert_eq!(r0.len(), 1, "polynomial isn't invertible");
+    let scaled: Poly = s0.iter().map(|&c| c / r0[0]).collect();
+    poly_divmod(&scaled, modulus).1
+}
+
+fn cyclotomic(n: usize) -> Poly {
+    let mut poly = binomial(n, -1);
+    for d in (1..n).filter(|&d| divides(d, n)) {
+        poly = poly_divmod(&poly, &cyclotomic(d)).0;
+    }
+    poly
+}
+
+// Splits a convolution modulus into two coprime factors, or returns None if it's irreducible
+fn split_modulus(modulus: &[Rational]) -> Option<(Poly, Poly)> {
+    let degree = modulus.len() - 1;
+
+    // x^2m - 1 = (x^m - 1)(x^m + 1) is cheaper to reduce by than its cyclotomic factors
+    let half = degree / 2;
+    if half > 0 && half * 2 == degree && modulus == binomial(degree, -1).as_slice() {
+        return Some((binomial(half, -1), binomial(half, 1)));
+    }
+
+    // Otherwise, split off the smallest cyclotomic factor
+    // Every modulus is a product of cyclotomic polynomials, so this always finds one
+    let factor = (1..)
+        .map(cyclotomic)
+        .find(|factor| factor.len() <= modulus.len() && poly_divmod(modulus, factor).1.is_empty())
+        .unwrap();
+    if factor.len() == modulus.len() {
+        None
+    } else {
+        let (quotient, _) = poly_divmod(modulus, &factor);
+        Some((factor, quotient))
+    }
+}
+
+// A linear combination of values, with rational coefficients.
+// These are either emitted variables, or the real or imaginary parts of twiddle factors, depending on context
+#[derive(Clone, Default, PartialEq, Debug)]
+struct Form(BTreeMap<usize, Rational>);
+impl Form {
+    fn term(key: usize, coefficient: Rational) -> Self {
+        let mut form = Self::default();
+        form.0.insert(key, coefficient);
+        form
+    }
+    fn scale(&self, factor: Rational) -> Self {
+        let mut result = Self::default();
+        result.add_scaled(self, factor);
+        result
+    }
+    fn add_scaled(&mut self, other: &Form, factor: Rational) {
+        for (&key, &coefficient) in &other.0 {
+            let sum = self.0.get(&key).cloned().unwrap_or(Rational::int(0)) + coefficient * factor;
+            if sum.is_zero() {
+                self.0.remove(&key);
+            } else {
+                self.0.insert(key, sum);
+            }
+        }
+    }
+}
+
+// Multiplies a polynomial whose coefficients are forms by a rational polynomial
+fn forms_mul(forms: &[Form], poly: &[Rational]) -> Vec<Form> {
+    let mut product = vec![Form::default(); forms.len() + poly.len() - 1];
+    for (i, form) in forms.iter().enumerate() {
+        for (j, &c) in poly.iter().enumerate() {
+            product[i + j].add_scaled(form, c);
+        }
+    }
+    product
+}
+
+// Reduces a polynomial whose coefficients are forms modulo a rational polynomial
+fn forms_rem(forms: &[Form], modulus: &[Rational]) -> Vec<Form> {
+    let degree = modulus.len() - 1;
+    let mut forms = forms.to_vec();
+    forms.resize(forms.len().max(degree), Form::default());
+    for i in (degree..forms.len()).rev() {
+        let top = forms[i].scale(Rational::int(1) / modulus[degree]);
+        for (j, &c) in modulus.iter().enumerate() {
+            forms[i - degree + j].add_scaled(&top, -c);
+        }
+    }
+    forms.truncate(degree);
+    forms
+}
+
+#[derive(Clone)]
+struct Emitter {
+    len: usize,
+    body: String,
+    names: Vec<String>,
+    // Every form we've emitted a variable for, so that we can reuse them
+    materialized: Vec<(Form, usize)>,
+    // Each constant is a form of twiddle factor indexes, and whether it uses their imaginary parts
+    constants: Vec<(Form, bool)>,
+}
+impl Emitter {
+    fn new(len: usize) -> Self {
+        Self {
+            len,
+            body: String::new(),
+            names: Vec::new(),
+            materialized: Vec::new(),
+            constants: Vec::new(),
+        }
+    }
+
+    fn emit(&mut self, name: String, expression: String) -> usize {
+        writeln!(self.body, "        let {} = {};", name, expression).unwrap();
+        self.names.push(name);
+        self.names.len() - 1
+    }
+
+    fn expression(&self, form: &Form) -> String {
+        if form.0.is_empty() {
+            return String::from("Complex { re: T::zero(), im: T::zero() }");
+        }
+        let mut expression = String::new();
+        for (&var, &coefficient) in &form.0 {
+            assert_eq!(
+                coefficient.den, 1,
+                "values can only be added and subtracted"
+            );
+            for _ in 0..coefficient.num.abs() {
+                match (expression.is_empty(), coefficient.num < 0) {
+                    (true, false) => {}
+                    (true, true) => expression.push('-'),
+                    (false, false) => expression.push_str(" + "),
+                    (false, true) => expression.push_str(" - "),
+                }
+                expression.push_str(&self.names[var]);
+            }
+        }
+        expression
+    }
+
+    // Returns a variable holding the value of `form`, emitting one if necessary
+    fn materialize(&mut self, form: &Form) -> usize {
+        if form.0.len() == 1 {
+            let (&var, &coefficient) = form.0.iter().next().unwrap();
+            if coefficient == Rational::int(1) {
+                return var;
+            }
+        }
+        if let Some(&(_, var)) = self.materialized.iter().find(|entry| entry.0 == *form) {
+            return var;
+        }
+        let expression = self.expression(form);
+        let name = format!("t{}", self.names.len());
+        let var = self.emit(name, expression);
+        self.materialized.push((form.clone(), var));
+        var
+    }
+
+    fn evaluate_constant(&self, constant: &Form, imaginary: bool) -> f64 {
+        constant
+            .0
+            .iter()
+            .map(|(&index, &coefficient)| {
+                let angle = 2.0 * PI * index as f64 / self.len as f64;
+                let part = if imaginary { -angle.sin() } else { angle.cos() };
+                part * coefficient.num as f64 / coefficient.den as f64
+            })
+            .sum()
+    }
+
+    fn multiply(&mut self, data: &Form, constant: &Form, imaginary: bool) -> Form {
+        if data.0.is_empty() || self.evaluate_constant(constant, imaginary).abs() < 1e-9 {
+            return Form::default();
+        }
+
+        // Move any sign from the data into the constant
+        let sign = if data.0.values().next().unwrap().num < 0 {
+            Rational::int(-1)
+        } else {
+            Rational::int(1)
+        };
+        let var = self.materialize(&data.scale(sign));
+        let constant = constant.scale(sign);
+
+        let negated = constant.scale(Rational::int(-1));
+        let existing = self.constants.iter().enumerate().find_map(|(i, entry)| {
+            if entry.1 != imaginary {
+                None
+            } else if entry.0 == constant {
+                Some((i, Rational::int(1)))
+            } else if entry.0 == negated {
+                Some((i, Rational::int(-1)))
+            } else {
+                None
+            }
+        });
+        let (index, sign) = existing.unwrap_or_else(|| {
+            self.constants.push((constant, imaginary));
+            (self.constants.len() - 1, Rational::int(1))
+        });
+
+        let name = format!("m{}", self.names.len());
+        let expression = format!("{} * self.constants[{}]", self.names[var], index);
+        Form::term(self.emit(name, expression), sign)
+    }
+
+    // Computes the product of data and kernel with Karatsuba's algorithm.
+    // The middle product multiplies the sums of the low and high halves if `sign` is 1, or their differences if it's -1
+    fn karatsuba(
+        &mut self,
+        data: &[Form],
+        kernel: &[Form],
+        imaginary: bool,
+        sign: Rational,
+    ) -> Vec<Form> {
+        let n = data.len();
+        if n == 1 {
+            return vec![self.multiply(&data[0], &kernel[0], imaginary)];
+        }
+        // Each input is used by several products, so we compute any sums once up front
+        let data: Vec<Form> = data
+            .iter()
+            .map(|form| {
+                let single_term =
+                    form.0.len() == 1 && form.0.values().all(|c| c.num.abs() == 1 && c.den == 1);
+                if form.0.is_empty() || single_term {
+                    form.clone()
+                } else {
+                    Form::term(self.materialize(form), Rational::int(1))
+                }
+            })
+            .collect();
+
+        let half = n - n / 2;
+        let sum_halves = |values: &[Form]| -> Vec<Form> {
+            let mut sum = values[..half].to_vec();
+            for (i, value) in values[half..].iter().enumerate() {
+                sum[i].add_scaled(value, sign);
+            }
+            sum
+        };
+        let low = self.karatsuba(&data[..half], &kernel[..half], imaginary, sign);
+        let high = self.karatsuba(&data[half..], &kernel[half..], imaginary, sign);
+        let mid = self.karatsuba(&sum_halves(&data), &sum_halves(kernel), imaginary, sign);
+
+        let mut product = vec![Form::default(); 2 * n - 1];
+        for (i, form) in low.iter().enumerate() {
+            product[i].add_scaled(form, Rational::int(1));
+            product[i + half].add_scaled(form, -sign);
+        }
+        for (i, form) in high.iter().enumerate() {
+            product[i + 2 * half].add_scaled(form, Rational::int(1));
+            product[i + half].add_scaled(form, -sign);
+        }
+        for (i, form) in mid.iter().enumerate() {
+            product[i + half].add_scaled(form, sign);
+        }
+        product
+    }
+
+    // Computes the product of data and kernel, modulo `modulus`
+    fn convolve(
+        &mut self,
+        modulus: &[Rational],
+        data: &[Form],
+        kernel: &[Form],
+        imaginary: bool,
+    ) -> Vec<Form> {
+        let result = if let Some((modulus1, modulus2)) = split_modulus(modulus) {
+            // Chinese remainder theorem: If y1 is the product modulo m1 and y2 is the product modulo m2, the product modulo m1*m2 is
+            // y1 * m2 * (m2^-1 mod m1) + y2 * m1 * (m1^-1 mod m2). The inverses are folded into the kernels
+            let mut sub_convolution = |factor: &[Rational], cofactor: &[Rational]| {
+                // A linear factor's single product might have a zero constant, so we leave it to `multiply` to emit that data
+                let mut reduced_data = forms_rem(data, factor);
+                if factor.len() > 2 {
+                    for form in reduced_data.iter_mut() {
+                        *form = Form::term(self.materialize(form), Rational::int(1));
+                    }
+                }
+                let reduced_kernel =
+                    forms_rem(&forms_mul(kernel, &poly_inverse(cofactor, factor)), factor);
+                let product = self.convolve(factor, &reduced_data, &reduced_kernel, imaginary);
+                forms_mul(&product, cofactor)
+            };
+            let mut result = sub_convolution(&modulus1, &modulus2);
+            for (form, other) in result.iter_mut().zip(sub_convolution(&modulus2, &modulus1)) {
+                form.add_scaled(&other, Rational::int(1));
+            }
+            forms_rem(&result, modulus)
+        } else {
+            // Try both signs of Karatsuba's middle product, and keep whichever needs fewer additions
+            let cost = |sign: Rational| {
+                let mut trial = self.clone();
+                let product = trial.karatsuba(data, kernel, imaginary, sign);
+                let added_terms: i64 = forms_rem(&product, modulus)
+                    .iter()
+                    .flat_map(|form| form.0.values())
+                    .map(|c| c.num.abs())
+                    .sum();
+                trial.names.len() as i64 + added_terms
+            };
+            let sign = if cost(Rational::int(-1)) < cost(Rational::int(1)) {
+                Rational::int(-1)
+            } else {
+                Rational::int(1)
+            };
+            let product = self.karatsuba(data, kernel, imaginary, sign);
+            forms_rem(&product, modulus)
+        };
+        result
+            .iter()
+            .map(|form| {
+                if form.0.is_empty() {
+                    Form::default()
+                } else {
+                    Form::term(self.materialize(form), Rational::int(1))
+                }
+            })
+            .collect()
+    }
+
+    // `sums` and `differences` hold the pairs of inputs n and len - n for n = g^a, for each power a up to half the count of units.
+    // If g^a is past the halfway point, the difference is negated to match.
+    // Returns sum(u_n * re(twiddle(nk))) and sum(v_n * im(twiddle(nk))) for each k = g^b, as cyclic and negacyclic convolutions
+    fn rader_convolutions(
+        &mut self,
+        sums: &[Form],
+        differences: &[Form],
+        twiddle_indexes: &[usize],
+    ) -> (Vec<Form>, Vec<Form>) {
+        let half = sums.len();
+        let cosine_data: Vec<Form> = (0..half).map(|j| sums[(half - j) % half].clone()).collect();
+        let sine_data: Vec<Form> = (0..half)
+            .map(|j| {
+                if j == 0 {
+                    differences[0].clone()
+                } else {
+                    differences[half - j].scale(Rational::int(-1))
+                }
+            })
+            .collect();
+        let kernel: Vec<Form> = twiddle_indexes
+            .iter()
+            .map(|&index| Form::term(index, Rational::int(1)))
+            .collect();
+
+        let cosines = self.convolve(&binomial(half, -1), &cosine_data, &kernel, false);
+        let sines = self.convolve(&binomial(half, 1), &sine_data, &kernel, true);
+        (cosines, sines)
+    }
+
+    // Returns a variable holding real + i * imaginary, or real - i * imaginary
+    fn rotate_add(&mut self, real: usize, imaginary: usize, negate: bool) -> usize {
+        let (real, imaginary) = (&self.names[real], &self.names[imaginary]);
+        let expression = if negate {
+            format!(
+                "Complex {{ re: {0}.re + {1}.im, im: {0}.im - {1}.re }}",
+                real, imaginary
+            )
+        } else {
+            format!(
+                "Complex {{ re: {0}.re - {1}.im, im: {0}.im + {1}.re }}",
+                real, imaginary
+            )
+        };
+        let name = format!("y{}", self.names.len());
+        self.emit(name, expression)
+    }
+
+    // Outputs n and len - n, for the given pair of `len` = p^k units. `bases` holds the value added to each output
+    fn emit_unit_outputs(
+        &mut self,
+        pairs: &[(Form, Form)],
+        bases: &dyn Fn(usize) -> Form,
+        len: usize,
+        stride: usize,
+        outputs: &mut [usize],
+    ) {
+        let half = pairs.len();
+        let g = primitive_root(len);
+        let mut powers = vec![1];
+        for _ in 1..half {
+            powers.push(powers.last().unwrap() * g % len);
+        }
+
+        let mut sums = Vec::new();
+        let mut differences = Vec::new();
+        for &power in &powers {
+            let pair_index = (1..len).filter(|&n| gcd(n as i64, len as i64) == 1);
+            let (index, sign) = if power <= len / 2 {
+                (pair_index.take_while(|&n| n < power).count(), 1)
+            } else {
+                (pair_index.take_while(|&n| n < len - power).count(), -1)
+            };
+            sums.push(pairs[index].0.clone());
+            differences.push(pairs[index].1.scale(Rational::int(sign)));
+        }
+        let twiddle_indexes: Vec<usize> = powers.iter().map(|power| power * stride).collect();
+        let (cosines, sines) = self.rader_convolutions(&sums, &differences, &twiddle_indexes);
+
+        for ((power, cosine), sine) in powers.iter().zip(cosines).zip(sines) {
+            let imaginary = self.materialize(&sine);
+            for &(k, negate) in &[(*power, false), (len - power, true)] {
+                let mut real = bases(k);
+                real.add_scaled(&cosine, Rational::int(1));
+                let real = self.materialize(&real);
+                outputs[k] = self.rotate_add(real, imaginary, negate);
+            }
+        }
+    }
+
+    // Computes a DFT of prime length, whose twiddle factors are every `stride`th twiddle factor of the butterfly
+    fn prime_dft(
+        &mut self,
+        first: usize,
+        pairs: &[(Form, Form)],
+        len: usize,
+        stride: usize,
+    ) -> Vec<usize> {
+        let mut outputs = vec![0; len];
+        let mut sum = Form::default();
+        for pair in pairs {
+            sum.add_scaled(&pair.0, Rational::int(1));
+        }
+        let mut first_output = Form::term(self.materialize(&sum), Rational::int(1));
+        first_output.add_scaled(&Form::term(first, Rational::int(1)), Rational::int(1));
+        outputs[0] = self.materialize(&first_output);
+        let base = |_| Form::term(first, Rational::int(1));
+        self.emit_unit_outputs(pairs, &base, len, stride, &mut outputs);
+        outputs
+    }
+}
+
+fn generate_butterfly(code: &mut String, len: usize) {
+    let name = format!("Butterfly{}Winograd", len);
+    let mut emitter = Emitter::new(len);
+
+    // Sums and differences of each pair of inputs. We load every input before storing any outputs, so this works in-place
+    let first = emitter.emit(String::from("x0"), String::from("input.load(0)"));
+    let mut pairs = Vec::new();
+    for n in 1..=len / 2 {
+        let sum = emitter.emit(
+            format!("x{}{}p", n, len - n),
+            format!("input.load({}) + input.load({})", n, len - n),
+        );
+        let difference = emitter.emit(
+            format!("x{}{}n", n, len - n),
+            format!("input.load({}) - input.load({})", n, len - n),
+        );
+        pairs.push((
+            Form::term(sum, Rational::int(1)),
+            Form::term(difference, Rational::int(1)),
+        ));
+    }
+
+    let outputs = if is_prime(len) {
+        emitter.prime_dft(first, &pairs, len, 1)
+    } else {
+        let prime = (2..len).find(|&p| divides(p, len)).unwrap();
+        assert!(
+            prime * prime == len && is_prime(prime),
+            "Winograd butterflies must have prime or prime squared length"
+        );
+
+        // The inputs at multiples of the prime form a DFT whose outputs repeat across the whole butterfly
+        let multiple_pairs: Vec<(Form, Form)> = (1..=prime / 2)
+            .map(|n| pairs[n * prime - 1].clone())
+            .collect();
+        let multiples = emitter.prime_dft(first, &multiple_pairs, prime, prime);
+
+        // The outputs at multiples of the prime are a DFT of the sums of each residue class. Pairs of units contribute to pairs of residue classes
+        let mut class_pairs = vec![(Form::default(), Form::default()); prime / 2];
+        let mut unit_pairs = Vec::new();
+        for (n, pair) in (1..).zip(&pairs) {
+            let residue = n % prime;
+            if residue == 0 {
+                continue;
+            }
+            let sign = if residue <= prime / 2 { 1 } else { -1 };
+            let class = residue.min(prime - residue) - 1;
+            class_pairs[class].0.add_scaled(&pair.0, Rational::int(1));
+            class_pairs[class]
+                .1
+                .add_scaled(&pair.1, Rational::int(sign));
+            unit_pairs.push(pair.clone());
+        }
+        let class_dft = emitter.prime_dft(multiples[0], &class_pairs, prime, prime);
+
+        let mut outputs = vec![0; len];
+        for (k, &output) in class_dft.iter().enumerate() {
+            outputs[k * prime] = output;
+        }
+        let base = |k: usize| Form::term(multiples[k % prime], Rational::int(1));
+        emitter.emit_unit_outputs(&unit_pairs, &base, len, 1, &mut outputs);
+        outputs
+    };
+
+    writeln!(code, "pub struct {}<T> {{", name).unwrap();
+    writeln!(code, "    constants: [T; {}],", emitter.constants.len()).unwrap();
+    writeln!(code, "    direction: FftDirection,").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(
+        code,
+        "boilerplate_fft_butterfly!({0}, {1}, |this: &{0}<_>| this.direction);",
+        name, len
+    )
+    .unwrap();
+
+    writeln!(code, "impl<T: FftNum> {}<T> {{", name).unwrap();
+    writeln!(code, "    pub fn new(direction: FftDirection) -> Self {{").unwrap();
+    writeln!(code, "        Self {{").unwrap();
+    writeln!(code, "            constants: [").unwrap();
+    for (constant, imaginary) in &emitter.constants {
+        let terms: Vec<String> = constant
+            .0
+            .iter()
+            .map(|(index, c)| format!("({}, {}, {})", c.num, c.den, index))
+            .collect();
+        writeln!(
+            code,
+            "                winograd_constant(&[{}], {}, {}, direction),",
+            terms.join(", "),
+            imaginary,
+            len
+        )
+        .unwrap();
+    }
+    writeln!(code, "            ],").unwrap();
+    writeln!(code, "            direction,").unwrap();
+    writeln!(code, "        }}").unwrap();
+    writeln!(code, "    }}").unwrap();
+    writeln!(code).unwrap();
+
+    writeln!(code, "    #[inline(never)]").unwrap();
+    writeln!(code, "    unsafe fn perform_fft_contiguous(").unwrap();
+    writeln!(code, "        &self,").unwrap();
+    writeln!(code, "        input: RawSlice<Complex<T>>,").unwrap();
+    writeln!(code, "        output: RawSliceMut<Complex<T>>,").unwrap();
+    writeln!(code, "    ) {{").unwrap();
+    code.push_str(&emitter.body);
+    for (k, &output) in outputs.iter().enumerate() {
+        writeln!(
+            code,
+            "        output.store({}, {});",
+            emitter.names[output], k
+        )
+        .unwrap();
+    }
+    writeln!(code, "    }}").unwrap();
+    writeln!(code, "}}").unwrap();
+    writeln!(code).unwrap();
+}
@@ -1,11 +1,13 @@
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use num_complex::Complex;
 use num_traits::Zero;
 
 use crate::array_utils;
 use crate::common::{fft_error_inplace, fft_error_outofplace};
-use crate::{common::FftNum, twiddles, FftDirection};
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
 use crate::{Direction, Fft, Length};
 
 /// Implementation of Bluestein's Algorithm
@@ -41,7 +43,8 @@ use crate::{Direction, Fft, Length};
 pub struct BluesteinsAlgorithm<T> {
     inner_fft: Arc<dyn Fft<T>>,
 
-    inner_fft_multiplier: Box<[Complex<T>]>,
+    // The inner FFT of the chirp, as computed for a forward FFT. Inverse FFTs multiply by its conjugate instead, so both directions can share it
+    inner_fft_multiplier: Arc<[Complex<T>]>,
     twiddles: Box<[Complex<T>]>,
 
     len: usize,
@@ -50,10 +53,14 @@ pub struct BluesteinsAlgorithm<T> {
 
 impl<T: FftNum> BluesteinsAlgorithm<T> {
     fn compute_bluesteins_twiddle(index: usize, len: usize, direction: FftDirection) -> Complex<T> {
-        let index_float = index as f64;
-        let index_squared = index_float * index_float;
-
-        twiddles::compute_twiddle_floatindex(index_squared, len * 2, direction.opposite_direction())
+        // index^2 can be much larger than len, so reduce it with integer arithmetic before it gets converted to an angle
+        let index_squared = (index as u128 * index as u128) % (len as u128 * 2);
+
+        twiddles::compute_twiddle(
+            index_squared as usize,
+            len * 2,
+            direction.opposite_direction(),
+        )
     }
 
     /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len * 2 - 1`
@@ -65,10 +72,64 @@ impl<T: FftNum> BluesteinsAlgorithm<T> {
     /// # Panics
     /// Panics if `inner_fft.len() < len * 2 - 1`.
     pub fn new(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Self {
+        Self::try_new(len, inner_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len * 2 - 1`
+    ///
+    /// Returns `Err(FftError::InnerFftTooSmall)` if `inner_fft.len() < len * 2 - 1`.
+    pub fn try_new(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Result<Self, FftError> {
         let inner_fft_len = inner_fft.len();
-        assert!(len * 2 - 1 <= inner_fft_len, "Bluestein's algorithm requires inner_fft.len() >= self.len() * 2 - 1. Expected >= {}, got {}", len * 2 - 1, inner_fft_len);
+        if len * 2 - 1 > inner_fft_len {
+            return Err(FftError::InnerFftTooSmall {
+                expected_len: len * 2 - 1,
+                actual_len: inner_fft_len,
+            });
+        }
+
+        let chirp_spectrum = Self::compute_chirp_spectrum(len, &*inner_fft);
+        Ok(Self::new_with_chirp_spectrum(
+            len,
+            inner_fft,
+            chirp_spectrum,
+        ))
+    }
+
+    // Creates a FFT instance that reuses a chirp spectrum computed by `compute_chirp_spectrum` for the same `len` and inner FFT length.
+    // The spectrum doesn't depend on the direction, so forward and inverse instances can share it.
+    pub(crate) fn new_with_chirp_spectrum(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        chirp_spectrum: Arc<[Complex<T>]>,
+    ) -> Self {
+        assert_eq!(
+            chirp_spectrum.len(),
+            inner_fft.len(),
+            "The chirp spectrum must have the same length as the inner FFT"
+        );
+        let direction = inner_fft.fft_direction();
+
+        // also compute some more mundane twiddle factors to start and end with
+        let twiddles: Vec<_> = (0..len)
+            .map(|i| Self::compute_bluesteins_twiddle(i, len, direction.opposite_direction()))
+            .collect();
 
-        // when computing FFTs, we're going to run our inner multiply pairise by some precomputed data, then run an inverse inner FFT. We need to precompute that inner data here
+        Self {
+            inner_fft: inner_fft,
+
+            inner_fft_multiplier: chirp_spectrum,
+            twiddles: twiddles.into_boxed_slice(),
+
+            len,
+            direction,
+        }
+    }
+
+    // Computes the data that we multiply our inner FFT output by: the inner FFT of the chirp, divided by the inner FFT length.
+    // The result is always the spectrum for a forward FFT, whichever direction `inner_fft` has. It's laid out as a plain array of
+    // complex numbers, so the scalar and AVX implementations of Bluestein's algorithm can share it.
+    pub(crate) fn compute_chirp_spectrum(len: usize, inner_fft: &dyn Fft<T>) -> Arc<[Complex<T>]> {
+        let inner_fft_len = inner_fft.len();
         let inner_len_float = T::from_usize(inner_fft_len).unwrap();
         let direction = inner_fft.fft_direction();
 
@@ -86,19 +147,58 @@ impl<T: FftNum> BluesteinsAlgorithm<T> {
         let mut inner_fft_scratch = vec![Complex::zero(); inner_fft.get_inplace_scratch_len()];
         inner_fft.process_with_scratch(&mut inner_fft_input, &mut inner_fft_scratch);
 
-        // also compute some more mundane twiddle factors to start and end with
-        let twiddles: Vec<_> = (0..len)
-            .map(|i| Self::compute_bluesteins_twiddle(i, len, direction.opposite_direction()))
-            .collect();
+        // An inverse inner FFT of the conjugated chirp computes the conjugate of the forward spectrum, so undo that
+        match direction {
+            FftDirection::Forward => Arc::from(inner_fft_input),
+            FftDirection::Inverse => inner_fft_input.into_iter().map(|c| c.conj()).collect(),
+        }
+    }
 
-        Self {
-            inner_fft: inner_fft,
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len * 2 - 1`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scale is folded into the precomputed
+    /// data, so it doesn't require an extra pass over the data.
+    ///
+    /// # Panics
+    /// Panics if `inner_fft.len() < len * 2 - 1`.
+    pub fn new_normalized(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, inner_fft);
+        let scale = twiddles::compute_scale_factor(normalization, len, result.direction);
+        result.scale_output(scale);
+        result
+    }
 
-            inner_fft_multiplier: inner_fft_input.into_boxed_slice(),
-            twiddles: twiddles.into_boxed_slice(),
+    // Multiplies every output of this FFT by `scale`. The chirp spectrum may be shared with other instances, so this makes a scaled copy of it
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        self.inner_fft_multiplier = self
+            .inner_fft_multiplier
+            .iter()
+            .map(|multiplier| *multiplier * scale)
+            .collect();
+    }
 
-            len,
-            direction,
+    // Multiplies our inner FFT output by our precomputed data. Then, conjugates the result to set up for an inverse FFT
+    fn multiply_by_chirp_spectrum(&self, inner_input: &mut [Complex<T>]) {
+        match self.direction {
+            FftDirection::Forward => {
+                for (inner, multiplier) in
+                    inner_input.iter_mut().zip(self.inner_fft_multiplier.iter())
+                {
+                    *inner = (*inner * *multiplier).conj();
+                }
+            }
+            FftDirection::Inverse => {
+                // Inverse FFTs multiply by the conjugate of the shared spectrum, and conj(x * conj(y)) = conj(x) * y
+                for (inner, multiplier) in
+                    inner_input.iter_mut().zip(self.inner_fft_multiplier.iter())
+                {
+                    *inner = inner.conj() * *multiplier;
+                }
+            }
         }
     }
 
@@ -122,9 +222,7 @@ impl<T: FftNum> BluesteinsAlgorithm<T> {
             .process_with_scratch(inner_input, inner_scratch);
 
         // Multiply our inner FFT output by our precomputed data. Then, conjugate the result to set up for an inverse FFT
-        for (inner, multiplier) in inner_input.iter_mut().zip(self.inner_fft_multiplier.iter()) {
-            *inner = (*inner * *multiplier).conj();
-        }
+        self.multiply_by_chirp_spectrum(inner_input);
 
         // inverse FFT. we're computing a forward but we're massaging it into an inverse by conjugating the inputs and outputs
         self.inner_fft
@@ -145,6 +243,16 @@ impl<T: FftNum> BluesteinsAlgorithm<T> {
         input: &mut [Complex<T>],
         output: &mut [Complex<T>],
         scratch: &mut [Complex<T>],
+    ) {
+        // Bluestein's algorithm never writes to its input, so the out-of-place FFT is the same as the immutable FFT
+        self.perform_fft_immutable(input, output, scratch)
+    }
+
+    fn perform_fft_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
     ) {
         let (inner_input, inner_scratch) = scratch.split_at_mut(self.inner_fft_multiplier.len());
 
@@ -165,9 +273,7 @@ impl<T: FftNum> BluesteinsAlgorithm<T> {
             .process_with_scratch(inner_input, inner_scratch);
 
         // Multiply our inner FFT output by our precomputed data. Then, conjugate the result to set up for an inverse FFT
-        for (inner, multiplier) in inner_input.iter_mut().zip(self.inner_fft_multiplier.iter()) {
-            *inner = (*inner * *multiplier).conj();
-        }
+        self.multiply_by_chirp_spectrum(inner_input);
 
         // inverse FFT. we're computing a forward but we're massaging it into an inverse by conjugating the inputs and outputs
         self.inner_fft
@@ -189,15 +295,17 @@ boilerplate_fft!(
     |this: &BluesteinsAlgorithm<_>| this.inner_fft_multiplier.len()
         + this.inner_fft.get_inplace_scratch_len(), // in-place scratch len
     |this: &BluesteinsAlgorithm<_>| this.inner_fft_multiplier.len()
-        + this.inner_fft.get_inplace_scratch_len()  // out of place scratch len
+        + this.inner_fft.get_inplace_scratch_len(), // out of place scratch len
+    |this: &BluesteinsAlgorithm<_>| this.inner_fft_multiplier.len()
+        + this.inner_fft.get_inplace_scratch_len()  // immutable scratch len
 );
 
 #[cfg(test)]
 mod unit_tests {
     use super::*;
     use crate::algorithm::Dft;
-    use crate::test_utils::check_fft_algorithm;
-    use std::sync::Arc;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+    use alloc::sync::Arc;
 
     #[test]
     fn test_bluesteins() {
@@ -216,4 +324,39 @@ mod unit_tests {
 
         check_fft_algorithm::<f32>(&fft, len, direction);
     }
+
+    #[test]
+    fn test_bluesteins_normalized() {
+        for &len in &[3usize, 5, 11] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let inner_fft = Arc::new(Dft::new(
+                        (len * 2 - 1).checked_next_power_of_two().unwrap(),
+                        direction,
+                    ));
+                    let fft = BluesteinsAlgorithm::new_normalized(len, inner_fft, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bluesteins_try_new() {
+        let inner_fft = Arc::new(Dft::new(9, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert!(BluesteinsAlgorithm::try_new(5, inner_fft).is_ok());
+
+        let inner_fft = Arc::new(Dft::new(8, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            BluesteinsAlgorithm::try_new(5, inner_fft).err(),
+            Some(FftError::InnerFftTooSmall {
+                expected_len: 9,
+                actual_len: 8
+            })
+        );
+    }
 }
@@ -0,0 +1,263 @@
+use num_complex::Complex;
+use num_traits::Zero;
+
+use crate::algorithm::butterflies::{Butterfly1, Butterfly16, Butterfly2, Butterfly4, Butterfly8};
+use crate::algorithm::radix4::butterfly_4;
+use crate::{common::FftNum, twiddles, FftDirection};
+use crate::{Direction, Fft, Length};
+
+// The butterfly that ConstSizeFft computes its base-level FFTs with. Stored inline, so that there's no dynamic dispatch.
+enum ConstSizeBase<T> {
+    Butterfly1(Butterfly1<T>),
+    Butterfly2(Butterfly2<T>),
+    Butterfly4(Butterfly4<T>),
+    Butterfly8(Butterfly8<T>),
+    Butterfly16(Butterfly16<T>),
+}
+
+/// Implementation of the Radix-4 algorithm for a power-of-two size `N` that's known at compile time.
+///
+/// `ConstSizeFft` stores its twiddle factors and its base butterfly inline, so creating one never allocates, and computing a FFT
+/// involves no dynamic dispatch, no scratch buffer and no copy of the input. This makes it suitable for hard real-time code, like audio callbacks,
+/// where even occasional allocations aren't acceptable. Computing the twiddle factors requires trigonometry, so instances can't be
+/// created in a `const` context, but they can be created once up front and then used from any thread.
+///
+/// This type requires RustFFT's `const-generics` feature flag, which raises the minimum supported Rust version to 1.51.
+///
+/// ~~~
+/// // Computes a forward FFT of size 256, without allocating
+/// use rustfft::algorithm::ConstSizeFft;
+/// use rustfft::FftDirection;
+/// use rustfft::num_complex::Complex;
+///
+/// let fft = ConstSizeFft::<f32, 256>::new(FftDirection::Forward);
+///
+/// let mut buffer = [Complex{ re: 0.0f32, im: 0.0f32 }; 256];
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct ConstSizeFft<T, const N: usize> {
+    twiddles: [Complex<T>; N],
+
+    base_fft: ConstSizeBase<T>,
+    base_len: usize,
+
+    direction: FftDirection,
+}
+
+impl<T: FftNum, const N: usize> ConstSizeFft<T, N> {
+    /// Precomputes the twiddle factors needed to compute a FFT of size `N`, without allocating.
+    ///
+    /// # Panics
+    /// Panics if `N` is not a power of two.
+    pub fn new(direction: FftDirection) -> Self {
+        assert!(
+            N.is_power_of_two(),
+            "ConstSizeFft requires a power-of-two size. Got {}",
+            N
+        );
+
+        // figure out which base length we're going to use. This is the same choice Radix4 makes
+        let num_bits = N.trailing_zeros();
+        let (base_len, base_fft) = match num_bits {
+            0 => (N, ConstSizeBase::Butterfly1(Butterfly1::new(direction))),
+            1 => (N, ConstSizeBase::Butterfly2(Butterfly2::new(direction))),
+            2 => (N, ConstSizeBase::Butterfly4(Butterfly4::new(direction))),
+            _ => {
+                if num_bits % 2 == 1 {
+                    (8, ConstSizeBase::Butterfly8(Butterfly8::new(direction)))
+                } else {
+                    (16, ConstSizeBase::Butterfly16(Butterfly16::new(direction)))
+                }
+            }
+        };
+
+        // precompute the twiddle factors, packing every layer into a single array, in the same layout Radix4 uses.
+        // Layer k has 3 * base_len * 4^k twiddles, and the layers sum to less than N
+        let mut twiddles = [Complex::zero(); N];
+        let mut twiddle_index = 0;
+        let mut twiddle_stride = N / (base_len * 4);
+        while twiddle_stride > 0 {
+            let num_rows = N / (twiddle_stride * 4);
+            for i in 0..num_rows {
+                for k in 1..4 {
+                    twiddles[twiddle_index] =
+                        twiddles::compute_twiddle(i * k * twiddle_stride, N, direction);
+                    twiddle_index += 1;
+                }
+            }
+            twiddle_stride >>= 2;
+        }
+
+        Self {
+            twiddles,
+            base_fft,
+            base_len,
+            direction,
+        }
+    }
+
+    /// Computes a FFT of size `N` in-place. Doesn't allocate, and doesn't require a scratch buffer.
+    pub fn process(&self, buffer: &mut [Complex<T>; N]) {
+        self.reorder_input(buffer);
+
+        // Base-level FFTs
+        match &self.base_fft {
+            ConstSizeBase::Butterfly1(fft) => fft.process_with_scratch(buffer, &mut []),
+            ConstSizeBase::Butterfly2(fft) => fft.process_with_scratch(buffer, &mut []),
+            ConstSizeBase::Butterfly4(fft) => fft.process_with_scratch(buffer, &mut []),
+            ConstSizeBase::Butterfly8(fft) => fft.process_with_scratch(buffer, &mut []),
+            ConstSizeBase::Butterfly16(fft) => fft.process_with_scratch(buffer, &mut []),
+        }
+
+        // cross-FFTs
+        let mut current_size = self.base_len * 4;
+        let mut layer_twiddles: &[Complex<T>] = &self.twiddles;
+
+        while current_size <= N {
+            let num_rows = N / current_size;
+
+            for i in 0..num_rows {
+                unsafe {
+                    butterfly_4(
+                        &mut buffer[i * current_size..],
+                        layer_twiddles,
+                        current_size / 4,
+                        current_size / 4,
+                        self.direction,
+                    )
+                }
+            }
+
+            //skip past all the twiddle factors used in this layer
+            let twiddle_offset = (current_size * 3) / 4;
+            layer_twiddles = &layer_twiddles[twiddle_offset..];
+
+            current_size *= 4;
+        }
+    }
+}
+impl<T: FftNum, const N: usize> ConstSizeFft<T, N> {
+    // Reorders the buffer in-place into the order Radix4's prepare_radix4 produces, so that we need neither scratch space nor a copy of the input.
+    // prepare_radix4 moves the input at index `reverse_base4(c) + j * num_chunks` to index `c * base_len + j`. That isn't an involution,
+    // but it's the composition of two: Reversing every bit of the index moves the input to `swap_bit_pairs(c) * base_len + reverse(j)`,
+    // then swapping each chunk index's bit pairs and reversing each offset within a chunk moves it the rest of the way
+    fn reorder_input(&self, buffer: &mut [Complex<T>; N]) {
+        let num_bits = N.trailing_zeros();
+        for i in 0..N {
+            let target = reverse_bits(i, num_bits);
+            if target > i {
+                buffer.swap(i, target);
+            }
+        }
+
+        let base_bits = self.base_len.trailing_zeros();
+        for i in 0..N {
+            let chunk = i >> base_bits;
+            let offset = i & (self.base_len - 1);
+            let target = (swap_bit_pairs(chunk) << base_bits) | reverse_bits(offset, base_bits);
+            if target > i {
+                buffer.swap(i, target);
+            }
+        }
+    }
+}
+
+// Reverses the lowest `num_bits` bits of `value`
+fn reverse_bits(value: usize, num_bits: u32) -> usize {
+    if num_bits == 0 {
+        0
+    } else {
+        // usize::BITS needs Rust 1.53, and this feature supports 1.51
+        let usize_bits = (core::mem::size_of::<usize>() * 8) as u32;
+        value.reverse_bits() >> (usize_bits - num_bits)
+    }
+}
+
+// Swaps each pair of bits in `value`, ie each of its base-4 digits' two bits
+fn swap_bit_pairs(value: usize) -> usize {
+    let low_bits = usize::MAX / 3; // 0b0101...01
+    ((value & low_bits) << 1) | ((value >> 1) & low_bits)
+}
+
+impl<T, const N: usize> Length for ConstSizeFft<T, N> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+}
+impl<T, const N: usize> Direction for ConstSizeFft<T, N> {
+    #[inline(always)]
+    fn fft_direction(&self) -> FftDirection {
+        self.direction
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::test_utils::{compare_vectors, random_signal};
+    use crate::FftPlanner;
+
+    macro_rules! test_const_size {
+        ($($len:expr),*) => {
+            $(
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let fft = ConstSizeFft::<f32, $len>::new(direction);
+                    assert_eq!(fft.len(), $len);
+                    assert_eq!(fft.fft_direction(), direction);
+
+                    let input = random_signal::<f32>($len);
+                    let mut expected = input.clone();
+                    Dft::new($len, direction).process(&mut expected);
+
+                    let mut buffer = [Complex::zero(); $len];
+                    buffer.copy_from_slice(&input);
+                    fft.process(&mut buffer);
+
+                    assert!(
+                        compare_vectors(&expected, &buffer),
+                        "length = {}, direction = {}",
+                        $len,
+                        direction
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_const_size_fft() {
+        test_const_size!(1, 2, 4, 8, 16, 32, 64, 128, 256, 512, 1024, 2048, 4096, 8192);
+    }
+
+    // ConstSizeFft should compute the same output as the planner's FFT of the same size, to within rounding
+    macro_rules! test_const_size_vs_planner {
+        ($float:ty, $($len:expr),*) => {
+            $(
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let input = random_signal::<$float>($len);
+                    let mut expected = input.clone();
+                    FftPlanner::<$float>::new().plan_fft($len, direction).process(&mut expected);
+
+                    let mut buffer = [Complex::zero(); $len];
+                    buffer.copy_from_slice(&input);
+                    ConstSizeFft::<$float, $len>::new(direction).process(&mut buffer);
+
+                    assert!(
+                        compare_vectors(&expected, &buffer),
+                        "length = {}, direction = {}",
+                        $len,
+                        direction
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn test_const_size_fft_vs_planner() {
+        test_const_size_vs_planner!(f32, 1, 2, 4, 8, 32, 128, 512, 2048, 16384);
+        test_const_size_vs_planner!(f64, 1, 2, 16, 64, 256, 1024, 4096);
+    }
+}
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use num_complex::Complex;
 use num_traits::Zero;
 
@@ -38,6 +39,13 @@ impl<T: FftNum> Dft<T> {
         }
     }
 
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        for twiddle in self.twiddles.iter_mut() {
+            *twiddle = *twiddle * scale;
+        }
+    }
+
     fn perform_fft_out_of_place(
         &self,
         signal: &[Complex<T>],
@@ -68,9 +76,9 @@ boilerplate_fft_oop!(Dft, |this: &Dft<_>| this.twiddles.len());
 mod unit_tests {
     use super::*;
     use crate::test_utils::{compare_vectors, random_signal};
+    use core::f32;
     use num_complex::Complex;
     use num_traits::Zero;
-    use std::f32;
 
     fn dft(signal: &[Complex<f32>], spectrum: &mut [Complex<f32>]) {
         for (k, spec_bin) in spectrum.iter_mut().enumerate() {
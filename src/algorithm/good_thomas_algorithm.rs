@@ -1,5 +1,7 @@
-use std::cmp::max;
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::max;
 
 use num_complex::Complex;
 use num_integer::Integer;
@@ -7,8 +9,10 @@ use strength_reduce::StrengthReducedUsize;
 use transpose;
 
 use crate::array_utils;
-use crate::common::{fft_error_inplace, fft_error_outofplace};
-use crate::{common::FftNum, FftDirection};
+use crate::common::{
+    fft_error_inplace, fft_error_outofplace, validate_directions, validate_small_inner_fft,
+};
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
 use crate::{Direction, Fft, Length};
 
 /// Implementation of the [Good-Thomas Algorithm (AKA Prime Factor Algorithm)](https://en.wikipedia.org/wiki/Prime-factor_FFT_algorithm)
@@ -51,6 +55,8 @@ pub struct GoodThomasAlgorithm<T> {
     inplace_scratch_len: usize,
     outofplace_scratch_len: usize,
 
+    scale: Option<T>,
+
     len: usize,
     direction: FftDirection,
 }
@@ -59,11 +65,22 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
     ///
     /// `GCD(width_fft.len(), height_fft.len())` must be equal to 1
-    pub fn new(mut width_fft: Arc<dyn Fft<T>>, mut height_fft: Arc<dyn Fft<T>>) -> Self {
-        assert_eq!(
-            width_fft.fft_direction(), height_fft.fft_direction(),
-            "width_fft and height_fft must have the same direction. got width direction={}, height direction={}",
-            width_fft.fft_direction(), height_fft.fft_direction());
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions, or if their lengths aren't coprime.
+    pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns `Err(FftError::MismatchedDirections)` if `width_fft` and `height_fft` have different directions, or
+    /// `Err(FftError::NotCoprime)` if their lengths aren't coprime.
+    pub fn try_new(
+        mut width_fft: Arc<dyn Fft<T>>,
+        mut height_fft: Arc<dyn Fft<T>>,
+    ) -> Result<Self, FftError> {
+        validate_directions(width_fft.fft_direction(), height_fft.fft_direction())?;
 
         let mut width = width_fft.len();
         let mut height = height_fft.len();
@@ -71,15 +88,14 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
 
         // This algorithm doesn't work if width and height aren't coprime
         let gcd = num_integer::gcd(width as i64, height as i64);
-        assert!(gcd == 1,
-                "Invalid width and height for Good-Thomas Algorithm (width={}, height={}): Inputs must be coprime",
-                width,
-                height);
+        if gcd != 1 {
+            return Err(FftError::NotCoprime { width, height });
+        }
 
         // The trick we're using for our index remapping will only work if width < height, so just swap them if it isn't
         if width > height {
-            std::mem::swap(&mut width, &mut height);
-            std::mem::swap(&mut width_fft, &mut height_fft);
+            core::mem::swap(&mut width, &mut height);
+            core::mem::swap(&mut width_fft, &mut height_fft);
         }
 
         let len = width * height;
@@ -117,7 +133,7 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
                 height_outofplace_scratch,
             );
 
-        Self {
+        Ok(Self {
             width,
             width_size_fft: width_fft,
 
@@ -130,9 +146,32 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
             inplace_scratch_len,
             outofplace_scratch_len,
 
+            scale: None,
+
             len,
             direction,
-        }
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied while the output is being reindexed,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len, result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
     }
 
     fn reindex_input(&self, source: &[Complex<T>], destination: &mut [Complex<T>]) {
@@ -181,6 +220,7 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
         }
     }
 
+    // Also applies the scale, if there is one
     fn reindex_output(&self, source: &[Complex<T>], destination: &mut [Complex<T>]) {
         // A critical part of the good-thomas algorithm is re-indexing the inputs and outputs.
         // To remap the outputs, we will use the ruritanian mapping, paired with the normal transpose we'd do for mixed radix.
@@ -203,13 +243,17 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
 
             // Process the first part of the row
             for x in start_x..self.height {
-                destination[destination_index] = source_chunk[x];
+                let element = source_chunk[x];
+                destination[destination_index] =
+                    self.scale.map_or(element, |scale| element * scale);
                 destination_index += self.width;
             }
 
             // Wrap back around to the beginning of the row and keep incrementing
             for x in 0..start_x {
-                destination[destination_index] = source_chunk[x];
+                let element = source_chunk[x];
+                destination[destination_index] =
+                    self.scale.map_or(element, |scale| element * scale);
                 destination_index += self.width;
             }
         }
@@ -274,12 +318,50 @@ impl<T: FftNum> GoodThomasAlgorithm<T> {
         // Re-index the output, copying from the input to the output in the process
         self.reindex_output(input, output);
     }
+
+    fn perform_fft_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        // This is the same as the out-of-place FFT, except we can't use the input as scratch space, so we use our own scratch buffer instead
+        let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
+
+        // Re-index the input, copying from the input to the output in the process
+        self.reindex_input(input, output);
+
+        // run FFTs of size `width`
+        let width_scratch = if inner_scratch.len() > scratch.len() {
+            &mut inner_scratch[..]
+        } else {
+            &mut scratch[..]
+        };
+        self.width_size_fft
+            .process_with_scratch(output, width_scratch);
+
+        // transpose
+        transpose::transpose(output, scratch, self.width, self.height);
+
+        // run FFTs of size 'height'
+        let height_scratch = if inner_scratch.len() > output.len() {
+            &mut inner_scratch[..]
+        } else {
+            &mut output[..]
+        };
+        self.height_size_fft
+            .process_with_scratch(scratch, height_scratch);
+
+        // Re-index the output, copying from the scratch to the output in the process
+        self.reindex_output(scratch, output);
+    }
 }
 boilerplate_fft!(
     GoodThomasAlgorithm,
     |this: &GoodThomasAlgorithm<_>| this.len,
     |this: &GoodThomasAlgorithm<_>| this.inplace_scratch_len,
-    |this: &GoodThomasAlgorithm<_>| this.outofplace_scratch_len
+    |this: &GoodThomasAlgorithm<_>| this.outofplace_scratch_len,
+    |this: &GoodThomasAlgorithm<_>| this.len + this.outofplace_scratch_len
 );
 
 /// Implementation of the Good-Thomas Algorithm, specialized for smaller input sizes
@@ -318,6 +400,8 @@ pub struct GoodThomasAlgorithmSmall<T> {
 
     input_output_map: Box<[usize]>,
 
+    scale: Option<T>,
+
     direction: FftDirection,
 }
 
@@ -325,28 +409,37 @@ impl<T: FftNum> GoodThomasAlgorithmSmall<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
     ///
     /// `GCD(width_fft.len(), height_fft.len())` must be equal to 1
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions, if their lengths aren't coprime, or if either of them requires
+    /// any out-of-place scratch, or more in-place scratch than its own length.
     pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
-        assert_eq!(
-            width_fft.fft_direction(), height_fft.fft_direction(),
-            "n1_fft and height_fft must have the same direction. got width direction={}, height direction={}",
-            width_fft.fft_direction(), height_fft.fft_direction());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns `Err(FftError::MismatchedDirections)` if `width_fft` and `height_fft` have different directions, `Err(FftError::NotCoprime)`
+    /// if their lengths aren't coprime, or `Err(FftError::InnerFftScratchTooLarge)` if either of them requires any out-of-place scratch,
+    /// or more in-place scratch than its own length.
+    pub fn try_new(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+    ) -> Result<Self, FftError> {
+        validate_directions(width_fft.fft_direction(), height_fft.fft_direction())?;
 
         let width = width_fft.len();
         let height = height_fft.len();
         let len = width * height;
 
-        assert_eq!(width_fft.get_outofplace_scratch_len(), 0, "GoodThomasAlgorithmSmall should only be used with algorithms that require 0 out-of-place scratch. Width FFT (len={}) requires {}, should require 0", width, width_fft.get_outofplace_scratch_len());
-        assert_eq!(height_fft.get_outofplace_scratch_len(), 0, "GoodThomasAlgorithmSmall should only be used with algorithms that require 0 out-of-place scratch. Height FFT (len={}) requires {}, should require 0", height, height_fft.get_outofplace_scratch_len());
-
-        assert!(width_fft.get_inplace_scratch_len() <= width, "GoodThomasAlgorithmSmall should only be used with algorithms that require little inplace scratch. Width FFT (len={}) requires {}, should require {} or less", width, width_fft.get_inplace_scratch_len(), width);
-        assert!(height_fft.get_inplace_scratch_len() <= height, "GoodThomasAlgorithmSmall should only be used with algorithms that require little inplace scratch. Height FFT (len={}) requires {}, should require {} or less", height, height_fft.get_inplace_scratch_len(), height);
+        validate_small_inner_fft(width_fft.as_ref())?;
+        validate_small_inner_fft(height_fft.as_ref())?;
 
         // compute the multiplicative inverse of width mod height and vice versa. x will be width mod height, and y will be height mod width
         let gcd_data = i64::extended_gcd(&(width as i64), &(height as i64));
-        assert!(gcd_data.gcd == 1,
-                "Invalid input width and height to Good-Thomas Algorithm: ({},{}): Inputs must be coprime",
-                width,
-                height);
+        if gcd_data.gcd != 1 {
+            return Err(FftError::NotCoprime { width, height });
+        }
 
         // width_inverse or height_inverse might be negative, make it positive by wrapping
         let width_inverse = if gcd_data.x >= 0 {
@@ -371,7 +464,7 @@ impl<T: FftNum> GoodThomasAlgorithmSmall<T> {
 
         let input_output_map: Vec<usize> = input_iter.chain(output_iter).collect();
 
-        Self {
+        Ok(Self {
             direction: width_fft.fft_direction(),
 
             width,
@@ -381,7 +474,30 @@ impl<T: FftNum> GoodThomasAlgorithmSmall<T> {
             height_size_fft: height_fft,
 
             input_output_map: input_output_map.into_boxed_slice(),
-        }
+
+            scale: None,
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied while the output is being reordered,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len(), result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
     }
 
     fn perform_fft_out_of_place(
@@ -410,9 +526,11 @@ impl<T: FftNum> GoodThomasAlgorithmSmall<T> {
         // run FFTs of size 'height'
         self.height_size_fft.process_with_scratch(input, output);
 
-        // copy to the output, using our output redordeing mapping
-        for (input_element, &output_index) in input.iter().zip(output_map.iter()) {
-            output[output_index] = *input_element;
+        // copy to the output, using our output redordeing mapping, and apply the scale if there is one
+        for (&input_element, &output_index) in input.iter().zip(output_map.iter()) {
+            output[output_index] = self
+                .scale
+                .map_or(input_element, |scale| input_element * scale);
         }
     }
 
@@ -438,9 +556,11 @@ impl<T: FftNum> GoodThomasAlgorithmSmall<T> {
         self.height_size_fft
             .process_outofplace_with_scratch(buffer, scratch, &mut []);
 
-        // copy to the output, using our output redordeing mapping
-        for (input_element, &output_index) in scratch.iter().zip(output_map.iter()) {
-            buffer[output_index] = *input_element;
+        // copy to the output, using our output redordeing mapping, and apply the scale if there is one
+        for (&input_element, &output_index) in scratch.iter().zip(output_map.iter()) {
+            buffer[output_index] = self
+                .scale
+                .map_or(input_element, |scale| input_element * scale);
         }
     }
 }
@@ -454,11 +574,11 @@ boilerplate_fft!(
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use crate::test_utils::check_fft_algorithm;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
     use crate::{algorithm::Dft, test_utils::BigScratchAlgorithm};
+    use alloc::sync::Arc;
     use num_integer::gcd;
     use num_traits::Zero;
-    use std::sync::Arc;
 
     #[test]
     fn test_good_thomas() {
@@ -503,6 +623,35 @@ mod unit_tests {
         check_fft_algorithm(&fft, width * height, direction);
     }
 
+    #[test]
+    fn test_good_thomas_normalized() {
+        for &(width, height) in &[(1, 1), (2, 3), (4, 5), (7, 8)] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
+                    let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
+                    let fft = GoodThomasAlgorithm::new_normalized(
+                        Arc::clone(&width_fft),
+                        Arc::clone(&height_fft),
+                        normalization,
+                    );
+                    check_normalized_fft_algorithm(&fft, width * height, direction, normalization);
+
+                    let fft = GoodThomasAlgorithmSmall::new_normalized(
+                        width_fft,
+                        height_fft,
+                        normalization,
+                    );
+                    check_normalized_fft_algorithm(&fft, width * height, direction, normalization);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_output_mapping() {
         let width = 15;
@@ -565,7 +714,61 @@ mod unit_tests {
                     &mut outofplace_output,
                     &mut outofplace_scratch,
                 );
+
+                let immutable_input = vec![Complex::zero(); fft.len()];
+                let mut immutable_output = vec![Complex::zero(); fft.len()];
+                let mut immutable_scratch = vec![Complex::zero(); fft.get_immutable_scratch_len()];
+                fft.process_immutable(
+                    &immutable_input,
+                    &mut immutable_output,
+                    &mut immutable_scratch,
+                );
             }
         }
     }
+
+    #[test]
+    fn test_good_thomas_try_new() {
+        let width_fft = Arc::new(Dft::new(4, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        let height_fft = Arc::new(Dft::new(6, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        let inverse_fft = Arc::new(Dft::new(5, FftDirection::Inverse)) as Arc<dyn Fft<f32>>;
+
+        assert_eq!(
+            GoodThomasAlgorithm::try_new(Arc::clone(&width_fft), Arc::clone(&height_fft)).err(),
+            Some(FftError::NotCoprime {
+                width: 4,
+                height: 6
+            })
+        );
+        assert_eq!(
+            GoodThomasAlgorithmSmall::try_new(Arc::clone(&width_fft), Arc::clone(&height_fft))
+                .err(),
+            Some(FftError::NotCoprime {
+                width: 4,
+                height: 6
+            })
+        );
+        assert_eq!(
+            GoodThomasAlgorithm::try_new(Arc::clone(&width_fft), Arc::clone(&inverse_fft)).err(),
+            Some(FftError::MismatchedDirections {
+                width_direction: FftDirection::Forward,
+                height_direction: FftDirection::Inverse
+            })
+        );
+
+        let big_scratch_fft = Arc::new(BigScratchAlgorithm {
+            len: 5,
+            inplace_scratch: 0,
+            outofplace_scratch: 1,
+            direction: FftDirection::Forward,
+        }) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            GoodThomasAlgorithmSmall::try_new(width_fft, big_scratch_fft).err(),
+            Some(FftError::InnerFftScratchTooLarge {
+                len: 5,
+                inplace_scratch_len: 0,
+                outofplace_scratch_len: 1
+            })
+        );
+    }
 }
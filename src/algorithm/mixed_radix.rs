@@ -1,12 +1,17 @@
-use std::cmp::max;
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
 
 use num_complex::Complex;
+use num_traits::Zero;
 use transpose;
 
 use crate::array_utils;
-use crate::common::{fft_error_inplace, fft_error_outofplace};
-use crate::{common::FftNum, twiddles, FftDirection};
+use crate::common::{
+    fft_error_inplace, fft_error_outofplace, validate_directions, validate_small_inner_fft,
+};
+use crate::twiddles::TwiddleView;
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
 use crate::{Direction, Fft, Length};
 
 /// Implementation of the Mixed-Radix FFT algorithm
@@ -33,7 +38,7 @@ use crate::{Direction, Fft, Length};
 /// fft.process(&mut buffer);
 /// ~~~
 pub struct MixedRadix<T> {
-    twiddles: Box<[Complex<T>]>,
+    twiddles: TwiddleView<T>,
 
     width_size_fft: Arc<dyn Fft<T>>,
     width: usize,
@@ -44,16 +49,51 @@ pub struct MixedRadix<T> {
     inplace_scratch_len: usize,
     outofplace_scratch_len: usize,
 
+    nonzero_inputs: usize,
+    needed_outputs: usize,
+
     direction: FftDirection,
 }
 
 impl<T: FftNum> MixedRadix<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions.
     pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
-        assert_eq!(
-            width_fft.fft_direction(), height_fft.fft_direction(),
-            "width_fft and height_fft must have the same direction. got width direction={}, height direction={}",
-            width_fft.fft_direction(), height_fft.fft_direction());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns `Err(FftError::MismatchedDirections)` if `width_fft` and `height_fft` have different directions.
+    pub fn try_new(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+    ) -> Result<Self, FftError> {
+        let (width, height) = (width_fft.len(), height_fft.len());
+        let twiddles = Self::compute_twiddles(width, height, width_fft.fft_direction());
+        let twiddles = TwiddleView::new(twiddles, width, height);
+        Self::try_new_with_twiddles(width_fft, height_fft, twiddles)
+    }
+
+    // Creates a FFT instance that uses the provided twiddle factors, which must be a view of `compute_twiddles(width, height, direction)`,
+    // or of the twiddles of a larger FFT in the same direction. This lets instances share their twiddles
+    pub(crate) fn new_with_twiddles(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        twiddles: TwiddleView<T>,
+    ) -> Self {
+        Self::try_new_with_twiddles(width_fft, height_fft, twiddles)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub(crate) fn try_new_with_twiddles(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        twiddles: TwiddleView<T>,
+    ) -> Result<Self, FftError> {
+        validate_directions(width_fft.fft_direction(), height_fft.fft_direction())?;
 
         let direction = width_fft.fft_direction();
 
@@ -61,13 +101,15 @@ impl<T: FftNum> MixedRadix<T> {
         let height = height_fft.len();
 
         let len = width * height;
-
-        let mut twiddles = Vec::with_capacity(len);
-        for x in 0..width {
-            for y in 0..height {
-                twiddles.push(twiddles::compute_twiddle(x * y, len, direction));
-            }
-        }
+        assert_eq!(
+            (twiddles.width(), twiddles.height()),
+            (width, height),
+            "MixedRadix of size {}x{} got twiddle factors for size {}x{}",
+            width,
+            height,
+            twiddles.width(),
+            twiddles.height()
+        );
 
         // Collect some data about what kind of scratch space our inner FFTs need
         let height_inplace_scratch = height_fft.get_inplace_scratch_len();
@@ -102,8 +144,8 @@ impl<T: FftNum> MixedRadix<T> {
                 width_outofplace_scratch,
             );
 
-        Self {
-            twiddles: twiddles.into_boxed_slice(),
+        Ok(Self {
+            twiddles,
 
             width_size_fft: width_fft,
             width: width,
@@ -114,37 +156,154 @@ impl<T: FftNum> MixedRadix<T> {
             inplace_scratch_len,
             outofplace_scratch_len,
 
+            nonzero_inputs: len,
+            needed_outputs: len,
+
             direction,
+        })
+    }
+
+    /// Creates a pruned FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// A pruned FFT treats every input element at index `nonzero_inputs` or above as zero, ignoring its contents, and only guarantees
+    /// that the first `needed_outputs` output elements are correct. The remaining output elements will contain garbage.
+    ///
+    /// If `nonzero_inputs < width_fft.len()`, some of the size-`height` inner FFTs will only see zeroes, and will be skipped.
+    /// Likewise, if `needed_outputs < height_fft.len()`, some of the size-`width` inner FFTs will only produce unneeded outputs, and will be skipped.
+    ///
+    /// The inner FFTs may themselves be pruned: `width_fft` only needs to handle `min(nonzero_inputs, width)` nonzero inputs,
+    /// and `height_fft` only needs to produce `min(needed_outputs, height)` outputs. Likewise, `height_fft` only receives
+    /// `ceil(nonzero_inputs / width)` nonzero inputs, and only `ceil(needed_outputs / height)` outputs of `width_fft` are used.
+    ///
+    /// Values of `nonzero_inputs` and `needed_outputs` larger than the FFT length are treated as the FFT length.
+    pub fn new_pruned(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        result.prune(nonzero_inputs, needed_outputs);
+        result
+    }
+
+    // Makes this instance skip the work that `new_pruned` would skip
+    pub(crate) fn prune(&mut self, nonzero_inputs: usize, needed_outputs: usize) {
+        self.nonzero_inputs = min(nonzero_inputs, self.len());
+        self.needed_outputs = min(needed_outputs, self.len());
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scale is folded into the twiddle factors,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len(), result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`. The twiddles may be shared with other instances, so this makes a scaled copy of them
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        self.twiddles = self.twiddles.scaled(scale);
+    }
+
+    // Precomputes the twiddle factors for a mixed radix FFT with the given width and height. They're shared with `MixedRadixSmall`, which uses the same layout
+    pub(crate) fn compute_twiddles(
+        width: usize,
+        height: usize,
+        direction: FftDirection,
+    ) -> Arc<[Complex<T>]> {
+        let len = width * height;
+        let mut twiddles = Vec::with_capacity(len);
+        for x in 0..width {
+            for y in 0..height {
+                twiddles.push(twiddles::compute_twiddle(x * y, len, direction));
+            }
+        }
+        twiddles.into()
+    }
+
+    // Returns true if this instance was created with `new_pruned` and actually has some work to skip
+    fn is_pruned(&self) -> bool {
+        self.nonzero_inputs < self.len() || self.needed_outputs < self.len()
+    }
+
+    // Multiplies the output of the height FFTs by our twiddle factors.
+    // If we're pruned, we only have to twiddle columns whose height FFTs were computed, and rows whose width FFTs will be computed
+    fn apply_twiddles(&self, data: &mut [Complex<T>]) {
+        if self.is_pruned() {
+            let nonzero_columns = min(self.nonzero_inputs, self.width);
+            let needed_rows = min(self.needed_outputs, self.height);
+            for (x, column) in data
+                .chunks_exact_mut(self.height)
+                .take(nonzero_columns)
+                .enumerate()
+            {
+                for (element, twiddle) in column[..needed_rows].iter_mut().zip(self.twiddles.row(x))
+                {
+                    *element = *element * twiddle;
+                }
+            }
+        } else {
+            self.twiddles.apply(data);
         }
     }
 
     fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        if self.needed_outputs == 0 {
+            // None of the outputs are needed, so there's nothing to compute
+            return;
+        }
+        if self.nonzero_inputs == 0 {
+            // Every input is zero, so every output is zero too
+            for element in buffer.iter_mut() {
+                *element = Zero::zero();
+            }
+            return;
+        }
+
         // SIX STEP FFT:
         let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
 
+        // If we're pruned, overwrite the ignored inputs with zeroes before they get mixed in with the rest of the data
+        for element in buffer[self.nonzero_inputs..].iter_mut() {
+            *element = Zero::zero();
+        }
+
         // STEP 1: transpose
         transpose::transpose(buffer, scratch, self.width, self.height);
 
-        // STEP 2: perform FFTs of size `height`
+        // STEP 2: perform FFTs of size `height`. If we're pruned, columns past `nonzero_inputs` are all zeroes, so we can skip them
+        let nonzero_columns = min(self.nonzero_inputs, self.width);
         let height_scratch = if inner_scratch.len() > buffer.len() {
             &mut inner_scratch[..]
         } else {
             &mut buffer[..]
         };
-        self.height_size_fft
-            .process_with_scratch(scratch, height_scratch);
+        self.height_size_fft.process_with_scratch(
+            &mut scratch[..nonzero_columns * self.height],
+            height_scratch,
+        );
 
         // STEP 3: Apply twiddle factors
-        for (element, twiddle) in scratch.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        self.apply_twiddles(scratch);
 
         // STEP 4: transpose again
         transpose::transpose(scratch, buffer, self.height, self.width);
 
-        // STEP 5: perform FFTs of size `width`
-        self.width_size_fft
-            .process_outofplace_with_scratch(buffer, scratch, inner_scratch);
+        // STEP 5: perform FFTs of size `width`. If we're pruned, rows past `needed_outputs` would only produce unneeded outputs, so we can skip them
+        let needed_len = min(self.needed_outputs, self.height) * self.width;
+        self.width_size_fft.process_outofplace_with_scratch(
+            &mut buffer[..needed_len],
+            &mut scratch[..needed_len],
+            inner_scratch,
+        );
 
         // STEP 6: transpose again
         transpose::transpose(scratch, buffer, self.width, self.height);
@@ -156,46 +315,126 @@ impl<T: FftNum> MixedRadix<T> {
         output: &mut [Complex<T>],
         scratch: &mut [Complex<T>],
     ) {
+        if self.needed_outputs == 0 {
+            // None of the outputs are needed, so there's nothing to compute
+            return;
+        }
+        if self.nonzero_inputs == 0 {
+            // Every input is zero, so every output is zero too
+            for element in output.iter_mut() {
+                *element = Zero::zero();
+            }
+            return;
+        }
+
         // SIX STEP FFT:
 
+        // If we're pruned, overwrite the ignored inputs with zeroes before they get mixed in with the rest of the data
+        for element in input[self.nonzero_inputs..].iter_mut() {
+            *element = Zero::zero();
+        }
+
         // STEP 1: transpose
         transpose::transpose(input, output, self.width, self.height);
 
-        // STEP 2: perform FFTs of size `height`
+        // STEP 2: perform FFTs of size `height`. If we're pruned, columns past `nonzero_inputs` are all zeroes, so we can skip them
+        let nonzero_columns = min(self.nonzero_inputs, self.width);
         let height_scratch = if scratch.len() > input.len() {
             &mut scratch[..]
         } else {
             &mut input[..]
         };
         self.height_size_fft
-            .process_with_scratch(output, height_scratch);
+            .process_with_scratch(&mut output[..nonzero_columns * self.height], height_scratch);
 
         // STEP 3: Apply twiddle factors
-        for (element, twiddle) in output.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        self.apply_twiddles(output);
 
         // STEP 4: transpose again
         transpose::transpose(output, input, self.height, self.width);
 
-        // STEP 5: perform FFTs of size `width`
+        // STEP 5: perform FFTs of size `width`. If we're pruned, rows past `needed_outputs` would only produce unneeded outputs, so we can skip them
+        let needed_len = min(self.needed_outputs, self.height) * self.width;
         let width_scratch = if scratch.len() > output.len() {
             &mut scratch[..]
         } else {
             &mut output[..]
         };
         self.width_size_fft
-            .process_with_scratch(input, width_scratch);
+            .process_with_scratch(&mut input[..needed_len], width_scratch);
 
         // STEP 6: transpose again
         transpose::transpose(input, output, self.width, self.height);
     }
+
+    fn perform_fft_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        if self.needed_outputs == 0 {
+            // None of the outputs are needed, so there's nothing to compute
+            return;
+        }
+        if self.nonzero_inputs == 0 {
+            // Every input is zero, so every output is zero too
+            for element in output.iter_mut() {
+                *element = Zero::zero();
+            }
+            return;
+        }
+
+        // SIX STEP FFT:
+        // This is the same as the out-of-place FFT, except we can't use the input as scratch space, so we use our own scratch buffer instead
+        let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
+
+        // STEP 1: transpose
+        transpose::transpose(input, output, self.width, self.height);
+
+        // If we're pruned, overwrite the ignored inputs with zeroes before they get mixed in with the rest of the data.
+        // We can't write to the input, so zero them out at their transposed location instead
+        for input_index in self.nonzero_inputs..self.len() {
+            let (row, column) = (input_index / self.width, input_index % self.width);
+            output[column * self.height + row] = Zero::zero();
+        }
+
+        // STEP 2: perform FFTs of size `height`. If we're pruned, columns past `nonzero_inputs` are all zeroes, so we can skip them
+        let nonzero_columns = min(self.nonzero_inputs, self.width);
+        let height_scratch = if inner_scratch.len() > scratch.len() {
+            &mut inner_scratch[..]
+        } else {
+            &mut scratch[..]
+        };
+        self.height_size_fft
+            .process_with_scratch(&mut output[..nonzero_columns * self.height], height_scratch);
+
+        // STEP 3: Apply twiddle factors
+        self.apply_twiddles(output);
+
+        // STEP 4: transpose again
+        transpose::transpose(output, scratch, self.height, self.width);
+
+        // STEP 5: perform FFTs of size `width`. If we're pruned, rows past `needed_outputs` would only produce unneeded outputs, so we can skip them
+        let needed_len = min(self.needed_outputs, self.height) * self.width;
+        let width_scratch = if inner_scratch.len() > output.len() {
+            &mut inner_scratch[..]
+        } else {
+            &mut output[..]
+        };
+        self.width_size_fft
+            .process_with_scratch(&mut scratch[..needed_len], width_scratch);
+
+        // STEP 6: transpose again
+        transpose::transpose(scratch, output, self.width, self.height);
+    }
 }
 boilerplate_fft!(
     MixedRadix,
-    |this: &MixedRadix<_>| this.twiddles.len(),
+    |this: &MixedRadix<_>| this.width * this.height,
     |this: &MixedRadix<_>| this.inplace_scratch_len,
-    |this: &MixedRadix<_>| this.outofplace_scratch_len
+    |this: &MixedRadix<_>| this.outofplace_scratch_len,
+    |this: &MixedRadix<_>| this.width * this.height + this.outofplace_scratch_len
 );
 
 /// Implementation of the Mixed-Radix FFT algorithm, specialized for smaller input sizes
@@ -225,7 +464,7 @@ boilerplate_fft!(
 /// fft.process(&mut buffer);
 /// ~~~
 pub struct MixedRadixSmall<T> {
-    twiddles: Box<[Complex<T>]>,
+    twiddles: TwiddleView<T>,
 
     width_size_fft: Arc<dyn Fft<T>>,
     width: usize,
@@ -238,34 +477,64 @@ pub struct MixedRadixSmall<T> {
 
 impl<T: FftNum> MixedRadixSmall<T> {
     /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions, or if either of them requires any out-of-place scratch,
+    /// or more in-place scratch than its own length.
     pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
-        assert_eq!(
-            width_fft.fft_direction(), height_fft.fft_direction(),
-            "width_fft and height_fft must have the same direction. got width direction={}, height direction={}",
-            width_fft.fft_direction(), height_fft.fft_direction());
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns `Err(FftError::MismatchedDirections)` if `width_fft` and `height_fft` have different directions, or
+    /// `Err(FftError::InnerFftScratchTooLarge)` if either of them requires any out-of-place scratch, or more in-place scratch than its own length.
+    pub fn try_new(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+    ) -> Result<Self, FftError> {
+        let (width, height) = (width_fft.len(), height_fft.len());
+        let twiddles = MixedRadix::compute_twiddles(width, height, width_fft.fft_direction());
+        let twiddles = TwiddleView::new(twiddles, width, height);
+        Self::try_new_with_twiddles(width_fft, height_fft, twiddles)
+    }
+
+    // Creates a FFT instance that uses the provided twiddle factors. See `MixedRadix::new_with_twiddles`
+    pub(crate) fn new_with_twiddles(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        twiddles: TwiddleView<T>,
+    ) -> Self {
+        Self::try_new_with_twiddles(width_fft, height_fft, twiddles)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    pub(crate) fn try_new_with_twiddles(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        twiddles: TwiddleView<T>,
+    ) -> Result<Self, FftError> {
+        validate_directions(width_fft.fft_direction(), height_fft.fft_direction())?;
 
         // Verify that the inner FFTs don't require out-of-place scratch, and only arequire a small amount of inplace scratch
         let width = width_fft.len();
         let height = height_fft.len();
-        let len = width * height;
-
-        assert_eq!(width_fft.get_outofplace_scratch_len(), 0, "MixedRadixSmall should only be used with algorithms that require 0 out-of-place scratch. Width FFT (len={}) requires {}, should require 0", width, width_fft.get_outofplace_scratch_len());
-        assert_eq!(height_fft.get_outofplace_scratch_len(), 0, "MixedRadixSmall should only be used with algorithms that require 0 out-of-place scratch. Height FFT (len={}) requires {}, should require 0", height, height_fft.get_outofplace_scratch_len());
-
-        assert!(width_fft.get_inplace_scratch_len() <= width, "MixedRadixSmall should only be used with algorithms that require little inplace scratch. Width FFT (len={}) requires {}, should require {} or less", width, width_fft.get_inplace_scratch_len(), width);
-        assert!(height_fft.get_inplace_scratch_len() <= height, "MixedRadixSmall should only be used with algorithms that require little inplace scratch. Height FFT (len={}) requires {}, should require {} or less", height, height_fft.get_inplace_scratch_len(), height);
+        validate_small_inner_fft(width_fft.as_ref())?;
+        validate_small_inner_fft(height_fft.as_ref())?;
 
         let direction = width_fft.fft_direction();
-
-        let mut twiddles = Vec::with_capacity(len);
-        for x in 0..width {
-            for y in 0..height {
-                twiddles.push(twiddles::compute_twiddle(x * y, len, direction));
-            }
-        }
-
-        Self {
-            twiddles: twiddles.into_boxed_slice(),
+        assert_eq!(
+            (twiddles.width(), twiddles.height()),
+            (width, height),
+            "MixedRadixSmall of size {}x{} got twiddle factors for size {}x{}",
+            width,
+            height,
+            twiddles.width(),
+            twiddles.height()
+        );
+
+        Ok(Self {
+            twiddles,
 
             width_size_fft: width_fft,
             width: width,
@@ -274,7 +543,27 @@ impl<T: FftNum> MixedRadixSmall<T> {
             height: height,
 
             direction,
-        }
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scale is folded into the twiddle factors,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len(), result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`. The twiddles may be shared with other instances, so this makes a scaled copy of them
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        self.twiddles = self.twiddles.scaled(scale);
     }
 
     fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
@@ -286,9 +575,7 @@ impl<T: FftNum> MixedRadixSmall<T> {
         self.height_size_fft.process_with_scratch(scratch, buffer);
 
         // STEP 3: Apply twiddle factors
-        for (element, twiddle) in scratch.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        self.twiddles.apply(scratch);
 
         // STEP 4: transpose again
         unsafe { array_utils::transpose_small(self.height, self.width, scratch, buffer) };
@@ -315,9 +602,7 @@ impl<T: FftNum> MixedRadixSmall<T> {
         self.height_size_fft.process_with_scratch(output, input);
 
         // STEP 3: Apply twiddle factors
-        for (element, twiddle) in output.iter_mut().zip(self.twiddles.iter()) {
-            *element = *element * twiddle;
-        }
+        self.twiddles.apply(output);
 
         // STEP 4: transpose again
         unsafe { array_utils::transpose_small(self.height, self.width, output, input) };
@@ -331,7 +616,7 @@ impl<T: FftNum> MixedRadixSmall<T> {
 }
 boilerplate_fft!(
     MixedRadixSmall,
-    |this: &MixedRadixSmall<_>| this.twiddles.len(),
+    |this: &MixedRadixSmall<_>| this.width * this.height,
     |this: &MixedRadixSmall<_>| this.len(),
     |_| 0
 );
@@ -339,10 +624,12 @@ boilerplate_fft!(
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use crate::test_utils::check_fft_algorithm;
+    use crate::test_utils::{
+        check_fft_algorithm, check_normalized_fft_algorithm, check_pruned_fft_algorithm,
+    };
     use crate::{algorithm::Dft, test_utils::BigScratchAlgorithm};
+    use alloc::sync::Arc;
     use num_traits::Zero;
-    use std::sync::Arc;
 
     #[test]
     fn test_mixed_radix() {
@@ -364,6 +651,39 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_mixed_radix_normalized() {
+        for &(width, height) in &[(1, 1), (2, 3), (4, 5), (6, 6)] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
+                    let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
+                    let fft = MixedRadix::new_normalized(
+                        Arc::clone(&width_fft),
+                        Arc::clone(&height_fft),
+                        normalization,
+                    );
+                    check_normalized_fft_algorithm(&fft, width * height, direction, normalization);
+
+                    if width > 1 && height > 1 {
+                        let fft =
+                            MixedRadixSmall::new_normalized(width_fft, height_fft, normalization);
+                        check_normalized_fft_algorithm(
+                            &fft,
+                            width * height,
+                            direction,
+                            normalization,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
     fn test_mixed_radix_with_lengths(width: usize, height: usize, direction: FftDirection) {
         let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
         let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
@@ -373,6 +693,50 @@ mod unit_tests {
         check_fft_algorithm(&fft, width * height, direction);
     }
 
+    #[test]
+    fn test_mixed_radix_pruned() {
+        for width in 1..7 {
+            for height in 1..7 {
+                let len = width * height;
+                for nonzero_inputs in 0..=len {
+                    for &needed_outputs in &[0, 1, height - 1, height + 1, len / 2, len] {
+                        test_mixed_radix_pruned_with_lengths(
+                            width,
+                            height,
+                            nonzero_inputs,
+                            needed_outputs,
+                            FftDirection::Forward,
+                        );
+                        test_mixed_radix_pruned_with_lengths(
+                            width,
+                            height,
+                            nonzero_inputs,
+                            needed_outputs,
+                            FftDirection::Inverse,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn test_mixed_radix_pruned_with_lengths(
+        width: usize,
+        height: usize,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+        direction: FftDirection,
+    ) {
+        let len = width * height;
+        let needed_outputs = needed_outputs.min(len);
+        let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
+        let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
+
+        let fft = MixedRadix::new_pruned(width_fft, height_fft, nonzero_inputs, needed_outputs);
+
+        check_pruned_fft_algorithm(&fft, len, direction, nonzero_inputs, needed_outputs, true);
+    }
+
     fn test_mixed_radix_small_with_lengths(width: usize, height: usize, direction: FftDirection) {
         let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
         let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
@@ -420,7 +784,52 @@ mod unit_tests {
                     &mut outofplace_output,
                     &mut outofplace_scratch,
                 );
+
+                let immutable_input = vec![Complex::zero(); fft.len()];
+                let mut immutable_output = vec![Complex::zero(); fft.len()];
+                let mut immutable_scratch = vec![Complex::zero(); fft.get_immutable_scratch_len()];
+                fft.process_immutable(
+                    &immutable_input,
+                    &mut immutable_output,
+                    &mut immutable_scratch,
+                );
             }
         }
     }
+
+    #[test]
+    fn test_mixed_radix_try_new() {
+        let width_fft = Arc::new(Dft::new(4, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        let height_fft = Arc::new(Dft::new(6, FftDirection::Inverse)) as Arc<dyn Fft<f32>>;
+
+        assert_eq!(
+            MixedRadix::try_new(Arc::clone(&width_fft), Arc::clone(&height_fft)).err(),
+            Some(FftError::MismatchedDirections {
+                width_direction: FftDirection::Forward,
+                height_direction: FftDirection::Inverse
+            })
+        );
+        assert_eq!(
+            MixedRadixSmall::try_new(Arc::clone(&width_fft), Arc::clone(&height_fft)).err(),
+            Some(FftError::MismatchedDirections {
+                width_direction: FftDirection::Forward,
+                height_direction: FftDirection::Inverse
+            })
+        );
+
+        let big_scratch_fft = Arc::new(BigScratchAlgorithm {
+            len: 6,
+            inplace_scratch: 7,
+            outofplace_scratch: 0,
+            direction: FftDirection::Forward,
+        }) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            MixedRadixSmall::try_new(width_fft, big_scratch_fft).err(),
+            Some(FftError::InnerFftScratchTooLarge {
+                len: 6,
+                inplace_scratch_len: 7,
+                outofplace_scratch_len: 0
+            })
+        );
+    }
 }
@@ -1,16 +1,31 @@
 mod bluesteins_algorithm;
 mod dft;
+#[cfg(feature = "const-generics")]
+mod const_size;
 mod good_thomas_algorithm;
 mod mixed_radix;
 mod raders_algorithm;
 mod radix4;
+mod radix8;
+mod scaled_fft;
+mod six_step;
+mod split_radix;
+mod stockham;
 
 /// Hardcoded size-specfic FFT algorithms
 pub mod butterflies;
 
 pub use self::bluesteins_algorithm::BluesteinsAlgorithm;
 pub use self::dft::Dft;
+#[cfg(feature = "const-generics")]
+pub use self::const_size::ConstSizeFft;
 pub use self::good_thomas_algorithm::{GoodThomasAlgorithm, GoodThomasAlgorithmSmall};
 pub use self::mixed_radix::{MixedRadix, MixedRadixSmall};
-pub use self::raders_algorithm::RadersAlgorithm;
+pub use self::raders_algorithm::{RadersAlgorithm, RadersAlgorithmPadded};
 pub use self::radix4::Radix4;
+pub use self::radix8::Radix8;
+pub use self::six_step::SixStep;
+pub use self::split_radix::SplitRadix;
+pub use self::stockham::Stockham;
+
+pub(crate) use self::scaled_fft::ScaledFft;
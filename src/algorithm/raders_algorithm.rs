@@ -1,15 +1,16 @@
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 
 use num_complex::Complex;
 use num_integer::Integer;
 use num_traits::Zero;
-use primal_check::miller_rabin;
 use strength_reduce::StrengthReducedUsize;
 
 use crate::array_utils;
 use crate::common::{fft_error_inplace, fft_error_outofplace};
 use crate::math_utils;
-use crate::{common::FftNum, twiddles, FftDirection};
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
 use crate::{Direction, Fft, Length};
 
 /// Implementation of Rader's Algorithm
@@ -47,6 +48,10 @@ pub struct RadersAlgorithm<T> {
     primitive_root: usize,
     primitive_root_inverse: usize,
 
+    // Every output gets multiplied by this. Most of the scale is folded into `inner_fft_data`, but the first output
+    // and the DC term of the second inner FFT don't pass through it, so they have to be scaled separately
+    scale: T,
+
     len: StrengthReducedUsize,
     inplace_scratch_len: usize,
     outofplace_scratch_len: usize,
@@ -63,9 +68,18 @@ impl<T: FftNum> RadersAlgorithm<T> {
     /// # Panics
     /// Panics if `inner_fft.len() + 1` is not a prime number.
     pub fn new(inner_fft: Arc<dyn Fft<T>>) -> Self {
+        Self::try_new(inner_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `inner_fft.len() + 1`.
+    ///
+    /// Returns `Err(FftError::NotPrime)` if `inner_fft.len() + 1` is not a prime number.
+    pub fn try_new(inner_fft: Arc<dyn Fft<T>>) -> Result<Self, FftError> {
         let inner_fft_len = inner_fft.len();
         let len = inner_fft_len + 1;
-        assert!(miller_rabin(len as u64), "For raders algorithm, inner_fft.len() + 1 must be prime. Expected prime number, got {} + 1 = {}", inner_fft_len, len);
+        if !math_utils::is_prime(len as u64) {
+            return Err(FftError::NotPrime { len });
+        }
 
         let direction = inner_fft.fft_direction();
         let reduced_len = StrengthReducedUsize::new(len);
@@ -84,7 +98,11 @@ impl<T: FftNum> RadersAlgorithm<T> {
         } as usize;
 
         // precompute the coefficients to use inside the process method
-        let unity_scale = T::from_f64(1f64 / inner_fft_len as f64).unwrap();
+        let unity_scale: T = twiddles::compute_scale_factor(
+            FftNormalization::ForwardByN,
+            inner_fft_len,
+            FftDirection::Forward,
+        );
         let mut inner_fft_input = vec![Complex::zero(); inner_fft_len];
         let mut twiddle_input = 1;
         for input_cell in &mut inner_fft_input {
@@ -105,18 +123,42 @@ impl<T: FftNum> RadersAlgorithm<T> {
         let mut inner_fft_scratch = vec![Zero::zero(); required_inner_scratch];
         inner_fft.process_with_scratch(&mut inner_fft_input, &mut inner_fft_scratch);
 
-        Self {
+        Ok(Self {
             inner_fft,
             inner_fft_data: inner_fft_input.into_boxed_slice(),
 
             primitive_root,
             primitive_root_inverse,
 
+            scale: T::one(),
+
             len: reduced_len,
             inplace_scratch_len: inner_fft_len + extra_inner_scratch,
             outofplace_scratch_len: extra_inner_scratch,
             direction,
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `inner_fft.len() + 1`.
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. Most of the scale is folded into the precomputed
+    /// data, so it doesn't require an extra pass over the data.
+    ///
+    /// # Panics
+    /// Panics if `inner_fft.len() + 1` is not a prime number.
+    pub fn new_normalized(inner_fft: Arc<dyn Fft<T>>, normalization: FftNormalization) -> Self {
+        let mut result = Self::new(inner_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len(), result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        for data in self.inner_fft_data.iter_mut() {
+            *data = *data * scale;
         }
+        self.scale = self.scale * scale;
     }
 
     fn perform_fft_out_of_place(
@@ -147,7 +189,7 @@ impl<T: FftNum> RadersAlgorithm<T> {
         self.inner_fft.process_with_scratch(output, inner_scratch);
 
         // output[0] now contains the sum of elements 1..len. We need the sum of all elements, so all we have to do is add the first input
-        *output_first = *input_first + output[0];
+        *output_first = (*input_first + output[0]) * self.scale;
 
         // multiply the inner result with our cached setup data
         // also conjugate every entry. this sets us up to do an inverse FFT
@@ -162,7 +204,7 @@ impl<T: FftNum> RadersAlgorithm<T> {
 
         // We need to add the first input value to all output values. We can accomplish this by adding it to the DC input of our inner ifft.
         // Of course, we have to conjugate it, just like we conjugated the complex multiplied above
-        input[0] = input[0] + input_first.conj();
+        input[0] = input[0] + (*input_first * self.scale).conj();
 
         // execute the second FFT
         let inner_scratch = if scratch.len() > 0 {
@@ -204,7 +246,7 @@ impl<T: FftNum> RadersAlgorithm<T> {
         self.inner_fft.process_with_scratch(scratch, inner_scratch);
 
         // scratch[0] now contains the sum of elements 1..len. We need the sum of all elements, so all we have to do is add the first input
-        *buffer_first = *buffer_first + scratch[0];
+        *buffer_first = (*buffer_first + scratch[0]) * self.scale;
 
         // multiply the inner result with our cached setup data
         // also conjugate every entry. this sets us up to do an inverse FFT
@@ -215,7 +257,7 @@ impl<T: FftNum> RadersAlgorithm<T> {
 
         // We need to add the first input value to all output values. We can accomplish this by adding it to the DC input of our inner ifft.
         // Of course, we have to conjugate it, just like we conjugated the complex multiplied above
-        scratch[0] = scratch[0] + buffer_first_val.conj();
+        scratch[0] = scratch[0] + (buffer_first_val * self.scale).conj();
 
         // execute the second FFT
         self.inner_fft.process_with_scratch(scratch, inner_scratch);
@@ -235,17 +277,303 @@ boilerplate_fft!(
     |this: &RadersAlgorithm<_>| this.outofplace_scratch_len
 );
 
+/// Implementation of Rader's Algorithm, with zero-padded inner FFTs
+///
+/// [`RadersAlgorithm`](crate::algorithm::RadersAlgorithm) converts a prime-sized FFT of size N into a cyclic convolution of size N - 1,
+/// and computes that convolution with FFTs of size N - 1. When N - 1 has a large prime factor, those FFTs are slow.
+///
+/// This algorithm instead takes advantage of a symmetry in Rader's convolution: The second half of its reordered twiddle factors
+/// are the complex conjugates of the first half. That splits the size-(N - 1) convolution into two convolutions of size (N - 1) / 2,
+/// one cyclic and one negacyclic, which are zero-padded like Bluestein's Algorithm so that they can use an inner FFT of any size
+/// M >= N - 2. Bluestein's Algorithm needs an inner FFT of size at least 2N - 1, so this computes twice as many inner FFTs, each about half as long.
+///
+/// ~~~
+/// // Computes a forward FFT of size 1439 (prime number). 1438 = 2 * 719, so a 1438-sized inner FFT would be slow
+/// use rustfft::algorithm::RadersAlgorithmPadded;
+/// use rustfft::{Fft, FftPlanner};
+/// use rustfft::num_complex::Complex;
+///
+/// let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 1439];
+///
+/// // We need an inner FFT whose size is at least 1439 - 2 = 1437. 1440 = 2^5 * 3^2 * 5 is a fast choice
+/// let mut planner = FftPlanner::new();
+/// let inner_fft = planner.plan_fft_forward(1440);
+///
+/// let fft = RadersAlgorithmPadded::new(1439, inner_fft);
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct RadersAlgorithmPadded<T> {
+    inner_fft: Arc<dyn Fft<T>>,
+
+    // The spectra of the cyclic and negacyclic kernels, one after the other
+    inner_fft_data: Box<[Complex<T>]>,
+
+    // The input index that goes to each element of the convolution, and the output index that each element of the convolution goes to.
+    // Looking these up is faster than computing them with modular arithmetic as we go, like RadersAlgorithm does
+    input_indexes: Box<[usize]>,
+    output_indexes: Box<[usize]>,
+
+    // Every output gets multiplied by this, for the same reasons as in RadersAlgorithm
+    scale: T,
+
+    len: usize,
+    direction: FftDirection,
+}
+
+impl<T: FftNum> RadersAlgorithmPadded<T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len - 2`
+    ///
+    /// Note that this constructor is quite expensive to run; This algorithm must compute two FFTs using `inner_fft` within the
+    /// constructor.
+    ///
+    /// # Panics
+    /// Panics if `len` is not a prime number, or if `inner_fft.len() < len - 2`.
+    pub fn new(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Self {
+        Self::try_new(len, inner_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len - 2`
+    ///
+    /// Returns `Err(FftError::NotPrime)` if `len` is not a prime number, and `Err(FftError::InnerFftTooSmall)` if `inner_fft.len() < len - 2`.
+    pub fn try_new(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Result<Self, FftError> {
+        if !math_utils::is_prime(len as u64) {
+            return Err(FftError::NotPrime { len });
+        }
+        let convolution_len = len - 1;
+
+        // Each half-size convolution is zero-padded, so the inner FFT needs room for twice its length minus one
+        let half_len = convolution_len / 2;
+        let inner_fft_len = inner_fft.len();
+        if half_len * 2 - 1 > inner_fft_len {
+            return Err(FftError::InnerFftTooSmall {
+                expected_len: half_len * 2 - 1,
+                actual_len: inner_fft_len,
+            });
+        }
+
+        let direction = inner_fft.fft_direction();
+        let reduced_len = StrengthReducedUsize::new(len);
+
+        let primitive_root = math_utils::primitive_root(len as u64).unwrap() as usize;
+        let gcd_data = i64::extended_gcd(&(primitive_root as i64), &(len as i64));
+        let primitive_root_inverse = if gcd_data.x >= 0 {
+            gcd_data.x
+        } else {
+            gcd_data.x + len as i64
+        } as usize;
+
+        // RadersAlgorithm convolves with the twiddle factors reordered by powers of the primitive root's inverse. g^((N - 1) / 2) = -1 mod N,
+        // so the second half of those twiddles are the conjugates of the first half. That means the convolution splits into a cyclic convolution
+        // of (first half + second half) with the real parts, and a negacyclic convolution of (first half - second half) with the imaginary parts.
+        // Both get zero-padded, so to make them wrap around at half_len, we repeat every kernel element but the first at the end, negated for the negacyclic one
+        let unity_scale: T = twiddles::compute_scale_factor(
+            FftNormalization::ForwardByN,
+            inner_fft_len,
+            FftDirection::Forward,
+        );
+        let mut inner_fft_input = vec![Complex::zero(); inner_fft_len * 2];
+        {
+            let (cyclic_kernel, negacyclic_kernel) = inner_fft_input.split_at_mut(inner_fft_len);
+            let mut twiddle_input = 1;
+            for i in 0..half_len {
+                let twiddle =
+                    twiddles::compute_twiddle(twiddle_input, len, direction) * unity_scale;
+                let real = Complex::new(twiddle.re, T::zero());
+                let imaginary = Complex::new(T::zero(), twiddle.im);
+                cyclic_kernel[i] = real;
+                negacyclic_kernel[i] = imaginary;
+                if i > 0 {
+                    cyclic_kernel[inner_fft_len - half_len + i] = real;
+                    negacyclic_kernel[inner_fft_len - half_len + i] = -imaginary;
+                }
+
+                twiddle_input = (twiddle_input * primitive_root_inverse) % reduced_len;
+            }
+        }
+
+        let mut inner_fft_scratch = vec![Complex::zero(); inner_fft.get_inplace_scratch_len()];
+        inner_fft.process_with_scratch(&mut inner_fft_input, &mut inner_fft_scratch);
+
+        let mut input_indexes = Vec::with_capacity(convolution_len);
+        let mut output_indexes = Vec::with_capacity(convolution_len);
+        let (mut input_index, mut output_index) = (1, 1);
+        for _ in 0..convolution_len {
+            input_index = (input_index * primitive_root) % reduced_len;
+            output_index = (output_index * primitive_root_inverse) % reduced_len;
+            input_indexes.push(input_index);
+            output_indexes.push(output_index);
+        }
+
+        Ok(Self {
+            inner_fft,
+            inner_fft_data: inner_fft_input.into_boxed_slice(),
+
+            input_indexes: input_indexes.into_boxed_slice(),
+            output_indexes: output_indexes.into_boxed_slice(),
+
+            scale: T::one(),
+
+            len,
+            direction,
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `len`. `inner_fft.len()` must be >= `len - 2`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. Most of the scale is folded into the precomputed
+    /// data, so it doesn't require an extra pass over the data.
+    ///
+    /// # Panics
+    /// Panics if `len` is not a prime number, or if `inner_fft.len() < len - 2`.
+    pub fn new_normalized(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, inner_fft);
+        let scale = twiddles::compute_scale_factor(normalization, len, result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        for data in self.inner_fft_data.iter_mut() {
+            *data = *data * scale;
+        }
+        self.scale = self.scale * scale;
+    }
+
+    // Computes both half-size convolutions into `inner_buffer`, one after the other, and returns the first output. This only reads from `input`,
+    // so afterwards, `input` can be overwritten with the output
+    fn convolve(
+        &self,
+        input: &[Complex<T>],
+        inner_buffer: &mut [Complex<T>],
+        inner_scratch: &mut [Complex<T>],
+    ) -> Complex<T> {
+        let inner_fft_len = self.inner_fft.len();
+        let half_len = self.input_indexes.len() / 2;
+        let (first_indexes, second_indexes) = self.input_indexes.split_at(half_len);
+        {
+            // copy the sum and difference of the two halves of the input into the inner buffers, reordering as we go, then zero-pad the rest
+            let (cyclic_buffer, negacyclic_buffer) = inner_buffer.split_at_mut(inner_fft_len);
+            for (((cyclic_element, negacyclic_element), &first_index), &second_index) in
+                cyclic_buffer
+                    .iter_mut()
+                    .zip(negacyclic_buffer.iter_mut())
+                    .zip(first_indexes.iter())
+                    .zip(second_indexes.iter())
+            {
+                let (first, second) = (input[first_index], input[second_index]);
+                *cyclic_element = first + second;
+                *negacyclic_element = first - second;
+            }
+            for (cyclic_element, negacyclic_element) in cyclic_buffer
+                .iter_mut()
+                .zip(negacyclic_buffer.iter_mut())
+                .skip(half_len)
+            {
+                *cyclic_element = Complex::zero();
+                *negacyclic_element = Complex::zero();
+            }
+        }
+
+        self.inner_fft
+            .process_with_scratch(inner_buffer, inner_scratch);
+
+        // inner_buffer[0] now contains the sum of elements 1..len. Add the first input to get the first output
+        let first_output = (input[0] + inner_buffer[0]) * self.scale;
+
+        // multiply by our precomputed data, and conjugate to set up for an inverse FFT, like RadersAlgorithm does.
+        // Adding the first input to the DC term of the cyclic convolution adds it to every output
+        for (inner_element, &multiple) in inner_buffer.iter_mut().zip(self.inner_fft_data.iter()) {
+            *inner_element = (*inner_element * multiple).conj();
+        }
+        inner_buffer[0] = inner_buffer[0] + (input[0] * self.scale).conj();
+
+        self.inner_fft
+            .process_with_scratch(inner_buffer, inner_scratch);
+
+        first_output
+    }
+
+    // Combines the two convolutions computed by `convolve` and copies them to the output, reordering as we go. The padded parts of the convolutions are discarded
+    fn store_output(
+        &self,
+        first_output: Complex<T>,
+        inner_buffer: &[Complex<T>],
+        output: &mut [Complex<T>],
+    ) {
+        let (cyclic_buffer, negacyclic_buffer) = inner_buffer.split_at(self.inner_fft.len());
+        let (first_indexes, second_indexes) = self.output_indexes.split_at(self.output_indexes.len() / 2);
+
+        output[0] = first_output;
+        for ((&cyclic_element, &negacyclic_element), &first_index) in cyclic_buffer
+            .iter()
+            .zip(negacyclic_buffer.iter())
+            .zip(first_indexes.iter())
+        {
+            output[first_index] = (cyclic_element + negacyclic_element).conj();
+        }
+        for ((&cyclic_element, &negacyclic_element), &second_index) in cyclic_buffer
+            .iter()
+            .zip(negacyclic_buffer.iter())
+            .zip(second_indexes.iter())
+        {
+            output[second_index] = (cyclic_element - negacyclic_element).conj();
+        }
+    }
+
+    fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        let (inner_buffer, inner_scratch) = scratch.split_at_mut(self.inner_fft_data.len());
+        let first_output = self.convolve(buffer, inner_buffer, inner_scratch);
+        self.store_output(first_output, inner_buffer, buffer);
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        // This algorithm never writes to its input, so the out-of-place FFT is the same as the immutable FFT
+        self.perform_fft_immutable(input, output, scratch)
+    }
+
+    fn perform_fft_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        let (inner_buffer, inner_scratch) = scratch.split_at_mut(self.inner_fft_data.len());
+        let first_output = self.convolve(input, inner_buffer, inner_scratch);
+        self.store_output(first_output, inner_buffer, output);
+    }
+}
+boilerplate_fft!(
+    RadersAlgorithmPadded,
+    |this: &RadersAlgorithmPadded<_>| this.len,
+    |this: &RadersAlgorithmPadded<_>| this.inner_fft_data.len()
+        + this.inner_fft.get_inplace_scratch_len(),
+    |this: &RadersAlgorithmPadded<_>| this.inner_fft_data.len()
+        + this.inner_fft.get_inplace_scratch_len(),
+    |this: &RadersAlgorithmPadded<_>| this.inner_fft_data.len()
+        + this.inner_fft.get_inplace_scratch_len()
+);
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
     use crate::algorithm::Dft;
-    use crate::test_utils::check_fft_algorithm;
-    use std::sync::Arc;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+    use alloc::sync::Arc;
 
     #[test]
     fn test_raders() {
         for len in 3..100 {
-            if miller_rabin(len as u64) {
+            if math_utils::is_prime(len as u64) {
                 test_raders_with_length(len, FftDirection::Forward);
                 test_raders_with_length(len, FftDirection::Inverse);
             }
@@ -258,4 +586,87 @@ mod unit_tests {
 
         check_fft_algorithm::<f32>(&fft, len, direction);
     }
+
+    #[test]
+    fn test_raders_normalized() {
+        for &len in &[3, 7, 13] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let inner_fft = Arc::new(Dft::new(len - 1, direction));
+                    let fft = RadersAlgorithm::new_normalized(inner_fft, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_raders_try_new() {
+        let inner_fft = Arc::new(Dft::new(6, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert!(RadersAlgorithm::try_new(inner_fft).is_ok());
+
+        let inner_fft = Arc::new(Dft::new(8, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            RadersAlgorithm::try_new(inner_fft).err(),
+            Some(FftError::NotPrime { len: 9 })
+        );
+    }
+
+    #[test]
+    fn test_raders_padded() {
+        for len in 3usize..100 {
+            if math_utils::is_prime(len as u64) {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    // Check the smallest allowed inner FFT, and a larger one
+                    for &inner_len in &[len - 2, len.next_power_of_two()] {
+                        let inner_fft = Arc::new(Dft::new(inner_len, direction));
+                        let fft = RadersAlgorithmPadded::new(len, inner_fft);
+                        check_fft_algorithm::<f32>(&fft, len, direction);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_raders_padded_normalized() {
+        for &len in &[3, 7, 13] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let inner_fft = Arc::new(Dft::new(len * 2, direction));
+                    let fft = RadersAlgorithmPadded::new_normalized(len, inner_fft, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_raders_padded_try_new() {
+        let inner_fft = Arc::new(Dft::new(5, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert!(RadersAlgorithmPadded::try_new(7, inner_fft).is_ok());
+
+        let inner_fft = Arc::new(Dft::new(4, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            RadersAlgorithmPadded::try_new(7, inner_fft).err(),
+            Some(FftError::InnerFftTooSmall {
+                expected_len: 5,
+                actual_len: 4
+            })
+        );
+
+        let inner_fft = Arc::new(Dft::new(16, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        assert_eq!(
+            RadersAlgorithmPadded::try_new(9, inner_fft).err(),
+            Some(FftError::NotPrime { len: 9 })
+        );
+    }
 }
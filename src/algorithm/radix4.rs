@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::min;
 
 use num_complex::Complex;
 use num_traits::Zero;
@@ -9,7 +11,7 @@ use crate::common::{fft_error_inplace, fft_error_outofplace};
 use crate::{
     array_utils::{RawSlice, RawSliceMut},
     common::FftNum,
-    twiddles, FftDirection,
+    twiddles, FftDirection, FftError, FftNormalization,
 };
 use crate::{Direction, Fft, Length};
 
@@ -28,65 +30,169 @@ use crate::{Direction, Fft, Length};
 /// ~~~
 
 pub struct Radix4<T> {
-    twiddles: Box<[Complex<T>]>,
+    twiddles: Arc<[Complex<T>]>,
 
     base_fft: Arc<dyn Fft<T>>,
     base_len: usize,
 
+    nonzero_inputs: usize,
+    needed_outputs: usize,
+
+    scale: Option<T>,
+
     len: usize,
     direction: FftDirection,
 }
 
 impl<T: FftNum> Radix4<T> {
     /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// # Panics
+    /// Panics if `len` is not a power of two.
     pub fn new(len: usize, direction: FftDirection) -> Self {
+        Self::try_new(len, direction).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// Returns `Err(FftError::NotPowerOfTwo)` if `len` is not a power of two.
+    pub fn try_new(len: usize, direction: FftDirection) -> Result<Self, FftError> {
+        if !len.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo { len });
+        }
+
+        let twiddles = Self::compute_twiddles(len, direction);
+        Ok(Self::new_with_twiddles(len, direction, twiddles))
+    }
+
+    // Creates a FFT instance that uses the provided twiddle factors. `twiddles` must start with the twiddle factors `compute_twiddles(len, direction)` would return,
+    // but it may be longer: The twiddles of a larger power-of-two FFT with the same base length contain ours as a prefix, so they can be shared.
+    pub(crate) fn new_with_twiddles(
+        len: usize,
+        direction: FftDirection,
+        twiddles: Arc<[Complex<T>]>,
+    ) -> Self {
         assert!(
             len.is_power_of_two(),
-            "Radix4 algorithm requires a power-of-two input size. Got {}",
+            "Radix4 requires a power-of-two length, got {}",
             len
         );
+        assert!(
+            twiddles.len() >= Self::twiddle_len(len),
+            "Radix4 of length {} requires at least {} twiddle factors, got {}",
+            len,
+            Self::twiddle_len(len),
+            twiddles.len()
+        );
 
-        // figure out which base length we're going to use
-        let num_bits = len.trailing_zeros();
-        let (base_len, base_fft) = match num_bits {
-            0 => (len, Arc::new(Butterfly1::new(direction)) as Arc<dyn Fft<T>>),
-            1 => (len, Arc::new(Butterfly2::new(direction)) as Arc<dyn Fft<T>>),
-            2 => (len, Arc::new(Butterfly4::new(direction)) as Arc<dyn Fft<T>>),
-            _ => {
-                if num_bits % 2 == 1 {
-                    (8, Arc::new(Butterfly8::new(direction)) as Arc<dyn Fft<T>>)
-                } else {
-                    (16, Arc::new(Butterfly16::new(direction)) as Arc<dyn Fft<T>>)
-                }
-            }
+        let base_len = Self::base_len(len);
+        let base_fft = match base_len {
+            1 => Arc::new(Butterfly1::new(direction)) as Arc<dyn Fft<T>>,
+            2 => Arc::new(Butterfly2::new(direction)) as Arc<dyn Fft<T>>,
+            4 => Arc::new(Butterfly4::new(direction)) as Arc<dyn Fft<T>>,
+            8 => Arc::new(Butterfly8::new(direction)) as Arc<dyn Fft<T>>,
+            _ => Arc::new(Butterfly16::new(direction)) as Arc<dyn Fft<T>>,
         };
 
-        // precompute the twiddle factors this algorithm will use.
+        Self {
+            twiddles,
+
+            base_fft,
+            base_len,
+
+            nonzero_inputs: len,
+            needed_outputs: len,
+
+            scale: None,
+
+            len,
+            direction,
+        }
+    }
+
+    // Returns the length of the base-level FFTs for a power-of-two FFT of size `len`
+    pub(crate) fn base_len(len: usize) -> usize {
+        let num_bits = len.trailing_zeros();
+        match num_bits {
+            0..=2 => len,
+            _ if num_bits % 2 == 1 => 8,
+            _ => 16,
+        }
+    }
+
+    // Returns the number of twiddle factors a power-of-two FFT of size `len` uses
+    pub(crate) fn twiddle_len(len: usize) -> usize {
+        // Each layer of size `current_size` uses `3 * current_size / 4` twiddles, and the layer sizes are the base length times powers of 4.
+        // So the layers sum up to `len - base_len`
+        len - Self::base_len(len)
+    }
+
+    // Precomputes the twiddle factors for a power-of-two FFT of size `len`.
+    pub(crate) fn compute_twiddles(len: usize, direction: FftDirection) -> Arc<[Complex<T>]> {
         // we're doing the same precomputation of twiddle factors as the mixed radix algorithm where width=4 and height=len/4
         // but mixed radix only does one step and then calls itself recusrively, and this algorithm does every layer all the way down
-        // so we're going to pack all the "layers" of twiddle factors into a single array, starting with the bottom layer and going up
-        let mut twiddle_stride = len / (base_len * 4);
-        let mut twiddle_factors = Vec::with_capacity(len * 2);
-        while twiddle_stride > 0 {
-            let num_rows = len / (twiddle_stride * 4);
-            for i in 0..num_rows {
+        // so we're going to pack all the "layers" of twiddle factors into a single array, starting with the bottom layer and going up.
+        // The twiddles of each layer only depend on the size of the layer, so the twiddles of a larger FFT with the same base length start with ours
+        let mut twiddle_factors = Vec::with_capacity(Self::twiddle_len(len));
+        let mut current_size = Self::base_len(len) * 4;
+        while current_size <= len {
+            for i in 0..current_size / 4 {
                 for k in 1..4 {
-                    let twiddle = twiddles::compute_twiddle(i * k * twiddle_stride, len, direction);
+                    let twiddle = twiddles::compute_twiddle(i * k, current_size, direction);
                     twiddle_factors.push(twiddle);
                 }
             }
-            twiddle_stride >>= 2;
+            current_size *= 4;
         }
+        twiddle_factors.into()
+    }
 
-        Self {
-            twiddles: twiddle_factors.into_boxed_slice(),
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute a pruned power-of-two FFT
+    ///
+    /// A pruned FFT treats every input element at index `nonzero_inputs` or above as zero, ignoring its contents, and only guarantees
+    /// that the first `needed_outputs` output elements are correct. The remaining output elements will contain garbage.
+    /// Whenever the ignored inputs or unneeded outputs cover entire sub-FFTs or butterflies, their computation is skipped.
+    ///
+    /// Values of `nonzero_inputs` and `needed_outputs` larger than `len` are treated as `len`.
+    pub fn new_pruned(
+        len: usize,
+        direction: FftDirection,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Self {
+        let mut result = Self::new(len, direction);
+        result.prune(nonzero_inputs, needed_outputs);
+        result
+    }
 
-            base_fft,
-            base_len,
+    // Makes this instance skip the work that `new_pruned` would skip
+    pub(crate) fn prune(&mut self, nonzero_inputs: usize, needed_outputs: usize) {
+        self.nonzero_inputs = min(nonzero_inputs, self.len);
+        self.needed_outputs = min(needed_outputs, self.len);
+    }
 
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute a normalized power-of-two FFT
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied while the input is being reordered,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, direction);
+        result.scale_output(twiddles::compute_scale_factor(
+            normalization,
             len,
             direction,
-        }
+        ));
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
     }
 
     fn perform_fft_out_of_place(
@@ -95,11 +201,32 @@ impl<T: FftNum> Radix4<T> {
         spectrum: &mut [Complex<T>],
         _scratch: &mut [Complex<T>],
     ) {
-        // copy the data into the spectrum vector
-        prepare_radix4(signal.len(), self.base_len, signal, spectrum, 1);
+        if self.nonzero_inputs < signal.len() {
+            // copy the data into the spectrum vector, replacing everything past `nonzero_inputs` with zeroes
+            prepare_radix4_pruned(
+                signal.len(),
+                self.base_len,
+                &signal[..self.nonzero_inputs],
+                spectrum,
+                0,
+                1,
+                self.scale,
+            );
 
-        // Base-level FFTs
-        self.base_fft.process_with_scratch(spectrum, &mut []);
+            // Base-level FFTs. Chunks that only contain zeroes will also produce zeroes, so skip them
+            let num_chunks = signal.len() / self.base_len;
+            for (i, chunk) in spectrum.chunks_exact_mut(self.base_len).enumerate() {
+                if reverse_base4_digits(i, num_chunks) < self.nonzero_inputs {
+                    self.base_fft.process_with_scratch(chunk, &mut []);
+                }
+            }
+        } else {
+            // copy the data into the spectrum vector
+            prepare_radix4(signal.len(), self.base_len, signal, spectrum, 1, self.scale);
+
+            // Base-level FFTs
+            self.base_fft.process_with_scratch(spectrum, &mut []);
+        }
 
         // cross-FFTs
         let mut current_size = self.base_len * 4;
@@ -108,12 +235,23 @@ impl<T: FftNum> Radix4<T> {
         while current_size <= signal.len() {
             let num_rows = signal.len() / current_size;
 
+            // Each butterfly in this layer only produces outputs at its own index and above, so if we don't need some outputs,
+            // we can skip the butterflies that only produce unneeded outputs
+            let num_butterflies = min(current_size / 4, self.needed_outputs);
+
             for i in 0..num_rows {
+                // If every input to this row is past `nonzero_inputs`, this row is all zeroes, and there's nothing to compute
+                if num_rows > self.nonzero_inputs
+                    && reverse_base4_digits(i, num_rows) >= self.nonzero_inputs
+                {
+                    continue;
+                }
                 unsafe {
                     butterfly_4(
                         &mut spectrum[i * current_size..],
                         layer_twiddles,
                         current_size / 4,
+                        num_butterflies,
                         self.direction,
                     )
                 }
@@ -131,17 +269,25 @@ boilerplate_fft_oop!(Radix4, |this: &Radix4<_>| this.len);
 
 // after testing an iterative bit reversal algorithm, this recursive algorithm
 // was almost an order of magnitude faster at setting up
+// If `scale` is provided, every element is multiplied by it as it's copied
 fn prepare_radix4<T: FftNum>(
     size: usize,
     base_len: usize,
     signal: &[Complex<T>],
     spectrum: &mut [Complex<T>],
     stride: usize,
+    scale: Option<T>,
 ) {
     if size == base_len {
         unsafe {
-            for i in 0..size {
-                *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+            if let Some(scale) = scale {
+                for i in 0..size {
+                    *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride) * scale;
+                }
+            } else {
+                for i in 0..size {
+                    *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+                }
             }
         }
     } else {
@@ -152,15 +298,65 @@ fn prepare_radix4<T: FftNum>(
                 &signal[i * stride..],
                 &mut spectrum[i * (size / 4)..],
                 stride * 4,
+                scale,
+            );
+        }
+    }
+}
+
+// Same as prepare_radix4, except `signal` only contains the nonzero inputs, and every element past its end is treated as zero.
+// `offset` is the index of signal[0] in the original, un-strided signal.
+fn prepare_radix4_pruned<T: FftNum>(
+    size: usize,
+    base_len: usize,
+    signal: &[Complex<T>],
+    spectrum: &mut [Complex<T>],
+    offset: usize,
+    stride: usize,
+    scale: Option<T>,
+) {
+    if size == base_len {
+        for (i, element) in spectrum[..size].iter_mut().enumerate() {
+            let signal_index = offset + i * stride;
+            *element = if signal_index < signal.len() {
+                scale.map_or(signal[signal_index], |scale| signal[signal_index] * scale)
+            } else {
+                Zero::zero()
+            };
+        }
+    } else {
+        for i in 0..4 {
+            prepare_radix4_pruned(
+                size / 4,
+                base_len,
+                signal,
+                &mut spectrum[i * (size / 4)..],
+                offset + i * stride,
+                stride * 4,
+                scale,
             );
         }
     }
 }
 
-unsafe fn butterfly_4<T: FftNum>(
+// prepare_radix4 stores the input with index `r` at chunk `i`, where `r` is `i` with its base-4 digits reversed.
+// Given a chunk index, returns the index of the first input stored in that chunk. `num_chunks` must be a power of 4.
+fn reverse_base4_digits(mut index: usize, num_chunks: usize) -> usize {
+    let mut result = 0;
+    let mut remaining_chunks = num_chunks;
+    while remaining_chunks > 1 {
+        result = (result << 2) | (index & 3);
+        index >>= 2;
+        remaining_chunks >>= 2;
+    }
+    result
+}
+
+pub(crate) unsafe fn butterfly_4<T: FftNum>(
     data: &mut [Complex<T>],
     twiddles: &[Complex<T>],
     num_ffts: usize,
+    num_butterflies: usize,
     direction: FftDirection,
 ) {
     let butterfly4 = Butterfly4::new(direction);
@@ -168,7 +364,7 @@ unsafe fn butterfly_4<T: FftNum>(
     let mut idx = 0usize;
     let mut tw_idx = 0usize;
     let mut scratch = [Zero::zero(); 4];
-    for _ in 0..num_ffts {
+    for _ in 0..num_butterflies {
         scratch[0] = *data.get_unchecked(idx);
         scratch[1] = *data.get_unchecked(idx + 1 * num_ffts) * twiddles[tw_idx];
         scratch[2] = *data.get_unchecked(idx + 2 * num_ffts) * twiddles[tw_idx + 1];
@@ -189,7 +385,9 @@ unsafe fn butterfly_4<T: FftNum>(
 #[cfg(test)]
 mod unit_tests {
     use super::*;
-    use crate::test_utils::check_fft_algorithm;
+    use crate::test_utils::{
+        check_fft_algorithm, check_normalized_fft_algorithm, check_pruned_fft_algorithm,
+    };
 
     #[test]
     fn test_radix4() {
@@ -205,4 +403,96 @@ mod unit_tests {
 
         check_fft_algorithm::<f32>(&fft, len, direction);
     }
+
+    #[test]
+    fn test_radix4_pruned() {
+        for pow in 0..8 {
+            let len = 1 << pow;
+            for &nonzero_inputs in &[0, 1, 3, len / 4, len / 2 + 1, len] {
+                for &needed_outputs in &[0, 1, 5, len / 4, len / 2 + 1, len] {
+                    test_radix4_pruned_with_length(
+                        len,
+                        nonzero_inputs,
+                        needed_outputs,
+                        FftDirection::Forward,
+                    );
+                    test_radix4_pruned_with_length(
+                        len,
+                        nonzero_inputs,
+                        needed_outputs,
+                        FftDirection::Inverse,
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix4_normalized() {
+        for pow in 0..8 {
+            let len = 1 << pow;
+            for &normalization in &[
+                FftNormalization::None,
+                FftNormalization::Unitary,
+                FftNormalization::ForwardByN,
+                FftNormalization::InverseByN,
+            ] {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let fft = Radix4::new_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    fn test_radix4_pruned_with_length(
+        len: usize,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+        direction: FftDirection,
+    ) {
+        let nonzero_inputs = nonzero_inputs.min(len);
+        let needed_outputs = needed_outputs.min(len);
+        let fft = Radix4::new_pruned(len, direction, nonzero_inputs, needed_outputs);
+
+        check_pruned_fft_algorithm::<f32>(
+            &fft,
+            len,
+            direction,
+            nonzero_inputs,
+            needed_outputs,
+            true,
+        );
+    }
+
+    #[test]
+    fn test_radix4_shared_twiddles() {
+        for pow in 0..8 {
+            let len = 1 << pow;
+            assert_eq!(
+                Radix4::<f32>::compute_twiddles(len, FftDirection::Forward).len(),
+                Radix4::<f32>::twiddle_len(len)
+            );
+
+            // Any larger FFT with the same base length should be able to share its twiddles with us
+            for shared_pow in (pow..10).filter(|&p| {
+                Radix4::<f32>::base_len(1 << p) == Radix4::<f32>::base_len(len) || p == pow
+            }) {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let twiddles = Radix4::compute_twiddles(1 << shared_pow, direction);
+                    let fft = Radix4::new_with_twiddles(len, direction, twiddles);
+                    check_fft_algorithm::<f32>(&fft, len, direction);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix4_try_new() {
+        assert!(Radix4::<f32>::try_new(64, FftDirection::Forward).is_ok());
+        assert_eq!(
+            Radix4::<f32>::try_new(48, FftDirection::Forward).err(),
+            Some(FftError::NotPowerOfTwo { len: 48 })
+        );
+    }
 }
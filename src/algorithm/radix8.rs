@@ -0,0 +1,346 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+use num_traits::Zero;
+
+use crate::algorithm::butterflies::{
+    Butterfly1, Butterfly16, Butterfly2, Butterfly32, Butterfly4, Butterfly8,
+};
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{
+    array_utils::{RawSlice, RawSliceMut},
+    common::FftNum,
+    twiddles, FftDirection, FftError, FftNormalization,
+};
+use crate::{Direction, Fft, Length};
+
+/// FFT algorithm optimized for power-of-two sizes, which computes its cross-FFTs with radix-8 passes
+///
+/// Compared to [`Radix4`](crate::algorithm::Radix4), this algorithm makes a third fewer passes over the data, at the cost of
+/// larger butterflies.
+///
+/// ~~~
+/// // Computes a forward FFT of size 4096
+/// use rustfft::algorithm::Radix8;
+/// use rustfft::{Fft, FftDirection};
+/// use rustfft::num_complex::Complex;
+///
+/// let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 4096];
+///
+/// let fft = Radix8::new(4096, FftDirection::Forward);
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct Radix8<T> {
+    twiddles: Arc<[Complex<T>]>,
+
+    base_fft: Arc<dyn Fft<T>>,
+    base_len: usize,
+
+    butterfly8: Butterfly8<T>,
+
+    scale: Option<T>,
+
+    len: usize,
+    direction: FftDirection,
+}
+
+impl<T: FftNum> Radix8<T> {
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// # Panics
+    /// Panics if `len` is not a power of two.
+    pub fn new(len: usize, direction: FftDirection) -> Self {
+        Self::try_new(len, direction).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// Returns `Err(FftError::NotPowerOfTwo)` if `len` is not a power of two.
+    pub fn try_new(len: usize, direction: FftDirection) -> Result<Self, FftError> {
+        if !len.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo { len });
+        }
+
+        let twiddles = Self::compute_twiddles(len, direction);
+        Ok(Self::new_with_twiddles(len, direction, twiddles))
+    }
+
+    // Creates a FFT instance that uses the provided twiddle factors. `twiddles` must start with the twiddle factors `compute_twiddles(len, direction)` would return,
+    // but it may be longer: The twiddles of a larger power-of-two FFT with the same base length contain ours as a prefix, so they can be shared.
+    pub(crate) fn new_with_twiddles(
+        len: usize,
+        direction: FftDirection,
+        twiddles: Arc<[Complex<T>]>,
+    ) -> Self {
+        assert!(
+            len.is_power_of_two(),
+            "Radix8 requires a power-of-two length, got {}",
+            len
+        );
+        assert!(
+            twiddles.len() >= Self::twiddle_len(len),
+            "Radix8 of length {} requires at least {} twiddle factors, got {}",
+            len,
+            Self::twiddle_len(len),
+            twiddles.len()
+        );
+
+        let base_len = Self::base_len(len);
+        let base_fft = match base_len {
+            1 => Arc::new(Butterfly1::new(direction)) as Arc<dyn Fft<T>>,
+            2 => Arc::new(Butterfly2::new(direction)) as Arc<dyn Fft<T>>,
+            4 => Arc::new(Butterfly4::new(direction)) as Arc<dyn Fft<T>>,
+            8 => Arc::new(Butterfly8::new(direction)) as Arc<dyn Fft<T>>,
+            16 => Arc::new(Butterfly16::new(direction)) as Arc<dyn Fft<T>>,
+            _ => Arc::new(Butterfly32::new(direction)) as Arc<dyn Fft<T>>,
+        };
+
+        Self {
+            twiddles,
+
+            base_fft,
+            base_len,
+
+            butterfly8: Butterfly8::new(direction),
+
+            scale: None,
+
+            len,
+            direction,
+        }
+    }
+
+    // Returns the length of the base-level FFTs for a power-of-two FFT of size `len`.
+    // We have butterflies for 8, 16 and 32, so we can always choose a base that leaves a power of 8 for the radix-8 passes
+    pub(crate) fn base_len(len: usize) -> usize {
+        let num_bits = len.trailing_zeros();
+        match num_bits {
+            0..=2 => len,
+            _ => match num_bits % 3 {
+                0 => 8,
+                1 => 16,
+                _ => 32,
+            },
+        }
+    }
+
+    // Returns the number of twiddle factors a power-of-two FFT of size `len` uses
+    pub(crate) fn twiddle_len(len: usize) -> usize {
+        // Each layer of size `current_size` uses `7 * current_size / 8` twiddles, and the layer sizes are the base length times powers of 8.
+        // So the layers sum up to `len - base_len`
+        len - Self::base_len(len)
+    }
+
+    // Precomputes the twiddle factors for a power-of-two FFT of size `len`.
+    // This is the same layout Radix4 uses, except that each butterfly in each layer has 7 twiddle factors instead of 3.
+    // The twiddles of each layer only depend on the size of the layer, so the twiddles of a larger FFT with the same base length start with ours
+    pub(crate) fn compute_twiddles(len: usize, direction: FftDirection) -> Arc<[Complex<T>]> {
+        let mut twiddle_factors = Vec::with_capacity(Self::twiddle_len(len));
+        let mut current_size = Self::base_len(len) * 8;
+        while current_size <= len {
+            for i in 0..current_size / 8 {
+                for k in 1..8 {
+                    let twiddle = twiddles::compute_twiddle(i * k, current_size, direction);
+                    twiddle_factors.push(twiddle);
+                }
+            }
+            current_size *= 8;
+        }
+        twiddle_factors.into()
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute a normalized power-of-two FFT
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied while the input is being reordered,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, direction);
+        result.scale_output(twiddles::compute_scale_factor(
+            normalization,
+            len,
+            direction,
+        ));
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        signal: &[Complex<T>],
+        spectrum: &mut [Complex<T>],
+        _scratch: &mut [Complex<T>],
+    ) {
+        // copy the data into the spectrum vector
+        prepare_radix8(signal.len(), self.base_len, signal, spectrum, 1, self.scale);
+
+        // Base-level FFTs
+        self.base_fft.process_with_scratch(spectrum, &mut []);
+
+        // cross-FFTs
+        let mut current_size = self.base_len * 8;
+        let mut layer_twiddles: &[Complex<T>] = &self.twiddles;
+
+        while current_size <= signal.len() {
+            let num_rows = signal.len() / current_size;
+
+            for i in 0..num_rows {
+                unsafe {
+                    butterfly_8(
+                        &mut spectrum[i * current_size..],
+                        layer_twiddles,
+                        current_size / 8,
+                        &self.butterfly8,
+                    )
+                }
+            }
+
+            //skip past all the twiddle factors used in this layer
+            let twiddle_offset = (current_size * 7) / 8;
+            layer_twiddles = &layer_twiddles[twiddle_offset..];
+
+            current_size *= 8;
+        }
+    }
+}
+boilerplate_fft_oop!(Radix8, |this: &Radix8<_>| this.len);
+
+// The same recursive reordering as prepare_radix4, except it reverses base-8 digits instead of base-4 digits
+// If `scale` is provided, every element is multiplied by it as it's copied
+fn prepare_radix8<T: FftNum>(
+    size: usize,
+    base_len: usize,
+    signal: &[Complex<T>],
+    spectrum: &mut [Complex<T>],
+    stride: usize,
+    scale: Option<T>,
+) {
+    if size == base_len {
+        unsafe {
+            if let Some(scale) = scale {
+                for i in 0..size {
+                    *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride) * scale;
+                }
+            } else {
+                for i in 0..size {
+                    *spectrum.get_unchecked_mut(i) = *signal.get_unchecked(i * stride);
+                }
+            }
+        }
+    } else {
+        for i in 0..8 {
+            prepare_radix8(
+                size / 8,
+                base_len,
+                &signal[i * stride..],
+                &mut spectrum[i * (size / 8)..],
+                stride * 8,
+                scale,
+            );
+        }
+    }
+}
+
+unsafe fn butterfly_8<T: FftNum>(
+    data: &mut [Complex<T>],
+    twiddles: &[Complex<T>],
+    num_ffts: usize,
+    butterfly8: &Butterfly8<T>,
+) {
+    let mut scratch = [Zero::zero(); 8];
+    for (idx, butterfly_twiddles) in twiddles.chunks_exact(7).take(num_ffts).enumerate() {
+        scratch[0] = *data.get_unchecked(idx);
+        for (k, (element, twiddle)) in scratch[1..]
+            .iter_mut()
+            .zip(butterfly_twiddles.iter())
+            .enumerate()
+        {
+            *element = *data.get_unchecked(idx + (k + 1) * num_ffts) * twiddle;
+        }
+
+        butterfly8.perform_fft_contiguous(RawSlice::new(&scratch), RawSliceMut::new(&mut scratch));
+
+        for (k, element) in scratch.iter().enumerate() {
+            *data.get_unchecked_mut(idx + k * num_ffts) = *element;
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+
+    #[test]
+    fn test_radix8() {
+        for pow in 0..11 {
+            let len = 1 << pow;
+            test_radix8_with_length(len, FftDirection::Forward);
+            test_radix8_with_length(len, FftDirection::Inverse);
+        }
+    }
+
+    fn test_radix8_with_length(len: usize, direction: FftDirection) {
+        let fft = Radix8::new(len, direction);
+
+        check_fft_algorithm::<f32>(&fft, len, direction);
+    }
+
+    #[test]
+    fn test_radix8_normalized() {
+        for &len in &[1, 8, 64, 128, 256] {
+            for &normalization in &[
+                FftNormalization::None,
+                FftNormalization::Unitary,
+                FftNormalization::ForwardByN,
+                FftNormalization::InverseByN,
+            ] {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let fft = Radix8::new_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix8_shared_twiddles() {
+        for pow in 0..9 {
+            let len = 1 << pow;
+            assert_eq!(
+                Radix8::<f32>::compute_twiddles(len, FftDirection::Forward).len(),
+                Radix8::<f32>::twiddle_len(len)
+            );
+
+            // Any larger FFT with the same base length should be able to share its twiddles with us
+            for shared_pow in (pow..12)
+                .filter(|&p| Radix8::<f32>::base_len(1 << p) == Radix8::<f32>::base_len(len))
+            {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let twiddles = Radix8::compute_twiddles(1 << shared_pow, direction);
+                    let fft = Radix8::new_with_twiddles(len, direction, twiddles);
+                    check_fft_algorithm::<f32>(&fft, len, direction);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_radix8_try_new() {
+        assert!(Radix8::<f32>::try_new(512, FftDirection::Forward).is_ok());
+        assert_eq!(
+            Radix8::<f32>::try_new(96, FftDirection::Forward).err(),
+            Some(FftError::NotPowerOfTwo { len: 96 })
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use alloc::sync::Arc;
+
+use num_complex::Complex;
+
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{common::FftNum, FftDirection};
+use crate::{Direction, Fft, Length};
+
+/// Wraps a FFT algorithm, and multiplies every output of the wrapped FFT by a constant scale.
+///
+/// Most algorithms can fold a scale into their precomputed data for free. This wrapper is for the ones that can't,
+/// like the hardcoded butterflies. It costs an extra pass over the data, so the planners only use it when the whole FFT is one of those algorithms.
+pub(crate) struct ScaledFft<T> {
+    inner_fft: Arc<dyn Fft<T>>,
+    scale: T,
+    direction: FftDirection,
+}
+
+impl<T: FftNum> ScaledFft<T> {
+    /// Creates a FFT instance which computes `inner_fft`, then multiplies every output by `scale`
+    pub fn new(inner_fft: Arc<dyn Fft<T>>, scale: T) -> Self {
+        Self {
+            direction: inner_fft.fft_direction(),
+            inner_fft,
+            scale,
+        }
+    }
+
+    fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        self.inner_fft.process_with_scratch(buffer, scratch);
+        for element in buffer.iter_mut() {
+            *element = *element * self.scale;
+        }
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        self.inner_fft
+            .process_outofplace_with_scratch(input, output, scratch);
+        for element in output.iter_mut() {
+            *element = *element * self.scale;
+        }
+    }
+}
+boilerplate_fft!(
+    ScaledFft,
+    |this: &ScaledFft<_>| this.inner_fft.len(),
+    |this: &ScaledFft<_>| this.inner_fft.get_inplace_scratch_len(),
+    |this: &ScaledFft<_>| this.inner_fft.get_outofplace_scratch_len()
+);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::butterflies::Butterfly5;
+    use crate::test_utils::check_normalized_fft_algorithm;
+    use crate::FftNormalization;
+
+    #[test]
+    fn test_scaled_fft() {
+        for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+            let inner_fft = Arc::new(Butterfly5::new(direction)) as Arc<dyn Fft<f32>>;
+            let scale = FftNormalization::Unitary.scale_factor(5, direction) as f32;
+            let fft = ScaledFft::new(inner_fft, scale);
+            check_normalized_fft_algorithm(&fft, 5, direction, FftNormalization::Unitary);
+        }
+    }
+}
@@ -0,0 +1,348 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+
+use num_complex::Complex;
+use transpose;
+
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace, validate_directions};
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
+use crate::{Direction, Fft, Length};
+
+// Number of columns to compute FFTs of at a time. Large enough that each row of the column block covers at least one cache line
+const COLUMN_BLOCK_WIDTH: usize = 8;
+
+/// Implementation of the six-step FFT algorithm, for FFTs too large to fit in cache
+///
+/// Like [`MixedRadix`](crate::algorithm::MixedRadix), this algorithm factors a size n FFT into n1 * n2, and computes it with
+/// inner FFTs of size n1 and n2. It's designed for very large sizes, where n1 and n2 are both close to `sqrt(n)`, so that each inner FFT
+/// fits in cache. Instead of transposing the whole array before and after the column FFTs, it copies a few columns at a time into a small
+/// buffer, computes their FFTs while they're in cache, and copies them back with the twiddle factors applied. That way, the data
+/// only makes three passes through memory: one for the column FFTs, one for the row FFTs, and one blocked transpose at the end.
+///
+/// The inner FFTs may themselves be `SixStep` instances, for sizes whose square root is still too large to fit in cache.
+///
+/// ~~~
+/// // Computes a forward FFT of size 2^22, using the six-step algorithm
+/// use rustfft::algorithm::SixStep;
+/// use rustfft::{Fft, FftPlanner};
+/// use rustfft::num_complex::Complex;
+///
+/// let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 1 << 22];
+///
+/// // n1 = n2 = 2048 splits the FFT into two square roots
+/// let mut planner = FftPlanner::new();
+/// let inner_fft_n1 = planner.plan_fft_forward(2048);
+/// let inner_fft_n2 = planner.plan_fft_forward(2048);
+///
+/// let fft = SixStep::new(inner_fft_n1, inner_fft_n2);
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct SixStep<T> {
+    twiddles: Box<[Complex<T>]>,
+
+    width_size_fft: Arc<dyn Fft<T>>,
+    width: usize,
+
+    height_size_fft: Arc<dyn Fft<T>>,
+    height: usize,
+
+    // Number of columns we compute at a time
+    column_block_width: usize,
+
+    inplace_scratch_len: usize,
+    outofplace_scratch_len: usize,
+
+    direction: FftDirection,
+}
+
+impl<T: FftNum> SixStep<T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions.
+    pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
+        Self::try_new(width_fft, height_fft).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns `Err(FftError::MismatchedDirections)` if `width_fft` and `height_fft` have different directions.
+    pub fn try_new(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+    ) -> Result<Self, FftError> {
+        validate_directions(width_fft.fft_direction(), height_fft.fft_direction())?;
+
+        let direction = width_fft.fft_direction();
+
+        let width = width_fft.len();
+        let height = height_fft.len();
+
+        let len = width * height;
+
+        // The twiddles are stored in the same layout as the output of the height FFTs, so that they can be read sequentially alongside it
+        let mut twiddles = Vec::with_capacity(len);
+        for x in 0..width {
+            for y in 0..height {
+                twiddles.push(twiddles::compute_twiddle(x * y, len, direction));
+            }
+        }
+
+        let column_block_width = min(COLUMN_BLOCK_WIDTH, width);
+        let column_block_len = column_block_width * height;
+
+        // When we compute an in-place FFT, we compute the column FFTs in our own scratch, and then compute the row FFTs out-of-place into it.
+        // The inner FFTs' scratch goes after that.
+        let height_inplace_scratch = height_fft.get_inplace_scratch_len();
+        let width_inplace_scratch = width_fft.get_inplace_scratch_len();
+        let width_outofplace_scratch = width_fft.get_outofplace_scratch_len();
+        let inplace_scratch_len = len + max(height_inplace_scratch, width_outofplace_scratch);
+
+        // When we compute an out-of-place FFT, we can use the output as scratch until the final transpose. We only need our own scratch
+        // if the inner FFTs need more than that
+        let outofplace_scratch_len = max(
+            if height_inplace_scratch > len - column_block_len {
+                height_inplace_scratch
+            } else {
+                0
+            },
+            if width_inplace_scratch > len {
+                width_inplace_scratch
+            } else {
+                0
+            },
+        );
+
+        Ok(Self {
+            twiddles: twiddles.into_boxed_slice(),
+
+            width_size_fft: width_fft,
+            width,
+
+            height_size_fft: height_fft,
+            height,
+
+            column_block_width,
+
+            inplace_scratch_len,
+            outofplace_scratch_len,
+
+            direction,
+        })
+    }
+
+    /// Creates a normalized FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scale is folded into the twiddle factors,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        width_fft: Arc<dyn Fft<T>>,
+        height_fft: Arc<dyn Fft<T>>,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(width_fft, height_fft);
+        let scale = twiddles::compute_scale_factor(normalization, result.len(), result.direction);
+        result.scale_output(scale);
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        for twiddle in self.twiddles.iter_mut() {
+            *twiddle = *twiddle * scale;
+        }
+    }
+
+    // Computes the FFTs of size `height` down each column of `data`, and multiplies their outputs by our twiddle factors.
+    // `column_block` holds the columns we're currently working on, and must have room for `column_block_width` columns
+    fn perform_column_ffts(
+        &self,
+        data: &mut [Complex<T>],
+        column_block: &mut [Complex<T>],
+        inner_scratch: &mut [Complex<T>],
+    ) {
+        for first_column in (0..self.width).step_by(self.column_block_width) {
+            let num_columns = min(self.column_block_width, self.width - first_column);
+            let column_block = &mut column_block[..num_columns * self.height];
+            let block_twiddles = &self.twiddles
+                [first_column * self.height..(first_column + num_columns) * self.height];
+
+            // STEP 1: transpose a block of columns into our column buffer
+            array_utils::gather_columns(data, self.width, first_column, self.height, column_block);
+
+            // STEP 2: perform FFTs of size `height`
+            self.height_size_fft
+                .process_with_scratch(column_block, inner_scratch);
+
+            // STEPS 3 AND 4: apply twiddle factors and transpose the block back
+            array_utils::scatter_columns_twiddled(
+                column_block,
+                block_twiddles,
+                self.height,
+                data,
+                self.width,
+                first_column,
+            );
+        }
+    }
+
+    fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
+
+        // STEPS 1-4: column FFTs and twiddle factors
+        self.perform_column_ffts(buffer, scratch, inner_scratch);
+
+        // STEP 5: perform FFTs of size `width`
+        self.width_size_fft
+            .process_outofplace_with_scratch(buffer, scratch, inner_scratch);
+
+        // STEP 6: transpose
+        transpose::transpose(scratch, buffer, self.width, self.height);
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        // STEPS 1-4: column FFTs and twiddle factors. We use the output to store the column block, and also as scratch if it's big enough
+        {
+            let (column_block, output_scratch) =
+                output.split_at_mut(self.column_block_width * self.height);
+            let height_scratch = if scratch.len() > output_scratch.len() {
+                &mut scratch[..]
+            } else {
+                output_scratch
+            };
+            self.perform_column_ffts(input, column_block, height_scratch);
+        }
+
+        // STEP 5: perform FFTs of size `width`
+        let width_scratch = if scratch.len() > output.len() {
+            &mut scratch[..]
+        } else {
+            &mut output[..]
+        };
+        self.width_size_fft
+            .process_with_scratch(input, width_scratch);
+
+        // STEP 6: transpose
+        transpose::transpose(input, output, self.width, self.height);
+    }
+}
+boilerplate_fft!(
+    SixStep,
+    |this: &SixStep<_>| this.twiddles.len(),
+    |this: &SixStep<_>| this.inplace_scratch_len,
+    |this: &SixStep<_>| this.outofplace_scratch_len
+);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+    use crate::{algorithm::Dft, test_utils::BigScratchAlgorithm};
+    use alloc::sync::Arc;
+    use num_traits::Zero;
+
+    #[test]
+    fn test_six_step() {
+        for width in 1..7 {
+            for height in 1..7 {
+                test_six_step_with_lengths(width, height, FftDirection::Forward);
+                test_six_step_with_lengths(width, height, FftDirection::Inverse);
+            }
+        }
+
+        // Make sure the transposes work when they span more than one block
+        test_six_step_with_lengths(20, 37, FftDirection::Forward);
+        test_six_step_with_lengths(37, 20, FftDirection::Inverse);
+    }
+
+    fn test_six_step_with_lengths(width: usize, height: usize, direction: FftDirection) {
+        let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
+        let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
+
+        let fft = SixStep::new(width_fft, height_fft);
+
+        check_fft_algorithm(&fft, width * height, direction);
+    }
+
+    #[test]
+    fn test_six_step_normalized() {
+        for &(width, height) in &[(1, 1), (2, 3), (4, 5), (6, 6)] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<f32>>;
+                    let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<f32>>;
+                    let fft = SixStep::new_normalized(width_fft, height_fft, normalization);
+                    check_normalized_fft_algorithm(&fft, width * height, direction, normalization);
+                }
+            }
+        }
+    }
+
+    // Verify that the six-step algorithm correctly provides scratch space to inner FFTs
+    #[test]
+    fn test_six_step_inner_scratch() {
+        let scratch_lengths = [1, 5, 25];
+
+        let mut inner_ffts = Vec::new();
+
+        for &len in &scratch_lengths {
+            for &inplace_scratch in &scratch_lengths {
+                for &outofplace_scratch in &scratch_lengths {
+                    inner_ffts.push(Arc::new(BigScratchAlgorithm {
+                        len,
+                        inplace_scratch,
+                        outofplace_scratch,
+                        direction: FftDirection::Forward,
+                    }) as Arc<dyn Fft<f32>>);
+                }
+            }
+        }
+
+        for width_fft in inner_ffts.iter() {
+            for height_fft in inner_ffts.iter() {
+                let fft = SixStep::new(Arc::clone(width_fft), Arc::clone(height_fft));
+
+                let mut inplace_buffer = vec![Complex::zero(); fft.len()];
+                let mut inplace_scratch = vec![Complex::zero(); fft.get_inplace_scratch_len()];
+
+                fft.process_with_scratch(&mut inplace_buffer, &mut inplace_scratch);
+
+                let mut outofplace_input = vec![Complex::zero(); fft.len()];
+                let mut outofplace_output = vec![Complex::zero(); fft.len()];
+                let mut outofplace_scratch =
+                    vec![Complex::zero(); fft.get_outofplace_scratch_len()];
+                fft.process_outofplace_with_scratch(
+                    &mut outofplace_input,
+                    &mut outofplace_output,
+                    &mut outofplace_scratch,
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_six_step_try_new() {
+        let width_fft = Arc::new(Dft::new(4, FftDirection::Forward)) as Arc<dyn Fft<f32>>;
+        let height_fft = Arc::new(Dft::new(6, FftDirection::Inverse)) as Arc<dyn Fft<f32>>;
+
+        assert_eq!(
+            SixStep::try_new(width_fft, height_fft).err(),
+            Some(FftError::MismatchedDirections {
+                width_direction: FftDirection::Forward,
+                height_direction: FftDirection::Inverse
+            })
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+
+use crate::algorithm::butterflies::{
+    Butterfly1, Butterfly16, Butterfly2, Butterfly32, Butterfly4, Butterfly8,
+};
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{common::FftNum, twiddles, FftDirection, FftError, FftNormalization};
+use crate::{Direction, Fft, Length};
+
+// Sub-FFTs of this size or smaller are computed with butterflies
+const MAX_BASE_LEN: usize = 32;
+
+/// Implementation of the conjugate-pair split-radix algorithm, for power-of-two sizes
+///
+/// Split-radix divides a FFT of size N into one sub-FFT of size N/2 over the even inputs, and two sub-FFTs of size N/4
+/// over the odd inputs, which needs fewer arithmetic operations than any fixed radix. The conjugate-pair variant takes the
+/// second N/4 sub-FFT from inputs `4n - 1` instead of `4n + 3`, so that the two odd sub-FFTs need conjugate twiddle factors,
+/// and only one of them has to be stored.
+///
+/// ~~~
+/// // Computes a forward FFT of size 4096
+/// use rustfft::algorithm::SplitRadix;
+/// use rustfft::{Fft, FftDirection};
+/// use rustfft::num_complex::Complex;
+///
+/// let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 4096];
+///
+/// let fft = SplitRadix::new(4096, FftDirection::Forward);
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct SplitRadix<T> {
+    // For each sub-FFT size `m` larger than the base, `twiddles[(len - m) / 2..]` holds the `m / 4` twiddle factors of size `m`
+    twiddles: Box<[Complex<T>]>,
+
+    // The recursion bottoms out at sub-FFTs of either size `base_fft.len()` or `half_base_fft.len()`
+    base_fft: Arc<dyn Fft<T>>,
+    half_base_fft: Arc<dyn Fft<T>>,
+
+    scale: Option<T>,
+
+    len: usize,
+    direction: FftDirection,
+}
+
+impl<T: FftNum> SplitRadix<T> {
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// # Panics
+    /// Panics if `len` is not a power of two.
+    pub fn new(len: usize, direction: FftDirection) -> Self {
+        Self::try_new(len, direction).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the power-of-two FFT
+    ///
+    /// Returns `Err(FftError::NotPowerOfTwo)` if `len` is not a power of two.
+    pub fn try_new(len: usize, direction: FftDirection) -> Result<Self, FftError> {
+        if !len.is_power_of_two() {
+            return Err(FftError::NotPowerOfTwo { len });
+        }
+
+        let base_fft = Self::butterfly(len.min(MAX_BASE_LEN), direction);
+        let half_base_fft = Self::butterfly(MAX_BASE_LEN / 2, direction);
+
+        let mut twiddle_factors = Vec::with_capacity(len / 2);
+        let mut sub_len = len;
+        while sub_len > MAX_BASE_LEN {
+            for k in 0..sub_len / 4 {
+                twiddle_factors.push(twiddles::compute_twiddle(k, sub_len, direction));
+            }
+            sub_len /= 2;
+        }
+
+        Ok(Self {
+            twiddles: twiddle_factors.into_boxed_slice(),
+
+            base_fft,
+            half_base_fft,
+
+            scale: None,
+
+            len,
+            direction,
+        })
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute a normalized power-of-two FFT
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied while the input is being reordered,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, direction);
+        result.scale_output(twiddles::compute_scale_factor(
+            normalization,
+            len,
+            direction,
+        ));
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
+    }
+
+    fn butterfly(len: usize, direction: FftDirection) -> Arc<dyn Fft<T>> {
+        match len {
+            1 => Arc::new(Butterfly1::new(direction)) as Arc<dyn Fft<T>>,
+            2 => Arc::new(Butterfly2::new(direction)) as Arc<dyn Fft<T>>,
+            4 => Arc::new(Butterfly4::new(direction)) as Arc<dyn Fft<T>>,
+            8 => Arc::new(Butterfly8::new(direction)) as Arc<dyn Fft<T>>,
+            16 => Arc::new(Butterfly16::new(direction)) as Arc<dyn Fft<T>>,
+            32 => Arc::new(Butterfly32::new(direction)) as Arc<dyn Fft<T>>,
+            _ => unreachable!(),
+        }
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        signal: &[Complex<T>],
+        spectrum: &mut [Complex<T>],
+        _scratch: &mut [Complex<T>],
+    ) {
+        self.perform_split_radix(signal, spectrum, 0, 1);
+    }
+
+    // Computes the FFT of the `output.len()` elements of `signal` at `offset`, `offset + stride`, `offset + 2 * stride`, etc.
+    // Indexes wrap around the end of `signal`. Since its length is a power of two, wrapping is just a mask, and wrapping
+    // arithmetic on the offset gives the same result as modular arithmetic would.
+    fn perform_split_radix(
+        &self,
+        signal: &[Complex<T>],
+        output: &mut [Complex<T>],
+        offset: usize,
+        stride: usize,
+    ) {
+        let len = output.len();
+        if len <= MAX_BASE_LEN {
+            let mask = signal.len() - 1;
+            for (i, element) in output.iter_mut().enumerate() {
+                let input = signal[offset.wrapping_add(i * stride) & mask];
+                *element = self.scale.map_or(input, |scale| input * scale);
+            }
+
+            if len == self.base_fft.len() {
+                self.base_fft.process_with_scratch(output, &mut []);
+            } else {
+                self.half_base_fft.process_with_scratch(output, &mut []);
+            }
+            return;
+        }
+
+        let quarter_len = len / 4;
+
+        // Compute the half-size FFT of the even inputs into the first half of the output, and the two quarter-size FFTs
+        // of inputs 4n + 1 and 4n - 1 into the third and fourth quarters
+        {
+            let (evens, odds) = output.split_at_mut(len / 2);
+            self.perform_split_radix(signal, evens, offset, stride * 2);
+
+            let (odds1, odds3) = odds.split_at_mut(quarter_len);
+            self.perform_split_radix(signal, odds1, offset.wrapping_add(stride), stride * 4);
+            self.perform_split_radix(signal, odds3, offset.wrapping_sub(stride), stride * 4);
+        }
+
+        // Combine them. Each iteration reads and writes the same four elements, so this can be done in place
+        let layer_twiddles = &self.twiddles[(self.len - len) / 2..];
+        let (evens0, rest) = output.split_at_mut(quarter_len);
+        let (evens1, rest) = rest.split_at_mut(quarter_len);
+        let (odds1, odds3) = rest.split_at_mut(quarter_len);
+
+        for ((((even0, even1), odd1), odd3), twiddle) in evens0
+            .iter_mut()
+            .zip(evens1.iter_mut())
+            .zip(odds1.iter_mut())
+            .zip(odds3.iter_mut())
+            .zip(layer_twiddles.iter())
+        {
+            let twiddled1 = *odd1 * twiddle;
+            let twiddled3 = *odd3 * twiddle.conj();
+
+            let sum = twiddled1 + twiddled3;
+            let rotated_diff = twiddles::rotate_90(twiddled1 - twiddled3, self.direction);
+
+            *odd1 = *even0 - sum;
+            *even0 = *even0 + sum;
+            *odd3 = *even1 - rotated_diff;
+            *even1 = *even1 + rotated_diff;
+        }
+    }
+}
+boilerplate_fft_oop!(SplitRadix, |this: &SplitRadix<_>| this.len);
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+
+    #[test]
+    fn test_split_radix() {
+        for pow in 0..11 {
+            let len = 1 << pow;
+            test_split_radix_with_length(len, FftDirection::Forward);
+            test_split_radix_with_length(len, FftDirection::Inverse);
+        }
+    }
+
+    fn test_split_radix_with_length(len: usize, direction: FftDirection) {
+        let fft = SplitRadix::new(len, direction);
+
+        check_fft_algorithm::<f32>(&fft, len, direction);
+    }
+
+    #[test]
+    fn test_split_radix_normalized() {
+        for &len in &[1, 16, 64, 128, 256] {
+            for &normalization in &[
+                FftNormalization::None,
+                FftNormalization::Unitary,
+                FftNormalization::ForwardByN,
+                FftNormalization::InverseByN,
+            ] {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let fft = SplitRadix::new_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_radix_try_new() {
+        assert!(SplitRadix::<f32>::try_new(512, FftDirection::Forward).is_ok());
+        assert_eq!(
+            SplitRadix::<f32>::try_new(96, FftDirection::Forward).err(),
+            Some(FftError::NotPowerOfTwo { len: 96 })
+        );
+    }
+}
@@ -0,0 +1,401 @@
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{
+    array_utils::{RawSlice, RawSliceMut},
+    common::FftNum,
+    twiddles, FftDirection, FftError, FftNormalization,
+};
+use crate::{Direction, Fft, Length};
+
+/// Implementation of the Stockham auto-sort algorithm, for sizes whose only prime factors are 2 and 3
+///
+/// Radix4 reorders its input before computing any butterflies, and MixedRadix transposes its data between passes. Stockham instead
+/// computes a sequence of radix-4, radix-2 and radix-3 passes, each of which writes its output in an order that leaves the final
+/// pass's output sorted. The passes alternate between the FFT buffer and a scratch buffer, so for sizes too large to fit in cache, this
+/// saves the memory-bound reordering pass.
+///
+/// ~~~
+/// // Computes a forward FFT of size 6144
+/// use rustfft::algorithm::Stockham;
+/// use rustfft::{Fft, FftDirection};
+/// use rustfft::num_complex::Complex;
+///
+/// let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 6144];
+///
+/// let fft = Stockham::new(6144, FftDirection::Forward);
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct Stockham<T> {
+    // The twiddle factors of every pass, in the order the passes are computed. A radix-r pass over sub-FFTs of size n has (r - 1) * n / r of them
+    twiddles: Box<[Complex<T>]>,
+    radixes: Box<[usize]>,
+
+    // The twiddle factor used by radix-3 passes
+    twiddle3: Complex<T>,
+
+    scale: Option<T>,
+
+    len: usize,
+    direction: FftDirection,
+}
+
+impl<T: FftNum> Stockham<T> {
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT
+    ///
+    /// # Panics
+    /// Panics if `len` has a prime factor other than 2 or 3.
+    pub fn new(len: usize, direction: FftDirection) -> Self {
+        Self::try_new(len, direction).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT
+    ///
+    /// Returns `Err(FftError::UnsupportedFactors)` if `len` has a prime factor other than 2 or 3.
+    pub fn try_new(len: usize, direction: FftDirection) -> Result<Self, FftError> {
+        let radixes = Self::compute_radixes(len).ok_or(FftError::UnsupportedFactors { len })?;
+
+        // precompute the twiddle factors for each pass. A pass over sub-FFTs of size `sub_len` multiplies output `j` of butterfly `p`
+        // by the `j * p`th twiddle factor of size `sub_len`
+        let mut twiddle_factors = Vec::with_capacity(len);
+        let mut sub_len = len;
+        for &radix in radixes.iter() {
+            let stride = len / sub_len;
+            for p in 0..sub_len / radix {
+                for j in 1..radix {
+                    let twiddle = twiddles::compute_twiddle(j * p * stride, len, direction);
+                    twiddle_factors.push(twiddle);
+                }
+            }
+            sub_len /= radix;
+        }
+
+        Ok(Self {
+            twiddles: twiddle_factors.into_boxed_slice(),
+            radixes: radixes.into_boxed_slice(),
+
+            twiddle3: twiddles::compute_twiddle(1, 3, direction),
+
+            scale: None,
+
+            len,
+            direction,
+        })
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute a normalized FFT
+    ///
+    /// The output is scaled by `normalization.scale_factor(len, direction)`. The scaling is applied during the last pass,
+    /// so it doesn't require an extra pass over the data.
+    pub fn new_normalized(
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Self {
+        let mut result = Self::new(len, direction);
+        result.scale_output(twiddles::compute_scale_factor(
+            normalization,
+            len,
+            direction,
+        ));
+        result
+    }
+
+    // Multiplies every output of this FFT by `scale`
+    pub(crate) fn scale_output(&mut self, scale: T) {
+        let scale = self.scale.map_or(scale, |existing| existing * scale);
+        self.scale = if scale == T::one() { None } else { Some(scale) };
+    }
+
+    // Returns the radix of each pass, or None if `len` has a prime factor other than 2 or 3.
+    // We do as many radix-4 passes as possible, since they're the cheapest per element, then at most one radix-2 pass, then the radix-3 passes
+    fn compute_radixes(len: usize) -> Option<Vec<usize>> {
+        if len == 0 {
+            return None;
+        }
+
+        let num_bits = len.trailing_zeros();
+        let mut radixes = vec![4; num_bits as usize / 2];
+        if num_bits & 1 == 1 {
+            radixes.push(2);
+        }
+
+        let mut power_of_three = len >> num_bits;
+        while power_of_three > 1 {
+            let remainder = power_of_three % 3;
+            if remainder > 0 {
+                return None;
+            }
+            power_of_three /= 3;
+            radixes.push(3);
+        }
+        Some(radixes)
+    }
+
+    // The last pass of a Stockham FFT reads and writes the same indexes, so it can be computed in place.
+    // We take advantage of that to make sure the final output always ends up in the right buffer, without an extra copy
+    fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        let num_passes = self.radixes.len();
+        if num_passes & 1 == 0 {
+            self.perform_passes(buffer, scratch, num_passes);
+        } else {
+            self.perform_passes(buffer, scratch, num_passes - 1);
+            self.perform_last_pass_inplace(buffer);
+        }
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        _scratch: &mut [Complex<T>],
+    ) {
+        let num_passes = self.radixes.len();
+        if num_passes == 0 {
+            output.copy_from_slice(input);
+        } else if num_passes & 1 == 1 {
+            self.perform_passes(input, output, num_passes);
+        } else {
+            self.perform_passes(input, output, num_passes - 1);
+            self.perform_last_pass_inplace(output);
+        }
+    }
+
+    // Computes the first `num_passes` passes, starting in `buffer_a` and alternating between the two buffers.
+    // If `num_passes` is even, the result ends up in `buffer_a`, otherwise it ends up in `buffer_b`
+    fn perform_passes(
+        &self,
+        buffer_a: &mut [Complex<T>],
+        buffer_b: &mut [Complex<T>],
+        num_passes: usize,
+    ) {
+        let mut src = buffer_a;
+        let mut dst = buffer_b;
+
+        let mut layer_twiddles: &[Complex<T>] = &self.twiddles;
+        let mut stride = 1;
+        for (pass, &radix) in self.radixes[..num_passes].iter().enumerate() {
+            // Only the FFT's last pass applies the scale
+            let scale = if pass + 1 == self.radixes.len() {
+                self.scale
+            } else {
+                None
+            };
+
+            let num_butterflies = self.len / (stride * radix);
+            unsafe {
+                self.perform_pass(
+                    radix,
+                    RawSlice::new(src),
+                    RawSliceMut::new(dst),
+                    layer_twiddles,
+                    stride,
+                    scale,
+                )
+            };
+
+            //skip past all the twiddle factors used in this pass
+            layer_twiddles = &layer_twiddles[(radix - 1) * num_butterflies..];
+            stride *= radix;
+
+            core::mem::swap(&mut src, &mut dst);
+        }
+    }
+
+    fn perform_last_pass_inplace(&self, buffer: &mut [Complex<T>]) {
+        let radix = *self.radixes.last().unwrap();
+
+        // The last pass's twiddle factors are at the end of the array
+        let last_twiddles = &self.twiddles[self.twiddles.len() - (radix - 1)..];
+        unsafe {
+            self.perform_pass(
+                radix,
+                RawSlice::new(buffer),
+                RawSliceMut::new(buffer),
+                last_twiddles,
+                self.len / radix,
+                self.scale,
+            )
+        };
+    }
+
+    // Computes one radix-`radix` pass, where `num_butterflies = len / (stride * radix)`. For each `p` in `0..num_butterflies` and each `q` in `0..stride`, this computes a butterfly on
+    // inputs `q + stride * (p + k * num_butterflies)`, multiplies output `j` by `twiddles[p * (radix - 1) + j - 1]`, and stores it
+    // to `q + stride * (p * radix + j)`.
+    // In the last pass, `num_butterflies` is 1 and each butterfly reads and writes the same indexes, so `input` and `output` may alias.
+    unsafe fn perform_pass(
+        &self,
+        radix: usize,
+        input: RawSlice<Complex<T>>,
+        output: RawSliceMut<Complex<T>>,
+        twiddles: &[Complex<T>],
+        stride: usize,
+        scale: Option<T>,
+    ) {
+        let num_butterflies = self.len / (stride * radix);
+        let section_len = stride * num_butterflies;
+        match radix {
+            2 => {
+                for p in 0..num_butterflies {
+                    let twiddle = *twiddles.get_unchecked(p);
+                    for q in p * stride..(p + 1) * stride {
+                        let values = butterfly_2([input.load(q), input.load(q + section_len)]);
+                        let out_index = q + p * stride;
+                        store(output, out_index, values[0], scale);
+                        store(output, out_index + stride, values[1] * twiddle, scale);
+                    }
+                }
+            }
+            3 => {
+                for p in 0..num_butterflies {
+                    let twiddle1 = *twiddles.get_unchecked(2 * p);
+                    let twiddle2 = *twiddles.get_unchecked(2 * p + 1);
+                    for q in p * stride..(p + 1) * stride {
+                        let values = butterfly_3(
+                            [
+                                input.load(q),
+                                input.load(q + section_len),
+                                input.load(q + 2 * section_len),
+                            ],
+                            self.twiddle3,
+                        );
+                        let out_index = q + 2 * p * stride;
+                        store(output, out_index, values[0], scale);
+                        store(output, out_index + stride, values[1] * twiddle1, scale);
+                        store(output, out_index + 2 * stride, values[2] * twiddle2, scale);
+                    }
+                }
+            }
+            4 => {
+                for p in 0..num_butterflies {
+                    let twiddle1 = *twiddles.get_unchecked(3 * p);
+                    let twiddle2 = *twiddles.get_unchecked(3 * p + 1);
+                    let twiddle3 = *twiddles.get_unchecked(3 * p + 2);
+                    for q in p * stride..(p + 1) * stride {
+                        let values = butterfly_4(
+                            [
+                                input.load(q),
+                                input.load(q + section_len),
+                                input.load(q + 2 * section_len),
+                                input.load(q + 3 * section_len),
+                            ],
+                            self.direction,
+                        );
+                        let out_index = q + 3 * p * stride;
+                        store(output, out_index, values[0], scale);
+                        store(output, out_index + stride, values[1] * twiddle1, scale);
+                        store(output, out_index + 2 * stride, values[2] * twiddle2, scale);
+                        store(output, out_index + 3 * stride, values[3] * twiddle3, scale);
+                    }
+                }
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+boilerplate_fft!(
+    Stockham,
+    |this: &Stockham<_>| this.len,
+    |this: &Stockham<_>| this.len,
+    |_| 0
+);
+
+#[inline(always)]
+unsafe fn store<T: FftNum>(
+    output: RawSliceMut<Complex<T>>,
+    index: usize,
+    value: Complex<T>,
+    scale: Option<T>,
+) {
+    output.store(scale.map_or(value, |scale| value * scale), index);
+}
+
+#[inline(always)]
+fn butterfly_2<T: FftNum>(values: [Complex<T>; 2]) -> [Complex<T>; 2] {
+    [values[0] + values[1], values[0] - values[1]]
+}
+
+#[inline(always)]
+fn butterfly_3<T: FftNum>(values: [Complex<T>; 3], twiddle: Complex<T>) -> [Complex<T>; 3] {
+    let sum = values[1] + values[2];
+    let diff = values[1] - values[2];
+
+    let partial = values[0] + sum * twiddle.re;
+    let rotated = Complex {
+        re: -diff.im * twiddle.im,
+        im: diff.re * twiddle.im,
+    };
+
+    [values[0] + sum, partial + rotated, partial - rotated]
+}
+
+#[inline(always)]
+fn butterfly_4<T: FftNum>(values: [Complex<T>; 4], direction: FftDirection) -> [Complex<T>; 4] {
+    let sum02 = values[0] + values[2];
+    let diff02 = values[0] - values[2];
+    let sum13 = values[1] + values[3];
+    let diff13 = twiddles::rotate_90(values[1] - values[3], direction);
+
+    [
+        sum02 + sum13,
+        diff02 + diff13,
+        sum02 - sum13,
+        diff02 - diff13,
+    ]
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::test_utils::{check_fft_algorithm, check_normalized_fft_algorithm};
+
+    #[test]
+    fn test_stockham() {
+        for pow2 in 0..7 {
+            for pow3 in 0..4 {
+                let len = (1 << pow2) * 3usize.pow(pow3);
+                test_stockham_with_length(len, FftDirection::Forward);
+                test_stockham_with_length(len, FftDirection::Inverse);
+            }
+        }
+    }
+
+    fn test_stockham_with_length(len: usize, direction: FftDirection) {
+        let fft = Stockham::new(len, direction);
+
+        check_fft_algorithm::<f32>(&fft, len, direction);
+    }
+
+    #[test]
+    fn test_stockham_normalized() {
+        for &len in &[1, 2, 12, 64, 96, 243] {
+            for &normalization in &[
+                FftNormalization::None,
+                FftNormalization::Unitary,
+                FftNormalization::ForwardByN,
+                FftNormalization::InverseByN,
+            ] {
+                for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                    let fft = Stockham::new_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm::<f32>(&fft, len, direction, normalization);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stockham_try_new() {
+        assert!(Stockham::<f32>::try_new(1536, FftDirection::Forward).is_ok());
+        for &len in &[0, 5, 20, 7 * 64] {
+            assert_eq!(
+                Stockham::<f32>::try_new(len, FftDirection::Forward).err(),
+                Some(FftError::UnsupportedFactors { len })
+            );
+        }
+    }
+}
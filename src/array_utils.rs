@@ -1,3 +1,7 @@
+use num_complex::Complex;
+
+use crate::common::FftNum;
+
 /// Given an array of size width * height, representing a flattened 2D array,
 /// transpose the rows and columns of that 2D array into the output
 /// benchmarking shows that loop tiling isn't effective for small arrays (in the range of 50x50 or smaller)
@@ -12,17 +16,67 @@ pub unsafe fn transpose_small<T: Copy>(width: usize, height: usize, input: &[T],
     }
 }
 
+/// Given an array with rows of length `width`, copy the `output.len() / column_len` columns starting at `first_column` into `output`,
+/// transposed so that each column is contiguous. Only the first `column_len` rows are copied.
+/// Each row of the input is read in a contiguous run, so this is cache-friendly even when the input is much larger than the cache
+pub fn gather_columns<T: Copy>(
+    input: &[T],
+    width: usize,
+    first_column: usize,
+    column_len: usize,
+    output: &mut [T],
+) {
+    let num_columns = output.len() / column_len;
+    assert!(first_column + num_columns <= width);
+    assert!(column_len * width <= input.len());
+
+    for (y, row) in input.chunks_exact(width).take(column_len).enumerate() {
+        for (x, element) in row[first_column..first_column + num_columns]
+            .iter()
+            .enumerate()
+        {
+            unsafe { *output.get_unchecked_mut(y + x * column_len) = *element };
+        }
+    }
+}
+
+/// The inverse of `gather_columns`: multiplies each element of `input` by the corresponding element of `twiddles`, and
+/// writes the contiguous columns of `input` back to the columns of `output` starting at `first_column`
+pub fn scatter_columns_twiddled<T: FftNum>(
+    input: &[Complex<T>],
+    twiddles: &[Complex<T>],
+    column_len: usize,
+    output: &mut [Complex<T>],
+    width: usize,
+    first_column: usize,
+) {
+    let num_columns = input.len() / column_len;
+    assert!(first_column + num_columns <= width);
+    assert!(column_len * width <= output.len());
+    assert_eq!(twiddles.len(), input.len());
+
+    for (y, row) in output.chunks_exact_mut(width).take(column_len).enumerate() {
+        for (x, element) in row[first_column..first_column + num_columns]
+            .iter_mut()
+            .enumerate()
+        {
+            let index = y + x * column_len;
+            unsafe { *element = *input.get_unchecked(index) * *twiddles.get_unchecked(index) };
+        }
+    }
+}
+
 #[allow(unused)]
 pub unsafe fn workaround_transmute<T, U>(slice: &[T]) -> &[U] {
     let ptr = slice.as_ptr() as *const U;
     let len = slice.len();
-    std::slice::from_raw_parts(ptr, len)
+    core::slice::from_raw_parts(ptr, len)
 }
 #[allow(unused)]
 pub unsafe fn workaround_transmute_mut<T, U>(slice: &mut [T]) -> &mut [U] {
     let ptr = slice.as_mut_ptr() as *mut U;
     let len = slice.len();
-    std::slice::from_raw_parts_mut(ptr, len)
+    core::slice::from_raw_parts_mut(ptr, len)
 }
 
 #[derive(Copy, Clone)]
@@ -138,6 +192,61 @@ mod unit_tests {
             }
         }
     }
+
+    #[test]
+    fn test_gather_scatter_columns() {
+        let sizes: Vec<usize> = (1..12).collect();
+
+        for &width in &sizes {
+            for &height in &sizes {
+                for first_column in 0..width {
+                    for num_columns in 1..=width - first_column {
+                        let input: Vec<Complex<f32>> = random_signal(width * height);
+                        let mut columns = vec![Zero::zero(); num_columns * height];
+
+                        gather_columns(&input, width, first_column, height, &mut columns);
+
+                        for x in 0..num_columns {
+                            for y in 0..height {
+                                assert_eq!(
+                                    input[first_column + x + y * width],
+                                    columns[y + x * height],
+                                    "x = {}, y = {}",
+                                    x,
+                                    y
+                                );
+                            }
+                        }
+
+                        let twiddles: Vec<Complex<f32>> = random_signal(num_columns * height);
+                        let mut output = input.clone();
+
+                        scatter_columns_twiddled(
+                            &columns,
+                            &twiddles,
+                            height,
+                            &mut output,
+                            width,
+                            first_column,
+                        );
+
+                        for x in 0..width {
+                            for y in 0..height {
+                                let expected =
+                                    if x >= first_column && x < first_column + num_columns {
+                                        let column_index = y + (x - first_column) * height;
+                                        columns[column_index] * twiddles[column_index]
+                                    } else {
+                                        input[x + y * width]
+                                    };
+                                assert_eq!(expected, output[x + y * width], "x = {}, y = {}", x, y);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
 }
 
 // Loop over exact chunks of the provided buffer. Very similar in semantics to ChunksExactMut, but generates smaller code and requires no modulo operations
@@ -200,3 +309,63 @@ pub fn iter_chunks_zipped<T>(
         Err(())
     }
 }
+
+// Same as iter_chunks_zipped, except the first buffer is read-only
+pub fn iter_chunks_zipped_immutable<T>(
+    mut buffer1: &[T],
+    mut buffer2: &mut [T],
+    chunk_size: usize,
+    mut chunk_fn: impl FnMut(&[T], &mut [T]),
+) -> Result<(), ()> {
+    // If the two buffers aren't the same size, record the fact that they're different, then snip them to be the same size
+    let uneven = if buffer1.len() > buffer2.len() {
+        buffer1 = &buffer1[..buffer2.len()];
+        true
+    } else if buffer2.len() > buffer1.len() {
+        buffer2 = &mut buffer2[..buffer1.len()];
+        true
+    } else {
+        false
+    };
+
+    // Now that we know the two slices are the same length, loop over each one, splicing off chunk_size at a time, and calling chunk_fn on each
+    while buffer1.len() >= chunk_size && buffer2.len() >= chunk_size {
+        let (head1, tail1) = buffer1.split_at(chunk_size);
+        buffer1 = tail1;
+
+        let (head2, tail2) = buffer2.split_at_mut(chunk_size);
+        buffer2 = tail2;
+
+        chunk_fn(head1, head2);
+    }
+
+    // We have a remainder if the 2 chunks were uneven to start with, or if there's still data in the buffers -- in which case we want to indicate to the caller that there was an unwanted remainder
+    if !uneven && buffer1.is_empty() {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+// Combines the real parts in `re` and the imaginary parts in `im` into complex numbers, and writes them to `output`
+pub fn interleave_split<T: Copy>(re: &[T], im: &[T], output: &mut [num_complex::Complex<T>]) {
+    assert_eq!(re.len(), output.len());
+    assert_eq!(im.len(), output.len());
+    for ((re_element, im_element), output_element) in
+        re.iter().zip(im.iter()).zip(output.iter_mut())
+    {
+        *output_element = num_complex::Complex::new(*re_element, *im_element);
+    }
+}
+
+// Splits the complex numbers in `input` into real parts, written to `re`, and imaginary parts, written to `im`
+pub fn deinterleave_split<T: Copy>(input: &[num_complex::Complex<T>], re: &mut [T], im: &mut [T]) {
+    assert_eq!(re.len(), input.len());
+    assert_eq!(im.len(), input.len());
+    for ((input_element, re_element), im_element) in
+        input.iter().zip(re.iter_mut()).zip(im.iter_mut())
+    {
+        *re_element = input_element.re;
+        *im_element = input_element.im;
+    }
+}
@@ -91,6 +91,39 @@ macro_rules! boilerplate_fft_simd_butterfly {
                     fft_error_inplace(self.len(), buffer.len(), 0, 0);
                 }
             }
+            fn process_immutable(
+                &self,
+                input: &[Complex<T>],
+                output: &mut [Complex<T>],
+                _scratch: &mut [Complex<T>],
+            ) {
+                if input.len() < self.len() || output.len() != input.len() {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                    return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+                }
+
+                let result = array_utils::iter_chunks_zipped_immutable(
+                    input,
+                    output,
+                    self.len(),
+                    |in_chunk, out_chunk| {
+                        unsafe {
+                            // Specialization workaround: See the comments in FftPlannerAvx::new() for why we have to transmute these slices
+                            let input_slice = RawSlice::<Complex<f32>>::new_transmuted(in_chunk);
+                            let output_slice =
+                                RawSliceMut::<Complex<f32>>::new_transmuted(out_chunk);
+                            self.perform_fft_f32(input_slice, output_slice);
+                        }
+                    },
+                );
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the Ff32 size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                }
+            }
             #[inline(always)]
             fn get_inplace_scratch_len(&self) -> usize {
                 0
@@ -99,6 +132,10 @@ macro_rules! boilerplate_fft_simd_butterfly {
             fn get_outofplace_scratch_len(&self) -> usize {
                 0
             }
+            #[inline(always)]
+            fn get_immutable_scratch_len(&self) -> usize {
+                0
+            }
         }
         impl<T> Length for $struct_name<T> {
             #[inline(always)]
@@ -152,7 +189,7 @@ macro_rules! boilerplate_fft_simd_butterfly_with_scratch {
             #[inline]
             fn perform_fft_out_of_place(
                 &self,
-                input: &mut [Complex<f32>],
+                input: &[Complex<f32>],
                 output: &mut [Complex<f32>],
             ) {
                 // Perform the column FFTs
@@ -220,6 +257,36 @@ macro_rules! boilerplate_fft_simd_butterfly_with_scratch {
                     fft_error_inplace(self.len(), buffer.len(), self.len(), scratch.len());
                 }
             }
+            fn process_immutable(
+                &self,
+                input: &[Complex<T>],
+                output: &mut [Complex<T>],
+                _scratch: &mut [Complex<T>],
+            ) {
+                if input.len() < self.len() || output.len() != input.len() {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                    return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+                }
+
+                // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+                let transmuted_input: &[Complex<f32>] =
+                    unsafe { array_utils::workaround_transmute(input) };
+                let transmuted_output: &mut [Complex<f32>] =
+                    unsafe { array_utils::workaround_transmute_mut(output) };
+                let result = array_utils::iter_chunks_zipped_immutable(
+                    transmuted_input,
+                    transmuted_output,
+                    self.len(),
+                    |in_chunk, out_chunk| self.perform_fft_out_of_place(in_chunk, out_chunk),
+                );
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the Ff32 size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                }
+            }
             #[inline(always)]
             fn get_inplace_scratch_len(&self) -> usize {
                 $len
@@ -228,6 +295,10 @@ macro_rules! boilerplate_fft_simd_butterfly_with_scratch {
             fn get_outofplace_scratch_len(&self) -> usize {
                 0
             }
+            #[inline(always)]
+            fn get_immutable_scratch_len(&self) -> usize {
+                0
+            }
         }
         impl<T> Length for $struct_name<T> {
             #[inline(always)]
@@ -585,6 +656,58 @@ impl<T> Butterfly11Avx<T> {
     }
 }
 
+// Butterflies for primes from 13 to 31, built on the column butterflies generated by `column_butterfly_prime!`.
+// Each element of the FFT gets its own 128-bit vector, so we're computing the FFT down a single column
+macro_rules! butterfly_prime_avx {
+    ($struct_name:ident, $len:expr, $butterfly_fn:ident) => {
+        pub struct $struct_name<T> {
+            twiddles: [__m128; $len / 2],
+            direction: FftDirection,
+            _phantom_t: std::marker::PhantomData<T>,
+        }
+        boilerplate_fft_simd_butterfly!($struct_name, $len);
+        impl $struct_name<f32> {
+            #[target_feature(enable = "avx")]
+            unsafe fn new_with_avx(direction: FftDirection) -> Self {
+                let mut twiddles = [AvxVector::zero(); $len / 2];
+                for (i, twiddle) in twiddles.iter_mut().enumerate() {
+                    *twiddle = AvxVector::broadcast_twiddle(i + 1, $len, direction);
+                }
+                Self {
+                    twiddles,
+                    direction,
+                    _phantom_t: PhantomData,
+                }
+            }
+        }
+        impl<T> $struct_name<T> {
+            #[target_feature(enable = "avx", enable = "fma")]
+            unsafe fn perform_fft_f32(
+                &self,
+                input: RawSlice<Complex<f32>>,
+                mut output: RawSliceMut<Complex<f32>>,
+            ) {
+                let mut rows = [AvxVector::zero(); $len];
+                for i in 0..$len {
+                    rows[i] = input.load_partial1_complex(i);
+                }
+
+                let outputs = AvxVector::$butterfly_fn(rows, self.twiddles);
+
+                for i in 0..$len {
+                    output.store_partial1_complex(outputs[i], i);
+                }
+            }
+        }
+    };
+}
+butterfly_prime_avx!(Butterfly13Avx, 13, column_butterfly13);
+butterfly_prime_avx!(Butterfly17Avx, 17, column_butterfly17);
+butterfly_prime_avx!(Butterfly19Avx, 19, column_butterfly19);
+butterfly_prime_avx!(Butterfly23Avx, 23, column_butterfly23);
+butterfly_prime_avx!(Butterfly29Avx, 29, column_butterfly29);
+butterfly_prime_avx!(Butterfly31Avx, 31, column_butterfly31);
+
 pub struct Butterfly8Avx<T> {
     twiddles: __m256,
     twiddles_butterfly4: __m256,
@@ -1778,6 +1901,12 @@ mod unit_tests {
     test_avx_butterfly!(test_avx_butterfly9, Butterfly9Avx, 9);
     test_avx_butterfly!(test_avx_butterfly11, Butterfly11Avx, 11);
     test_avx_butterfly!(test_avx_butterfly12, Butterfly12Avx, 12);
+    test_avx_butterfly!(test_avx_butterfly13, Butterfly13Avx, 13);
+    test_avx_butterfly!(test_avx_butterfly17, Butterfly17Avx, 17);
+    test_avx_butterfly!(test_avx_butterfly19, Butterfly19Avx, 19);
+    test_avx_butterfly!(test_avx_butterfly23, Butterfly23Avx, 23);
+    test_avx_butterfly!(test_avx_butterfly29, Butterfly29Avx, 29);
+    test_avx_butterfly!(test_avx_butterfly31, Butterfly31Avx, 31);
     test_avx_butterfly!(test_avx_butterfly16, Butterfly16Avx, 16);
     test_avx_butterfly!(test_avx_butterfly24, Butterfly24Avx, 24);
     test_avx_butterfly!(test_avx_butterfly27, Butterfly27Avx, 27);
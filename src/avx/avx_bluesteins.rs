@@ -5,12 +5,16 @@ use num_complex::Complex;
 use num_integer::div_ceil;
 use num_traits::Zero;
 
+use crate::algorithm::BluesteinsAlgorithm;
 use crate::common::{fft_error_inplace, fft_error_outofplace};
 use crate::{array_utils, twiddles, FftDirection};
 use crate::{Direction, Fft, FftNum, Length};
 
 use super::CommonSimdData;
+#[cfg(feature = "half")]
+use super::FusedPasses;
 use super::{
+    avx_split::{SplitSlice, SplitSliceMut},
     avx_vector::{AvxArray, AvxArrayMut, AvxVector, AvxVector128, AvxVector256},
     AvxNum,
 };
@@ -26,18 +30,25 @@ use super::{
 /// an order of magnitude slower than similar composite sizes.
 
 pub struct BluesteinsAvx<A: AvxNum, T> {
-    inner_fft_multiplier: Box<[A::VectorType]>,
-    common_data: CommonSimdData<T, A::VectorType>,
+    // The inner FFT of the chirp, as computed for a forward FFT. It has the same layout as the one in `BluesteinsAlgorithm`, so the two can share it
+    inner_fft_multiplier: Arc<[Complex<T>]>,
+    common_data: CommonSimdData<T, Box<[A::VectorType]>>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(BluesteinsAvx);
+#[cfg(feature = "half")]
+impl<A: AvxNum, T: FftNum> FusedPasses for BluesteinsAvx<A, T> {}
 
 impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
     fn compute_bluesteins_twiddle(index: usize, len: usize, direction: FftDirection) -> Complex<A> {
-        let index_float = index as f64;
-        let index_squared = index_float * index_float;
-
-        twiddles::compute_twiddle_floatindex(index_squared, len * 2, direction.opposite_direction())
+        // index^2 can be much larger than len, so reduce it with integer arithmetic before it gets converted to an angle
+        let index_squared = (index as u128 * index as u128) % (len as u128 * 2);
+
+        twiddles::compute_twiddle(
+            index_squared as usize,
+            len * 2,
+            direction.opposite_direction(),
+        )
     }
 
     /// Pairwise multiply the complex numbers in `left` with the complex numbers in `right`.
@@ -60,8 +71,22 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
 
     /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT
     /// Returns Ok() if this machine has the required instruction sets, Err() if some instruction sets are missing
+    #[allow(unused)]
     #[inline]
     pub fn new(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Result<Self, ()> {
+        let chirp_spectrum = BluesteinsAlgorithm::compute_chirp_spectrum(len, &*inner_fft);
+        Self::new_with_chirp_spectrum(len, inner_fft, chirp_spectrum)
+    }
+
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT, reusing a chirp spectrum computed by
+    /// `BluesteinsAlgorithm::compute_chirp_spectrum` for the same `len` and inner FFT length
+    /// Returns Ok() if this machine has the required instruction sets, Err() if some instruction sets are missing
+    #[inline]
+    pub fn new_with_chirp_spectrum(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        chirp_spectrum: Arc<[Complex<T>]>,
+    ) -> Result<Self, ()> {
         // Internal sanity check: Make sure that A == T.
         // This struct has two generic parameters A and T, but they must always be the same, and are only kept separate to help work around the lack of specialization.
         // It would be cool if we could do this as a static_assert instead
@@ -73,55 +98,52 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
         let has_fma = is_x86_feature_detected!("fma");
         if has_avx && has_fma {
             // Safety: new_with_avx requires the "avx" feature set. Since we know it's present, we're safe
-            Ok(unsafe { Self::new_with_avx(len, inner_fft) })
+            Ok(unsafe { Self::new_with_avx(len, inner_fft, chirp_spectrum) })
         } else {
             Err(())
         }
     }
 
+    /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT, with every output multiplied by `scale`
+    /// Returns Ok() if this machine has the required instruction sets, Err() if some instruction sets are missing
+    #[inline]
+    pub fn new_scaled(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        chirp_spectrum: Arc<[Complex<T>]>,
+        scale: f64,
+    ) -> Result<Self, ()> {
+        let mut result = Self::new_with_chirp_spectrum(len, inner_fft, chirp_spectrum)?;
+        result.scale_output(T::from_f64(scale).unwrap());
+        Ok(result)
+    }
+
+    // Multiplies every output of this FFT by `scale`, by folding the scale into our precomputed inner FFT multiplier.
+    // The chirp spectrum may be shared with other instances, so this makes a scaled copy of it
+    fn scale_output(&mut self, scale: T) {
+        self.inner_fft_multiplier = self
+            .inner_fft_multiplier
+            .iter()
+            .map(|multiplier| *multiplier * scale)
+            .collect();
+    }
+
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(len: usize, inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(
+        len: usize,
+        inner_fft: Arc<dyn Fft<T>>,
+        inner_fft_multiplier: Arc<[Complex<T>]>,
+    ) -> Self {
         let inner_fft_len = inner_fft.len();
         assert!(len * 2 - 1 <= inner_fft_len, "Bluestein's algorithm requires inner_fft.len() >= self.len() * 2 - 1. Expected >= {}, got {}", len * 2 - 1, inner_fft_len);
         assert_eq!(inner_fft_len % A::VectorType::COMPLEX_PER_VECTOR, 0, "BluesteinsAvx requires its inner_fft.len() to be a multiple of {} (IE the number of complex numbers in a single vector) inner_fft.len() = {}", A::VectorType::COMPLEX_PER_VECTOR, inner_fft_len);
-
-        // when computing FFTs, we're going to run our inner multiply pairwise by some precomputed data, then run an inverse inner FFT. We need to precompute that inner data here
-        let inner_len_float = A::from_usize(inner_fft_len).unwrap();
+        assert_eq!(
+            inner_fft_multiplier.len(),
+            inner_fft_len,
+            "The chirp spectrum must have the same length as the inner FFT"
+        );
         let direction = inner_fft.fft_direction();
 
-        // Compute twiddle factors that we'll run our inner FFT on
-        let mut inner_fft_input = vec![Complex::zero(); inner_fft_len];
-        for i in 0..len {
-            inner_fft_input[i] =
-                Self::compute_bluesteins_twiddle(i, len, direction) / inner_len_float;
-        }
-        for i in 1..len {
-            inner_fft_input[inner_fft_len - i] = inner_fft_input[i];
-        }
-
-        //Compute the inner fft
-        let mut inner_fft_scratch = vec![Complex::zero(); inner_fft.get_inplace_scratch_len()];
-
-        {
-            // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
-            let transmuted_input: &mut [Complex<T>] =
-                array_utils::workaround_transmute_mut(&mut inner_fft_input);
-
-            inner_fft.process_with_scratch(transmuted_input, &mut inner_fft_scratch);
-        }
-
-        // When computing the FFT, we'll want this array to be pre-conjugated, so conjugate it now
-        let conjugation_mask =
-            AvxVector256::broadcast_complex_elements(Complex::new(A::zero(), -A::zero()));
-        let inner_fft_multiplier = inner_fft_input
-            .chunks_exact(A::VectorType::COMPLEX_PER_VECTOR)
-            .map(|chunk| {
-                let chunk_vector = chunk.load_complex(0);
-                AvxVector::xor(chunk_vector, conjugation_mask) // compute our conjugation by xoring our data with a precomputed mask
-            })
-            .collect::<Vec<_>>()
-            .into_boxed_slice();
-
         // also compute some more mundane twiddle factors to start and end with.
         let chunk_count = div_ceil(len, A::VectorType::COMPLEX_PER_VECTOR);
         let twiddles: Vec<_> = (0..chunk_count)
@@ -139,7 +161,7 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
             })
             .collect();
 
-        let required_scratch = inner_fft_input.len() + inner_fft_scratch.len();
+        let required_scratch = inner_fft_len + inner_fft.get_inplace_scratch_len();
 
         Self {
             inner_fft_multiplier,
@@ -151,6 +173,7 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
 
                 inplace_scratch_len: required_scratch,
                 outofplace_scratch_len: required_scratch,
+                split_scratch_len: required_scratch,
 
                 direction,
             },
@@ -158,9 +181,14 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
         }
     }
 
-    // Do the necessary setup for bluestein's algorithm: copy the data to the inner buffers, apply some twiddle factors, zero out the rest of the inner buffer
+    // Do the necessary setup for bluestein's algorithm: copy the data to the inner buffers, apply some twiddle factors, zero out the rest of the inner buffer.
+    // Reads from any kind of array that can be loaded into vectors of A, so that split-complex input can be interleaved as it's loaded
     #[target_feature(enable = "avx", enable = "fma")]
-    unsafe fn prepare_bluesteins(&self, input: &[Complex<A>], inner_fft_buffer: &mut [Complex<A>]) {
+    unsafe fn prepare_bluesteins<I: AvxArray<A> + ?Sized>(
+        &self,
+        input: &I,
+        inner_fft_buffer: &mut [Complex<A>],
+    ) {
         let chunk_count = self.common_data.twiddles.len() - 1;
         let remainder = self.len() - chunk_count * A::VectorType::COMPLEX_PER_VECTOR;
 
@@ -204,12 +232,13 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
         }
     }
 
-    // Do the necessary finalization for bluestein's algorithm: Conjugate the inner FFT buffer, apply some twiddle factors, zero out the rest of the inner buffer
+    // Do the necessary finalization for bluestein's algorithm: Conjugate the inner FFT buffer, apply some twiddle factors, zero out the rest of the inner buffer.
+    // Writes to any kind of array that vectors of A can be stored to, so that split-complex output can be split out as it's stored
     #[target_feature(enable = "avx", enable = "fma")]
-    unsafe fn finalize_bluesteins(
+    unsafe fn finalize_bluesteins<O: AvxArrayMut<A> + ?Sized>(
         &self,
         inner_fft_buffer: &[Complex<A>],
-        output: &mut [Complex<A>],
+        output: &mut O,
     ) {
         let chunk_count = self.common_data.twiddles.len() - 1;
         let remainder = self.len() - chunk_count * A::VectorType::COMPLEX_PER_VECTOR;
@@ -246,26 +275,46 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
         }
     }
 
-    // compute buffer[i] = buffer[i].conj() * multiplier[i] pairwise complex multiplication for each element.
+    // Multiply our inner FFT output by the chirp spectrum, and conjugate the result to set up for an inverse FFT.
+    // Forward FFTs compute buffer[i] = (buffer[i] * multiplier[i]).conj(). Inverse FFTs multiply by the conjugate of the spectrum,
+    // and (buffer[i] * multiplier[i].conj()).conj() simplifies to buffer[i].conj() * multiplier[i]
     #[target_feature(enable = "avx", enable = "fma")]
-    unsafe fn pairwise_complex_multiply_conjugated(
+    unsafe fn multiply_by_chirp_spectrum(
+        &self,
         buffer: &mut [Complex<A>],
-        multiplier: &[A::VectorType],
+        multiplier: &[Complex<A>],
     ) {
-        for (i, right) in multiplier.iter().enumerate() {
-            let left = buffer.load_complex(i * A::VectorType::COMPLEX_PER_VECTOR);
-
-            // Do a complex multiplication between `left` and `right`
-            let product = Self::mul_complex_conjugated(left, *right);
-
-            // Store the result
-            buffer.store_complex(product, i * A::VectorType::COMPLEX_PER_VECTOR);
+        let chunk_count = multiplier.len() / A::VectorType::COMPLEX_PER_VECTOR;
+        match self.common_data.direction {
+            FftDirection::Forward => {
+                let conjugation_mask =
+                    AvxVector256::broadcast_complex_elements(Complex::new(A::zero(), -A::zero()));
+                for i in 0..chunk_count {
+                    let index = i * A::VectorType::COMPLEX_PER_VECTOR;
+                    let left = buffer.load_complex(index);
+                    let right = multiplier.load_complex(index);
+
+                    // compute our conjugation by xoring our data with a precomputed mask
+                    let product =
+                        AvxVector::xor(AvxVector::mul_complex(left, right), conjugation_mask);
+                    buffer.store_complex(product, index);
+                }
+            }
+            FftDirection::Inverse => {
+                for i in 0..chunk_count {
+                    let index = i * A::VectorType::COMPLEX_PER_VECTOR;
+                    let left = buffer.load_complex(index);
+                    let right = multiplier.load_complex(index);
+
+                    let product = Self::mul_complex_conjugated(left, right);
+                    buffer.store_complex(product, index);
+                }
+            }
         }
     }
 
     fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
-        let (inner_input, inner_scratch) = scratch
-            .split_at_mut(self.inner_fft_multiplier.len() * A::VectorType::COMPLEX_PER_VECTOR);
+        let (inner_input, inner_scratch) = scratch.split_at_mut(self.inner_fft_multiplier.len());
 
         // do the necessary setup for bluestein's algorithm: copy the data to the inner buffers, apply some twiddle factors, zero out the rest of the inner buffer
         unsafe {
@@ -284,17 +333,14 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
             .process_with_scratch(inner_input, inner_scratch);
 
         // Multiply our inner FFT output by our precomputed data. Then, conjugate the result to set up for an inverse FFT.
-        // We can conjugate the result of multiplication by conjugating both inputs. We pre-conjugated the multiplier array,
-        // so we just need to conjugate inner_input, which the pairwise_complex_multiply_conjugated function will handle
         unsafe {
             // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
             let transmuted_inner_input: &mut [Complex<A>] =
                 array_utils::workaround_transmute_mut(inner_input);
+            let transmuted_multiplier: &[Complex<A>] =
+                array_utils::workaround_transmute(&self.inner_fft_multiplier);
 
-            Self::pairwise_complex_multiply_conjugated(
-                transmuted_inner_input,
-                &self.inner_fft_multiplier,
-            )
+            self.multiply_by_chirp_spectrum(transmuted_inner_input, transmuted_multiplier)
         };
 
         // inverse FFT. we're computing a forward but we're massaging it into an inverse by conjugating the inputs and outputs
@@ -320,8 +366,17 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
         output: &mut [Complex<T>],
         scratch: &mut [Complex<T>],
     ) {
-        let (inner_input, inner_scratch) = scratch
-            .split_at_mut(self.inner_fft_multiplier.len() * A::VectorType::COMPLEX_PER_VECTOR);
+        // Bluestein's algorithm never writes to its input, so the out-of-place FFT is the same as the immutable FFT
+        self.perform_fft_immutable(input, output, scratch)
+    }
+
+    fn perform_fft_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        let (inner_input, inner_scratch) = scratch.split_at_mut(self.inner_fft_multiplier.len());
 
         // do the necessary setup for bluestein's algorithm: copy the data to the inner buffers, apply some twiddle factors, zero out the rest of the inner buffer
         unsafe {
@@ -339,17 +394,14 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
             .process_with_scratch(inner_input, inner_scratch);
 
         // Multiply our inner FFT output by our precomputed data. Then, conjugate the result to set up for an inverse FFT.
-        // We can conjugate the result of multiplication by conjugating both inputs. We pre-conjugated the multiplier array,
-        // so we just need to conjugate inner_input, which the pairwise_complex_multiply_conjugated function will handle
         unsafe {
             // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
             let transmuted_inner_input: &mut [Complex<A>] =
                 array_utils::workaround_transmute_mut(inner_input);
+            let transmuted_multiplier: &[Complex<A>] =
+                array_utils::workaround_transmute(&self.inner_fft_multiplier);
 
-            Self::pairwise_complex_multiply_conjugated(
-                transmuted_inner_input,
-                &self.inner_fft_multiplier,
-            )
+            self.multiply_by_chirp_spectrum(transmuted_inner_input, transmuted_multiplier)
         };
 
         // inverse FFT. we're computing a forward but we're massaging it into an inverse by conjugating the inputs and outputs
@@ -368,6 +420,57 @@ impl<A: AvxNum, T: FftNum> BluesteinsAvx<A, T> {
             self.finalize_bluesteins(transmuted_inner_input, transmuted_output)
         }
     }
+
+    fn perform_fft_split(&self, re: &mut [T], im: &mut [T], scratch: &mut [Complex<T>]) {
+        let (inner_input, inner_scratch) = scratch.split_at_mut(self.inner_fft_multiplier.len());
+
+        // do the necessary setup for bluestein's algorithm, interleaving the split-complex input as we load it
+        unsafe {
+            // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+            let input = SplitSlice::<A>::new(
+                array_utils::workaround_transmute(re),
+                array_utils::workaround_transmute(im),
+            );
+            let transmuted_inner_input: &mut [Complex<A>] =
+                array_utils::workaround_transmute_mut(inner_input);
+
+            self.prepare_bluesteins(&input, transmuted_inner_input)
+        }
+
+        // run our inner forward FFT
+        self.common_data
+            .inner_fft
+            .process_with_scratch(inner_input, inner_scratch);
+
+        // Multiply our inner FFT output by our precomputed data. Then, conjugate the result to set up for an inverse FFT.
+        unsafe {
+            // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+            let transmuted_inner_input: &mut [Complex<A>] =
+                array_utils::workaround_transmute_mut(inner_input);
+            let transmuted_multiplier: &[Complex<A>] =
+                array_utils::workaround_transmute(&self.inner_fft_multiplier);
+
+            self.multiply_by_chirp_spectrum(transmuted_inner_input, transmuted_multiplier)
+        };
+
+        // inverse FFT. we're computing a forward but we're massaging it into an inverse by conjugating the inputs and outputs
+        self.common_data
+            .inner_fft
+            .process_with_scratch(inner_input, inner_scratch);
+
+        // finalize the result, splitting it back out as we store it
+        unsafe {
+            // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+            let mut output = SplitSliceMut::<A>::new(
+                array_utils::workaround_transmute_mut(re),
+                array_utils::workaround_transmute_mut(im),
+            );
+            let transmuted_inner_input: &mut [Complex<A>] =
+                array_utils::workaround_transmute_mut(inner_input);
+
+            self.finalize_bluesteins(transmuted_inner_input, &mut output)
+        }
+    }
 }
 
 #[cfg(test)]
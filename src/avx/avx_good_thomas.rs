@@ -0,0 +1,383 @@
+use std::any::TypeId;
+use std::arch::x86_64::*;
+use std::cmp::max;
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use num_complex::Complex;
+use num_integer::{div_ceil, gcd};
+
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{Direction, Fft, FftDirection, FftNum, Length};
+
+use super::avx_vector::{AvxArrayMut, AvxVector, AvxVector256};
+use super::AvxNum;
+
+/// Implementation of the Good-Thomas Algorithm (AKA Prime Factor Algorithm), using AVX2 instructions.
+///
+/// This algorithm factors a size n FFT into n1 * n2, where GCD(n1, n2) == 1. Unlike mixed radix, it doesn't need any twiddle factors
+/// between the two sets of inner FFTs. Instead, it reorders the input with the Chinese Remainder Theorem mapping, and reorders the output
+/// with the Ruritanian mapping. Both reorderings are precomputed as tables of indexes, and applied with AVX2 gathers.
+///
+/// Conceptually, this algorithm is very similar to [`GoodThomasAlgorithm`](crate::algorithm::GoodThomasAlgorithm), except that the reindexing is vectorized.
+pub struct GoodThomasAvx2<A: AvxNum, T> {
+    width: usize,
+    width_size_fft: Arc<dyn Fft<T>>,
+
+    height: usize,
+    height_size_fft: Arc<dyn Fft<T>>,
+
+    // Gather indexes for each vector of the input and output reindexing. For f64, each index is duplicated, because each complex number takes 2 lanes of the gather
+    input_index_mapping: Box<[__m128i]>,
+    output_index_mapping: Box<[__m128i]>,
+
+    len: usize,
+
+    inplace_scratch_len: usize,
+    outofplace_scratch_len: usize,
+    direction: FftDirection,
+
+    _phantom: std::marker::PhantomData<A>,
+}
+
+impl<A: AvxNum, T: FftNum> GoodThomasAvx2<A, T> {
+    /// Creates a FFT instance which will process inputs/outputs of size `width_fft.len() * height_fft.len()`
+    ///
+    /// Returns Ok(instance) if this machine has the required instruction sets ("avx", "fma", and "avx2"), Err() if some instruction sets are missing
+    ///
+    /// # Panics
+    /// Panics if `width_fft` and `height_fft` have different directions, if their lengths aren't coprime, or if the product of their lengths is 2^31 or larger.
+    #[inline]
+    pub fn new(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Result<Self, ()> {
+        // Internal sanity check: Make sure that A == T.
+        // This struct has two generic parameters A and T, but they must always be the same, and are only kept separate to help work around the lack of specialization.
+        // It would be cool if we could do this as a static_assert instead
+        let id_a = TypeId::of::<A>();
+        let id_t = TypeId::of::<T>();
+        assert_eq!(id_a, id_t);
+
+        let has_avx = is_x86_feature_detected!("avx");
+        let has_avx2 = is_x86_feature_detected!("avx2");
+        let has_fma = is_x86_feature_detected!("fma");
+        if has_avx && has_avx2 && has_fma {
+            // Safety: new_with_avx requires the "avx" feature set. Since we know it's present, we're safe
+            Ok(unsafe { Self::new_with_avx(width_fft, height_fft) })
+        } else {
+            Err(())
+        }
+    }
+
+    #[target_feature(enable = "avx")]
+    unsafe fn new_with_avx(width_fft: Arc<dyn Fft<T>>, height_fft: Arc<dyn Fft<T>>) -> Self {
+        assert_eq!(
+            width_fft.fft_direction(),
+            height_fft.fft_direction(),
+            "width_fft and height_fft must have the same direction. got width direction={}, height direction={}",
+            width_fft.fft_direction(),
+            height_fft.fft_direction()
+        );
+
+        let width = width_fft.len();
+        let height = height_fft.len();
+        let len = width * height;
+        let direction = width_fft.fft_direction();
+
+        assert_eq!(
+            gcd(width, height),
+            1,
+            "For the Good-Thomas Algorithm, width and height must be coprime. Got width={}, height={}",
+            width,
+            height
+        );
+        let _: i32 = len.try_into().unwrap_or_else(|_| {
+            panic!(
+                "The AVX Good-Thomas Algorithm's gather indexes are 32-bit, so its size must be less than 2^31. Got {}",
+                len
+            )
+        });
+
+        // The width FFTs read rows of `width` elements, where element x of row y is the input index that's congruent to x mod width and y mod height (the CRT mapping).
+        // Walking through the input in order, both remainders increment by 1, so we can compute this without any division.
+        let mut input_mapping = vec![0; len];
+        let (mut x, mut y) = (0, 0);
+        for input_index in 0..len {
+            input_mapping[y * width + x] = input_index;
+            x += 1;
+            if x == width {
+                x = 0;
+            }
+            y += 1;
+            if y == height {
+                y = 0;
+            }
+        }
+
+        // After the height FFTs, element y of row x belongs in output index (x * height + y * width) mod len (the Ruritanian mapping).
+        let mut output_mapping = vec![0; len];
+        for x in 0..width {
+            let mut output_index = x * height;
+            for y in 0..height {
+                output_mapping[output_index] = x * height + y;
+                output_index += width;
+                if output_index >= len {
+                    output_index -= len;
+                }
+            }
+        }
+
+        // Collect some data about what kind of scratch space our inner FFTs need. This is the same layout as the scalar GoodThomasAlgorithm
+        let width_inplace_scratch = width_fft.get_inplace_scratch_len();
+        let height_inplace_scratch = height_fft.get_inplace_scratch_len();
+        let height_outofplace_scratch = height_fft.get_outofplace_scratch_len();
+
+        let max_inner_inplace_scratch = max(height_inplace_scratch, width_inplace_scratch);
+        let outofplace_scratch_len = if max_inner_inplace_scratch > len {
+            max_inner_inplace_scratch
+        } else {
+            0
+        };
+        let inplace_scratch_len = len
+            + max(
+                if width_inplace_scratch > len {
+                    width_inplace_scratch
+                } else {
+                    0
+                },
+                height_outofplace_scratch,
+            );
+
+        Self {
+            width,
+            width_size_fft: width_fft,
+
+            height,
+            height_size_fft: height_fft,
+
+            input_index_mapping: Self::gather_indexes(&input_mapping),
+            output_index_mapping: Self::gather_indexes(&output_mapping),
+
+            len,
+
+            inplace_scratch_len,
+            outofplace_scratch_len,
+            direction,
+
+            _phantom: std::marker::PhantomData,
+        }
+    }
+
+    // Packs a mapping into one vector of gather indexes per output vector. The last vector is padded with index 0, which is always in bounds
+    fn gather_indexes(mapping: &[usize]) -> Box<[__m128i]> {
+        let padded_len = div_ceil(mapping.len(), A::VectorType::COMPLEX_PER_VECTOR)
+            * A::VectorType::COMPLEX_PER_VECTOR;
+        let mut padded_mapping: Vec<i32> = mapping.iter().map(|index| *index as i32).collect();
+        padded_mapping.resize(padded_len, 0);
+
+        // the actual vector of indexes depends on whether we're f32 or f64
+        if A::VectorType::COMPLEX_PER_VECTOR == 4 {
+            padded_mapping
+                .chunks_exact(4)
+                .map(|chunk| unsafe { _mm_loadu_si128(chunk.as_ptr() as *const __m128i) })
+                .collect()
+        } else {
+            padded_mapping
+                .chunks_exact(2)
+                .map(|chunk| {
+                    let duplicated_indexes = [chunk[0], chunk[0], chunk[1], chunk[1]];
+                    unsafe { _mm_loadu_si128(duplicated_indexes.as_ptr() as *const __m128i) }
+                })
+                .collect()
+        }
+    }
+
+    // Copies input[mapping[i]] to output[i] for every i, using AVX2 gathers
+    #[target_feature(enable = "avx2", enable = "avx")]
+    unsafe fn reindex(input: &[Complex<A>], output: &mut [Complex<A>], mapping: &[__m128i]) {
+        let mut chunks_iter = output.chunks_exact_mut(A::VectorType::COMPLEX_PER_VECTOR);
+        for (chunk, index_chunk) in chunks_iter.by_ref().zip(mapping.iter()) {
+            let gathered_elements =
+                A::VectorType::gather_complex_avx2_index32(input.as_ptr(), *index_chunk);
+            chunk.store_complex(gathered_elements, 0);
+        }
+
+        // The last index vector is padded with valid indexes, so we can gather a whole vector, and only store the part we need
+        let output_remainder = chunks_iter.into_remainder();
+        if !output_remainder.is_empty() {
+            let index_chunk = *mapping.get_unchecked(mapping.len() - 1);
+            let gathered_elements =
+                A::VectorType::gather_complex_avx2_index32(input.as_ptr(), index_chunk);
+            match output_remainder.len() {
+                1 => output_remainder.store_partial1_complex(gathered_elements.lo(), 0),
+                2 => output_remainder.store_partial2_complex(gathered_elements.lo(), 0),
+                3 => output_remainder.store_partial3_complex(gathered_elements, 0),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    fn reindex_input(&self, source: &[Complex<T>], destination: &mut [Complex<T>]) {
+        // Safety: reindex requires the "avx" and "avx2" instruction sets, and we return Err() in our constructor if the instructions aren't available
+        // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+        unsafe {
+            Self::reindex(
+                array_utils::workaround_transmute(source),
+                array_utils::workaround_transmute_mut(destination),
+                &self.input_index_mapping,
+            )
+        }
+    }
+
+    fn reindex_output(&self, source: &[Complex<T>], destination: &mut [Complex<T>]) {
+        // Safety: reindex requires the "avx" and "avx2" instruction sets, and we return Err() in our constructor if the instructions aren't available
+        // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+        unsafe {
+            Self::reindex(
+                array_utils::workaround_transmute(source),
+                array_utils::workaround_transmute_mut(destination),
+                &self.output_index_mapping,
+            )
+        }
+    }
+
+    fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
+        let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
+
+        // Re-index the input, copying from the buffer to the scratch in the process
+        self.reindex_input(buffer, scratch);
+
+        // run FFTs of size `width`
+        let width_scratch = if inner_scratch.len() > buffer.len() {
+            &mut inner_scratch[..]
+        } else {
+            &mut buffer[..]
+        };
+        self.width_size_fft
+            .process_with_scratch(scratch, width_scratch);
+
+        // transpose
+        transpose::transpose(scratch, buffer, self.width, self.height);
+
+        // run FFTs of size 'height'
+        self.height_size_fft
+            .process_outofplace_with_scratch(buffer, scratch, inner_scratch);
+
+        // Re-index the output, copying from the scratch to the buffer in the process
+        self.reindex_output(scratch, buffer);
+    }
+
+    fn perform_fft_out_of_place(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        // Re-index the input, copying from the input to the output in the process
+        self.reindex_input(input, output);
+
+        // run FFTs of size `width`
+        let width_scratch = if scratch.len() > input.len() {
+            &mut scratch[..]
+        } else {
+            &mut input[..]
+        };
+        self.width_size_fft
+            .process_with_scratch(output, width_scratch);
+
+        // transpose
+        transpose::transpose(output, input, self.width, self.height);
+
+        // run FFTs of size 'height'
+        let height_scratch = if scratch.len() > output.len() {
+            &mut scratch[..]
+        } else {
+            &mut output[..]
+        };
+        self.height_size_fft
+            .process_with_scratch(input, height_scratch);
+
+        // Re-index the output, copying from the input to the output in the process
+        self.reindex_output(input, output);
+    }
+}
+boilerplate_avx_fft!(
+    GoodThomasAvx2,
+    |this: &GoodThomasAvx2<_, _>| this.len,
+    |this: &GoodThomasAvx2<_, _>| this.inplace_scratch_len,
+    |this: &GoodThomasAvx2<_, _>| this.outofplace_scratch_len
+);
+
+#[cfg(test)]
+mod unit_tests {
+    use num_traits::Float;
+    use rand::distributions::uniform::SampleUniform;
+
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::test_utils::{check_fft_algorithm, BigScratchAlgorithm};
+
+    #[test]
+    fn test_good_thomas_avx_f32() {
+        for width in 1..12 {
+            for height in 1..12 {
+                if gcd(width, height) == 1 {
+                    test_good_thomas_with_lengths::<f32>(width, height, FftDirection::Forward);
+                    test_good_thomas_with_lengths::<f32>(width, height, FftDirection::Inverse);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_good_thomas_avx_f64() {
+        for width in 1..12 {
+            for height in 1..12 {
+                if gcd(width, height) == 1 {
+                    test_good_thomas_with_lengths::<f64>(width, height, FftDirection::Forward);
+                    test_good_thomas_with_lengths::<f64>(width, height, FftDirection::Inverse);
+                }
+            }
+        }
+    }
+
+    fn test_good_thomas_with_lengths<T: AvxNum + Float + SampleUniform>(
+        width: usize,
+        height: usize,
+        direction: FftDirection,
+    ) {
+        let width_fft = Arc::new(Dft::new(width, direction)) as Arc<dyn Fft<T>>;
+        let height_fft = Arc::new(Dft::new(height, direction)) as Arc<dyn Fft<T>>;
+        let fft = GoodThomasAvx2::<T, T>::new(width_fft, height_fft).unwrap();
+
+        check_fft_algorithm::<T>(&fft, width * height, direction);
+    }
+
+    // Verify that the inner FFTs get enough scratch when they need more than our own length
+    #[test]
+    fn test_good_thomas_avx_inner_scratch() {
+        for &(width, height) in &[(3, 4), (7, 2), (5, 13)] {
+            let width_fft = Arc::new(BigScratchAlgorithm {
+                len: width,
+                inplace_scratch: 5 * width * height,
+                outofplace_scratch: 6 * width * height,
+                direction: FftDirection::Forward,
+            }) as Arc<dyn Fft<f32>>;
+            let height_fft = Arc::new(BigScratchAlgorithm {
+                len: height,
+                inplace_scratch: 7 * width * height,
+                outofplace_scratch: 8 * width * height,
+                direction: FftDirection::Forward,
+            }) as Arc<dyn Fft<f32>>;
+            let fft = GoodThomasAvx2::<f32, f32>::new(width_fft, height_fft).unwrap();
+
+            let mut buffer = vec![Complex::new(0.0, 0.0); width * height];
+            let mut scratch = vec![Complex::new(0.0, 0.0); fft.get_inplace_scratch_len()];
+            fft.process_with_scratch(&mut buffer, &mut scratch);
+
+            let mut input = vec![Complex::new(0.0, 0.0); width * height];
+            let mut output = vec![Complex::new(0.0, 0.0); width * height];
+            let mut scratch = vec![Complex::new(0.0, 0.0); fft.get_outofplace_scratch_len()];
+            fft.process_outofplace_with_scratch(&mut input, &mut output, &mut scratch);
+        }
+    }
+}
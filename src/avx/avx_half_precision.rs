@@ -0,0 +1,58 @@
+use std::arch::x86_64::__m256;
+
+use half::f16;
+use num_complex::Complex;
+
+use super::avx_vector::{AvxVector, AvxVector256, AvxVectorF16};
+
+// Returns true if this machine supports the instruction sets that widen_f16() and narrow_f16() require
+pub fn has_f16c() -> bool {
+    is_x86_feature_detected!("avx") && is_x86_feature_detected!("f16c")
+}
+
+// Converts every element of `input` to f32, and writes it to the corresponding element of `output`
+#[target_feature(enable = "avx", enable = "f16c")]
+pub unsafe fn widen_f16(input: &[Complex<f16>], output: &mut [Complex<f32>]) {
+    assert_eq!(input.len(), output.len());
+
+    let input_chunks = input.chunks_exact(__m256::COMPLEX_PER_VECTOR);
+    let input_remainder = input_chunks.remainder();
+    let mut output_chunks = output.chunks_exact_mut(__m256::COMPLEX_PER_VECTOR);
+    for (input_chunk, output_chunk) in input_chunks.zip(output_chunks.by_ref()) {
+        let data = __m256::load_complex_f16(input_chunk.as_ptr());
+        __m256::store_complex(output_chunk.as_mut_ptr(), data);
+    }
+
+    // There's no partial load for f16 data, so convert the last few elements one at a time
+    for (input_element, output_element) in input_remainder
+        .iter()
+        .zip(output_chunks.into_remainder().iter_mut())
+    {
+        *output_element = Complex::new(input_element.re.to_f32(), input_element.im.to_f32());
+    }
+}
+
+// Rounds every element of `input` to the nearest f16, and writes it to the corresponding element of `output`
+#[target_feature(enable = "avx", enable = "f16c")]
+pub unsafe fn narrow_f16(input: &[Complex<f32>], output: &mut [Complex<f16>]) {
+    assert_eq!(input.len(), output.len());
+
+    let input_chunks = input.chunks_exact(__m256::COMPLEX_PER_VECTOR);
+    let input_remainder = input_chunks.remainder();
+    let mut output_chunks = output.chunks_exact_mut(__m256::COMPLEX_PER_VECTOR);
+    for (input_chunk, output_chunk) in input_chunks.zip(output_chunks.by_ref()) {
+        let data = __m256::load_complex(input_chunk.as_ptr());
+        __m256::store_complex_f16(output_chunk.as_mut_ptr(), data);
+    }
+
+    // There's no partial store for f16 data, so convert the last few elements one at a time
+    for (input_element, output_element) in input_remainder
+        .iter()
+        .zip(output_chunks.into_remainder().iter_mut())
+    {
+        *output_element = Complex::new(
+            f16::from_f32(input_element.re),
+            f16::from_f32(input_element.im),
+        );
+    }
+}
@@ -2,14 +2,24 @@ use std::any::TypeId;
 use std::sync::Arc;
 
 use num_complex::Complex;
-use num_integer::div_ceil;
 
+use crate::algorithm::MixedRadix;
 use crate::array_utils;
+use crate::array_utils::{RawSlice, RawSliceMut};
 use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::twiddles::TwiddleView;
 use crate::{Direction, Fft, FftDirection, FftNum, Length};
 
+#[cfg(feature = "half")]
+use crate::half_precision::FusedHalfFft;
+#[cfg(feature = "half")]
+use half::f16;
+
+#[cfg(feature = "half")]
+use super::FusedPasses;
 use super::{AvxNum, CommonSimdData};
 
+use super::avx_split::{SplitSlice, SplitSliceMut};
 use super::avx_vector;
 use super::avx_vector::{AvxArray, AvxArrayMut, AvxVector, AvxVector128, AvxVector256, Rotation90};
 
@@ -30,12 +40,61 @@ macro_rules! boilerplate_mixedradix {
             let has_fma = is_x86_feature_detected!("fma");
             if has_avx && has_fma {
                 // Safety: new_with_avx requires the "avx" feature set. Since we know it's present, we're safe
-                Ok(unsafe { Self::new_with_avx(inner_fft) })
+                Ok(unsafe { Self::new_with_avx(inner_fft, None) })
+            } else {
+                Err(())
+            }
+        }
+
+        // Like `new`, but reads its twiddle factors from a table computed by `MixedRadix::compute_twiddles`, so that it can be shared with other FFTs
+        #[inline]
+        pub(crate) fn new_with_twiddles(
+            inner_fft: Arc<dyn Fft<T>>,
+            twiddles: TwiddleView<T>,
+        ) -> Result<Self, ()> {
+            let id_a = TypeId::of::<A>();
+            let id_t = TypeId::of::<T>();
+            assert_eq!(id_a, id_t);
+
+            let has_avx = is_x86_feature_detected!("avx");
+            let has_fma = is_x86_feature_detected!("fma");
+            if has_avx && has_fma {
+                // Safety: new_with_avx requires the "avx" feature set. Since we know it's present, we're safe
+                Ok(unsafe { Self::new_with_avx(inner_fft, Some(twiddles)) })
             } else {
                 Err(())
             }
         }
 
+        /// Preallocates necessary arrays and precomputes necessary data to efficiently compute the FFT, with every output multiplied by `scale`
+        /// Returns Ok() if this machine has the required instruction sets, Err() if some instruction sets are missing
+        #[inline]
+        pub fn new_scaled(inner_fft: Arc<dyn Fft<T>>, scale: f64) -> Result<Self, ()> {
+            let mut result = Self::new(inner_fft)?;
+
+            // Safety: scale_output requires the "avx" feature set. If we got this far, we know it's present
+            unsafe { result.scale_output(scale) };
+            Ok(result)
+        }
+
+        // Multiplies every output of this FFT by `scale`.
+        // Rows 1 and up are scaled for free by folding the scale into the twiddle factors, but row 0 doesn't get twiddled, so it needs its own multiply.
+        // The scaled twiddles are a private copy, so a shared table is left untouched
+        #[target_feature(enable = "avx")]
+        unsafe fn scale_output(&mut self, scale: f64) {
+            self.common_data.twiddles = self
+                .common_data
+                .twiddles
+                .scaled(T::from_f64(scale).unwrap());
+
+            let scale = A::from_f64(scale).unwrap();
+            let scale_vector = AvxVector256::broadcast_complex_elements(Complex::new(scale, scale));
+            self.first_row_scale = Some(match self.first_row_scale {
+                Some(existing) => AvxVector::mul(existing, scale_vector),
+                None => scale_vector,
+            });
+        }
+
         #[inline]
         fn perform_fft_inplace(&self, buffer: &mut [Complex<T>], scratch: &mut [Complex<T>]) {
             // Perform the column FFTs
@@ -45,7 +104,10 @@ macro_rules! boilerplate_mixedradix {
                 let transmuted_buffer: &mut [Complex<A>] =
                     array_utils::workaround_transmute_mut(buffer);
 
-                self.perform_column_butterflies(transmuted_buffer)
+                self.perform_column_butterflies(
+                    RawSlice::new(transmuted_buffer),
+                    RawSliceMut::new(transmuted_buffer),
+                )
             }
 
             // process the row FFTs
@@ -83,7 +145,10 @@ macro_rules! boilerplate_mixedradix {
                 let transmuted_input: &mut [Complex<A>] =
                     array_utils::workaround_transmute_mut(input);
 
-                self.perform_column_butterflies(transmuted_input);
+                self.perform_column_butterflies(
+                    RawSlice::new(transmuted_input),
+                    RawSliceMut::new(transmuted_input),
+                );
             }
 
             // process the row FFTs. If extra scratch was provided, pass it in. Otherwise, use the output.
@@ -108,41 +173,194 @@ macro_rules! boilerplate_mixedradix {
                 self.transpose(transmuted_input, transmuted_output)
             }
         }
+
+        #[inline]
+        fn perform_fft_split(&self, re: &mut [T], im: &mut [T], scratch: &mut [Complex<T>]) {
+            // Perform the column FFTs, interleaving the split-complex input as it's loaded
+            let (work_buffer, inner_scratch) = scratch.split_at_mut(self.len());
+            // Safety: self.perform_column_butterflies_split() requres the "avx" and "fma" instruction sets, and we return Err() in our constructor if the instructions aren't available
+            unsafe {
+                // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+                let transmuted_buffer: &mut [Complex<A>] =
+                    array_utils::workaround_transmute_mut(work_buffer);
+
+                self.perform_column_butterflies_split(
+                    array_utils::workaround_transmute(re),
+                    array_utils::workaround_transmute(im),
+                    RawSliceMut::new(transmuted_buffer),
+                );
+            }
+
+            // process the row FFTs
+            self.common_data
+                .inner_fft
+                .process_with_scratch(work_buffer, inner_scratch);
+
+            // Transpose, splitting the output back out as it's stored
+            // Safety: self.transpose_split() requres the "avx" instruction set, and we return Err() in our constructor if the instructions aren't available
+            unsafe {
+                // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+                self.transpose_split(
+                    array_utils::workaround_transmute(work_buffer),
+                    array_utils::workaround_transmute_mut(re),
+                    array_utils::workaround_transmute_mut(im),
+                )
+            }
+        }
+
+        #[target_feature(enable = "avx", enable = "fma")]
+        unsafe fn perform_column_butterflies_split(
+            &self,
+            re: &[A],
+            im: &[A],
+            output: RawSliceMut<Complex<A>>,
+        ) {
+            self.column_butterflies(&SplitSlice::new(re, im), output)
+        }
+
+        #[target_feature(enable = "avx")]
+        unsafe fn transpose_split(&self, input: &[Complex<A>], re: &mut [A], im: &mut [A]) {
+            self.transpose_to(input, &mut SplitSliceMut::new(re, im))
+        }
+
+        #[inline]
+        fn perform_fft_immutable(
+            &self,
+            input: &[Complex<T>],
+            output: &mut [Complex<T>],
+            scratch: &mut [Complex<T>],
+        ) {
+            // Perform the column FFTs, reading from the input and writing to the output, so that the input is left untouched
+            // Safety: self.perform_column_butterflies() requres the "avx" and "fma" instruction sets, and we return Err() in our constructor if the instructions aren't avaiable
+            unsafe {
+                // Specialization workaround: See the comments in FftPlannerAvx::new() for why we have to transmute these slices
+                self.perform_column_butterflies(
+                    RawSlice::new_transmuted(input),
+                    RawSliceMut::new_transmuted(output),
+                );
+            }
+
+            // process the row FFTs
+            let (scratch, inner_scratch) = scratch.split_at_mut(self.len());
+            self.common_data.inner_fft.process_outofplace_with_scratch(
+                output,
+                scratch,
+                inner_scratch,
+            );
+
+            // Transpose
+            // Safety: self.transpose() requres the "avx" instruction set, and we return Err() in our constructor if the instructions aren't available
+            unsafe {
+                // Specialization workaround: See the comments in FftPlannerAvx::new() for why these calls to array_utils::workaround_transmute are necessary
+                let transmuted_scratch: &mut [Complex<A>] =
+                    array_utils::workaround_transmute_mut(scratch);
+                let transmuted_output: &mut [Complex<A>] =
+                    array_utils::workaround_transmute_mut(output);
+
+                self.transpose(transmuted_scratch, transmuted_output)
+            }
+        }
+    };
+}
+
+// Implements FusedPasses for a mixed radix struct. For f32, the column FFTs can read f16 input and the transpose can write f16 output,
+// converting with F16C instructions as the data is loaded and stored, so HalfPrecisionFft doesn't need separate conversion passes
+macro_rules! boilerplate_mixedradix_fused {
+    ($struct_name:ident) => {
+        #[cfg(feature = "half")]
+        impl<A: AvxNum, T: FftNum> FusedPasses for $struct_name<A, T> {
+            fn fused_f16(&self) -> Option<&dyn FusedHalfFft<f16>> {
+                if TypeId::of::<A>() == TypeId::of::<f32>() {
+                    // Safety: We just checked that A is f32, and our constructor asserts that T is the same type as A, so this is a no-op cast
+                    Some(unsafe { &*(self as *const Self as *const $struct_name<f32, f32>) })
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[cfg(feature = "half")]
+        impl $struct_name<f32, f32> {
+            #[target_feature(enable = "avx", enable = "fma", enable = "f16c")]
+            unsafe fn perform_column_butterflies_f16(
+                &self,
+                input: &[Complex<f16>],
+                output: RawSliceMut<Complex<f32>>,
+            ) {
+                self.column_butterflies(input, output)
+            }
+
+            #[target_feature(enable = "avx", enable = "f16c")]
+            unsafe fn transpose_f16(&self, input: &[Complex<f32>], output: &mut [Complex<f16>]) {
+                self.transpose_to(input, output)
+            }
+        }
+
+        #[cfg(feature = "half")]
+        impl FusedHalfFft<f16> for $struct_name<f32, f32> {
+            unsafe fn process_half_inplace(
+                &self,
+                buffer: &mut [Complex<f16>],
+                scratch: &mut [Complex<f32>],
+            ) {
+                // Convert to f32 while computing the column FFTs, then do the row FFTs in f32, then convert back to f16 while transposing.
+                // The column FFTs read the whole buffer before the transpose writes to it, so the buffer can be both the input and output
+                let (work_buffer, inner_scratch) = scratch.split_at_mut(self.len());
+                self.perform_column_butterflies_f16(buffer, RawSliceMut::new(work_buffer));
+                self.common_data
+                    .inner_fft
+                    .process_with_scratch(work_buffer, inner_scratch);
+                self.transpose_f16(work_buffer, buffer);
+            }
+
+            unsafe fn process_half_outofplace(
+                &self,
+                input: &[Complex<f16>],
+                output: &mut [Complex<f16>],
+                scratch: &mut [Complex<f32>],
+            ) {
+                let (work_buffer, inner_scratch) = scratch.split_at_mut(self.len());
+                self.perform_column_butterflies_f16(input, RawSliceMut::new(work_buffer));
+                self.common_data
+                    .inner_fft
+                    .process_with_scratch(work_buffer, inner_scratch);
+                self.transpose_f16(work_buffer, output);
+            }
+
+            fn get_half_scratch_len(&self) -> usize {
+                self.len() + self.common_data.inner_fft.get_inplace_scratch_len()
+            }
+        }
     };
 }
 
 macro_rules! mixedradix_gen_data {
-    ($row_count: expr, $inner_fft:expr) => {{
+    ($row_count: expr, $inner_fft:expr, $twiddles:expr) => {{
         // Important constants
         const ROW_COUNT : usize = $row_count;
-        const TWIDDLES_PER_COLUMN : usize = ROW_COUNT - 1;
 
         // derive some info from our inner FFT
         let direction = $inner_fft.fft_direction();
         let len_per_row = $inner_fft.len();
         let len = len_per_row * ROW_COUNT;
 
-        // We're going to process each row of the FFT one AVX register at a time. We need to know how many AVX registers each row can fit,
-        // and if the last register in each row going to have partial data (ie a remainder)
-        let quotient = len_per_row / A::VectorType::COMPLEX_PER_VECTOR;
-        let remainder = len_per_row % A::VectorType::COMPLEX_PER_VECTOR;
-
-        // Compute our twiddle factors, and arrange them so that we can access them one column of AVX vectors at a time
-        let num_twiddle_columns = quotient + div_ceil(remainder, A::VectorType::COMPLEX_PER_VECTOR);
-        let mut twiddles = Vec::with_capacity(num_twiddle_columns * TWIDDLES_PER_COLUMN);
-        for x in 0..num_twiddle_columns {
-            for y in 1..ROW_COUNT {
-                twiddles.push(AvxVector::make_mixedradix_twiddle_chunk(x * A::VectorType::COMPLEX_PER_VECTOR, y, len, direction));
-            }
-        }
+        // Our twiddle factors have the same layout as the ones in the scalar MixedRadix, with one row per row of this FFT.
+        // The column butterflies load a vector's worth of each row at a time, so each row must be contiguous, but rows may be spaced out within a larger shared table
+        let twiddles : TwiddleView<T> = match $twiddles {
+            Some(twiddles) => twiddles,
+            None => TwiddleView::new(MixedRadix::compute_twiddles(ROW_COUNT, len_per_row, direction), ROW_COUNT, len_per_row),
+        };
+        assert_eq!((twiddles.width(), twiddles.height()), (ROW_COUNT, len_per_row), "The twiddle table must have one row per row of this FFT");
+        assert_eq!(twiddles.column_stride(), 1, "Each row of the twiddle table must be contiguous");
 
         let inner_outofplace_scratch = $inner_fft.get_outofplace_scratch_len();
         let inner_inplace_scratch = $inner_fft.get_inplace_scratch_len();
 
         CommonSimdData {
-            twiddles: twiddles.into_boxed_slice(),
+            twiddles,
             inplace_scratch_len: len + inner_outofplace_scratch,
             outofplace_scratch_len: if inner_inplace_scratch > len { inner_inplace_scratch } else { 0 },
+            split_scratch_len: len + inner_inplace_scratch,
             inner_fft: $inner_fft,
             len,
             direction,
@@ -153,41 +371,58 @@ macro_rules! mixedradix_gen_data {
 macro_rules! mixedradix_column_butterflies {
     ($row_count: expr, $butterfly_fn: expr, $butterfly_fn_lo: expr) => {
         #[target_feature(enable = "avx", enable = "fma")]
-        unsafe fn perform_column_butterflies(&self, buffer: &mut [Complex<A>]) {
+        unsafe fn perform_column_butterflies(
+            &self,
+            input: RawSlice<Complex<A>>,
+            output_buffer: RawSliceMut<Complex<A>>,
+        ) {
+            self.column_butterflies(&input, output_buffer)
+        }
+
+        // Computes the column FFTs, reading from any kind of array that can be loaded into vectors of A, so that other element types can be converted as they're loaded.
+        // Always inlined, so that it picks up the target features of the function that calls it
+        #[inline(always)]
+        unsafe fn column_butterflies<I: AvxArray<A> + ?Sized>(
+            &self,
+            input: &I,
+            mut output_buffer: RawSliceMut<Complex<A>>,
+        ) {
             // How many rows this FFT has, ie 2 for 2xn, 4 for 4xn, etc
             const ROW_COUNT: usize = $row_count;
-            const TWIDDLES_PER_COLUMN: usize = ROW_COUNT - 1;
 
             let len_per_row = self.len() / ROW_COUNT;
             let chunk_count = len_per_row / A::VectorType::COMPLEX_PER_VECTOR;
 
+            // Specialization workaround: See the comments in FftPlannerAvx::new() for why we have to transmute these slices
+            let twiddles: &[Complex<A>] =
+                array_utils::workaround_transmute(self.common_data.twiddles.table());
+            let twiddle_row_stride = self.common_data.twiddles.row_stride();
+
             // process the column FFTs
-            for (c, twiddle_chunk) in self
-                .common_data
-                .twiddles
-                .chunks_exact(TWIDDLES_PER_COLUMN)
-                .take(chunk_count)
-                .enumerate()
-            {
+            for c in 0..chunk_count {
                 let index_base = c * A::VectorType::COMPLEX_PER_VECTOR;
 
-                // Load columns from the buffer into registers
+                // Load columns from the input into registers
                 let mut columns = [AvxVector::zero(); ROW_COUNT];
                 for i in 0..ROW_COUNT {
-                    columns[i] = buffer.load_complex(index_base + len_per_row * i);
+                    columns[i] = input.load_complex(index_base + len_per_row * i);
                 }
 
                 // apply our butterfly function down the columns
                 let output = $butterfly_fn(columns, self);
 
-                // always write the first row directly back without twiddles
-                buffer.store_complex(output[0], index_base);
+                // always write the first row directly back without twiddles, but with the output scale if we have one
+                let output0 = match self.first_row_scale {
+                    Some(scale) => AvxVector::mul(output[0], scale),
+                    None => output[0],
+                };
+                output_buffer.store_complex(output0, index_base);
 
                 // for every other row, apply twiddle factors and then write back to memory
                 for i in 1..ROW_COUNT {
-                    let twiddle = twiddle_chunk[i - 1];
+                    let twiddle = twiddles.load_complex(twiddle_row_stride * i + index_base);
                     let output = AvxVector::mul_complex(twiddle, output[i]);
-                    buffer.store_complex(output, index_base + len_per_row * i);
+                    output_buffer.store_complex(output, index_base + len_per_row * i);
                 }
             }
 
@@ -196,30 +431,31 @@ macro_rules! mixedradix_column_butterflies {
             let partial_remainder = len_per_row % A::VectorType::COMPLEX_PER_VECTOR;
             if partial_remainder > 0 {
                 let partial_remainder_base = chunk_count * A::VectorType::COMPLEX_PER_VECTOR;
-                let partial_remainder_twiddle_base =
-                    self.common_data.twiddles.len() - TWIDDLES_PER_COLUMN;
-                let final_twiddle_chunk =
-                    &self.common_data.twiddles[partial_remainder_twiddle_base..];
 
                 if partial_remainder > 2 {
                     // Load 3 columns into full AVX vectors to preocess our remainder
                     let mut columns = [AvxVector::zero(); ROW_COUNT];
                     for i in 0..ROW_COUNT {
                         columns[i] =
-                            buffer.load_partial3_complex(partial_remainder_base + len_per_row * i);
+                            input.load_partial3_complex(partial_remainder_base + len_per_row * i);
                     }
 
                     // apply our butterfly function down the columns
                     let mid = $butterfly_fn(columns, self);
 
-                    // always write the first row without twiddles
-                    buffer.store_partial3_complex(mid[0], partial_remainder_base);
+                    // always write the first row without twiddles, but with the output scale if we have one
+                    let mid0 = match self.first_row_scale {
+                        Some(scale) => AvxVector::mul(mid[0], scale),
+                        None => mid[0],
+                    };
+                    output_buffer.store_partial3_complex(mid0, partial_remainder_base);
 
                     // for the remaining rows, apply twiddle factors and then write back to memory
                     for i in 1..ROW_COUNT {
-                        let twiddle = final_twiddle_chunk[i - 1];
+                        let twiddle = twiddles
+                            .load_partial3_complex(twiddle_row_stride * i + partial_remainder_base);
                         let output = AvxVector::mul_complex(twiddle, mid[i]);
-                        buffer.store_partial3_complex(
+                        output_buffer.store_partial3_complex(
                             output,
                             partial_remainder_base + len_per_row * i,
                         );
@@ -230,14 +466,14 @@ macro_rules! mixedradix_column_butterflies {
                     if partial_remainder == 1 {
                         for i in 0..ROW_COUNT {
                             columns[i] = AvxArray::<A>::load_partial1_complex(
-                                buffer,
+                                input,
                                 partial_remainder_base + len_per_row * i,
                             );
                         }
                     } else {
                         for i in 0..ROW_COUNT {
                             columns[i] = AvxArray::<A>::load_partial2_complex(
-                                buffer,
+                                input,
                                 partial_remainder_base + len_per_row * i,
                             );
                         }
@@ -246,16 +482,25 @@ macro_rules! mixedradix_column_butterflies {
                     // apply our butterfly function down the columns
                     let mut mid = $butterfly_fn_lo(columns, self);
 
-                    // apply twiddle factors
+                    // apply twiddle factors, and the output scale if we have one
+                    if let Some(scale) = self.first_row_scale {
+                        mid[0] = AvxVector::mul(mid[0], scale.lo());
+                    }
                     for i in 1..ROW_COUNT {
-                        mid[i] = AvxVector::mul_complex(final_twiddle_chunk[i - 1].lo(), mid[i]);
+                        let twiddle_index = twiddle_row_stride * i + partial_remainder_base;
+                        let twiddle = if partial_remainder == 1 {
+                            AvxArray::<A>::load_partial1_complex(twiddles, twiddle_index)
+                        } else {
+                            AvxArray::<A>::load_partial2_complex(twiddles, twiddle_index)
+                        };
+                        mid[i] = AvxVector::mul_complex(twiddle, mid[i]);
                     }
 
                     // store output
                     if partial_remainder == 1 {
                         for i in 0..ROW_COUNT {
                             AvxArrayMut::<A>::store_partial1_complex(
-                                buffer,
+                                &mut output_buffer,
                                 mid[i],
                                 partial_remainder_base + len_per_row * i,
                             );
@@ -263,7 +508,7 @@ macro_rules! mixedradix_column_butterflies {
                     } else {
                         for i in 0..ROW_COUNT {
                             AvxArrayMut::<A>::store_partial2_complex(
-                                buffer,
+                                &mut output_buffer,
                                 mid[i],
                                 partial_remainder_base + len_per_row * i,
                             );
@@ -281,6 +526,13 @@ macro_rules! mixedradix_transpose{
     // Transpose the input (treated as a nxc array) into the output (as a cxn array)
     #[target_feature(enable = "avx")]
     unsafe fn transpose(&self, input: &[Complex<A>], output: &mut [Complex<A>]) {
+        self.transpose_to(input, output)
+    }
+
+    // Transposes into any kind of array that vectors of A can be stored to, so that other element types can be converted as they're stored.
+    // Always inlined, so that it picks up the target features of the function that calls it
+    #[inline(always)]
+    unsafe fn transpose_to<O: AvxArrayMut<A> + ?Sized>(&self, input: &[Complex<A>], output: &mut O) {
         const ROW_COUNT : usize = $row_count;
 
         let len_per_row = self.len() / ROW_COUNT;
@@ -322,9 +574,8 @@ macro_rules! mixedradix_transpose{
         if partial_remainder == 1 {
             // If the partial remainder is 1, there's no transposing to do - just gather from across the rows and store contiguously
             for i in 0..ROW_COUNT {
-                let input_cell = input.get_unchecked(input_index_base + len_per_row*i);
-                let output_cell = output.get_unchecked_mut(output_index_base + i);
-                *output_cell = *input_cell;
+                let input_cell = AvxArray::<A>::load_partial1_complex(input, input_index_base + len_per_row*i);
+                AvxArrayMut::<A>::store_partial1_complex(output, input_cell, output_index_base + i);
             }
         } else if partial_remainder == 2 {
             // If the partial remainder is 2, use the provided transpose_lo function to do a transpose on half-vectors
@@ -378,17 +629,145 @@ macro_rules! mixedradix_transpose{
     }
 )}
 
+// Transposes `rows`, a `rows.len()` x `column_count` block of complex numbers, and writes it to `output` as a `column_count` x `rows.len()` block.
+// Each group of COMPLEX_PER_VECTOR rows is transposed as a square, and every column of the square is stored straight to the output,
+// so unlike the packed transposes, this works for any number of rows. Rows left over at the end are stored one element at a time
+#[inline(always)]
+unsafe fn store_transposed<A: AvxNum, O: AvxArrayMut<A> + ?Sized>(
+    rows: &[A::VectorType],
+    column_count: usize,
+    output: &mut O,
+    output_index_base: usize,
+) {
+    let row_count = rows.len();
+    let complex_per_vector = A::VectorType::COMPLEX_PER_VECTOR;
+    let complex_per_half_vector = <A::VectorType as AvxVector256>::HalfVector::COMPLEX_PER_VECTOR;
+
+    let mut row = 0;
+    while row + complex_per_vector <= row_count {
+        // Thankfully, the compiler is smart enough to eliminate this branch, since it only depends on the vector type
+        let transposed = if complex_per_vector == 4 {
+            AvxVector::transpose4_packed([rows[row], rows[row + 1], rows[row + 2], rows[row + 3]])
+        } else {
+            let [transposed0, transposed1] =
+                AvxVector::transpose2_packed([rows[row], rows[row + 1]]);
+            [
+                transposed0,
+                transposed1,
+                AvxVector::zero(),
+                AvxVector::zero(),
+            ]
+        };
+        for (column, transposed_column) in transposed.iter().take(column_count).enumerate() {
+            output.store_complex(
+                *transposed_column,
+                output_index_base + column * row_count + row,
+            );
+        }
+        row += complex_per_vector;
+    }
+
+    for (row, row_vector) in rows.iter().enumerate().skip(row) {
+        let (lo, hi) = row_vector.split();
+        for column in 0..column_count {
+            let half = if column < complex_per_half_vector {
+                lo
+            } else {
+                hi
+            };
+            let element = if column % complex_per_half_vector == 0 {
+                half
+            } else {
+                AvxVector::unpackhi_complex([half, half])
+            };
+            AvxArrayMut::<A>::store_partial1_complex(
+                output,
+                element,
+                output_index_base + column * row_count + row,
+            );
+        }
+    }
+}
+
+// A version of mixedradix_transpose for row counts that don't have a packed transpose. It loads the same way, but stores through `store_transposed`
+macro_rules! mixedradix_transpose_unpacked {
+    ($row_count: expr) => {
+        // Transpose the input (treated as a nxc array) into the output (as a cxn array)
+        #[target_feature(enable = "avx")]
+        unsafe fn transpose(&self, input: &[Complex<A>], output: &mut [Complex<A>]) {
+            self.transpose_to(input, output)
+        }
+
+        // Transposes into any kind of array that vectors of A can be stored to, so that other element types can be converted as they're stored.
+        // Always inlined, so that it picks up the target features of the function that calls it
+        #[inline(always)]
+        unsafe fn transpose_to<O: AvxArrayMut<A> + ?Sized>(
+            &self,
+            input: &[Complex<A>],
+            output: &mut O,
+        ) {
+            const ROW_COUNT: usize = $row_count;
+
+            let len_per_row = self.len() / ROW_COUNT;
+            let chunk_count = len_per_row / A::VectorType::COMPLEX_PER_VECTOR;
+
+            for c in 0..chunk_count {
+                let input_index_base = c * A::VectorType::COMPLEX_PER_VECTOR;
+
+                // Load rows from the input into registers
+                let mut rows: [A::VectorType; ROW_COUNT] = [AvxVector::zero(); ROW_COUNT];
+                for i in 0..ROW_COUNT {
+                    rows[i] = input.load_complex(input_index_base + len_per_row * i);
+                }
+
+                store_transposed(
+                    &rows,
+                    A::VectorType::COMPLEX_PER_VECTOR,
+                    output,
+                    input_index_base * ROW_COUNT,
+                );
+            }
+
+            // transpose the remainder. Load the partial columns into full vectors, and only store the columns we loaded
+            let partial_remainder = len_per_row % A::VectorType::COMPLEX_PER_VECTOR;
+            if partial_remainder > 0 {
+                let input_index_base = chunk_count * A::VectorType::COMPLEX_PER_VECTOR;
+
+                let mut rows: [A::VectorType; ROW_COUNT] = [AvxVector::zero(); ROW_COUNT];
+                for i in 0..ROW_COUNT {
+                    let index = input_index_base + len_per_row * i;
+                    rows[i] = match partial_remainder {
+                        1 => AvxArray::<A>::load_partial1_complex(input, index).zero_extend(),
+                        2 => AvxArray::<A>::load_partial2_complex(input, index).zero_extend(),
+                        _ => input.load_partial3_complex(index),
+                    };
+                }
+
+                store_transposed(
+                    &rows,
+                    partial_remainder,
+                    output,
+                    input_index_base * ROW_COUNT,
+                );
+            }
+        }
+    };
+}
+
 pub struct MixedRadix2xnAvx<A: AvxNum, T> {
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix2xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix2xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix2xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
-            common_data: mixedradix_gen_data!(2, inner_fft),
+            common_data: mixedradix_gen_data!(2, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -407,17 +786,20 @@ impl<A: AvxNum, T: FftNum> MixedRadix2xnAvx<A, T> {
 
 pub struct MixedRadix3xnAvx<A: AvxNum, T> {
     twiddles_butterfly3: A::VectorType,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix3xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix3xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix3xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly3: AvxVector::broadcast_twiddle(1, 3, inner_fft.fft_direction()),
-            common_data: mixedradix_gen_data!(3, inner_fft),
+            common_data: mixedradix_gen_data!(3, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -436,17 +818,20 @@ impl<A: AvxNum, T: FftNum> MixedRadix3xnAvx<A, T> {
 
 pub struct MixedRadix4xnAvx<A: AvxNum, T> {
     twiddles_butterfly4: Rotation90<A::VectorType>,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix4xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix4xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix4xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly4: AvxVector::make_rotation90(inner_fft.fft_direction()),
-            common_data: mixedradix_gen_data!(4, inner_fft),
+            common_data: mixedradix_gen_data!(4, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -465,20 +850,23 @@ impl<A: AvxNum, T: FftNum> MixedRadix4xnAvx<A, T> {
 
 pub struct MixedRadix5xnAvx<A: AvxNum, T> {
     twiddles_butterfly5: [A::VectorType; 2],
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix5xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix5xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix5xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly5: [
                 AvxVector::broadcast_twiddle(1, 5, inner_fft.fft_direction()),
                 AvxVector::broadcast_twiddle(2, 5, inner_fft.fft_direction()),
             ],
-            common_data: mixedradix_gen_data!(5, inner_fft),
+            common_data: mixedradix_gen_data!(5, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -503,17 +891,20 @@ impl<A: AvxNum, T: FftNum> MixedRadix5xnAvx<A, T> {
 
 pub struct MixedRadix6xnAvx<A: AvxNum, T> {
     twiddles_butterfly3: A::VectorType,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix6xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix6xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix6xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly3: AvxVector::broadcast_twiddle(1, 3, inner_fft.fft_direction()),
-            common_data: mixedradix_gen_data!(6, inner_fft),
+            common_data: mixedradix_gen_data!(6, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -532,21 +923,24 @@ impl<A: AvxNum, T: FftNum> MixedRadix6xnAvx<A, T> {
 
 pub struct MixedRadix7xnAvx<A: AvxNum, T> {
     twiddles_butterfly7: [A::VectorType; 3],
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix7xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix7xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix7xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly7: [
                 AvxVector::broadcast_twiddle(1, 7, inner_fft.fft_direction()),
                 AvxVector::broadcast_twiddle(2, 7, inner_fft.fft_direction()),
                 AvxVector::broadcast_twiddle(3, 7, inner_fft.fft_direction()),
             ],
-            common_data: mixedradix_gen_data!(7, inner_fft),
+            common_data: mixedradix_gen_data!(7, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -572,17 +966,20 @@ impl<A: AvxNum, T: FftNum> MixedRadix7xnAvx<A, T> {
 
 pub struct MixedRadix8xnAvx<A: AvxNum, T> {
     twiddles_butterfly4: Rotation90<A::VectorType>,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix8xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix8xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix8xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly4: AvxVector::make_rotation90(inner_fft.fft_direction()),
-            common_data: mixedradix_gen_data!(8, inner_fft),
+            common_data: mixedradix_gen_data!(8, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -604,14 +1001,16 @@ pub struct MixedRadix9xnAvx<A: AvxNum, T> {
     twiddles_butterfly9: [A::VectorType; 3],
     twiddles_butterfly9_lo: [A::VectorType; 2],
     twiddles_butterfly3: A::VectorType,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix9xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix9xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix9xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         let inverse = inner_fft.fft_direction();
 
         let twiddle1 = AvxVector::broadcast_twiddle(1, 9, inner_fft.fft_direction());
@@ -629,7 +1028,8 @@ impl<A: AvxNum, T: FftNum> MixedRadix9xnAvx<A, T> {
                 AvxVector256::merge(twiddle2, twiddle4),
             ],
             twiddles_butterfly3: AvxVector::broadcast_twiddle(1, 3, inner_fft.fft_direction()),
-            common_data: mixedradix_gen_data!(9, inner_fft),
+            common_data: mixedradix_gen_data!(9, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -657,14 +1057,16 @@ impl<A: AvxNum, T: FftNum> MixedRadix9xnAvx<A, T> {
 
 pub struct MixedRadix11xnAvx<A: AvxNum, T> {
     twiddles_butterfly11: [A::VectorType; 5],
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix11xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix11xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix11xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         Self {
             twiddles_butterfly11: [
                 AvxVector::broadcast_twiddle(1, 11, inner_fft.fft_direction()),
@@ -673,7 +1075,8 @@ impl<A: AvxNum, T: FftNum> MixedRadix11xnAvx<A, T> {
                 AvxVector::broadcast_twiddle(4, 11, inner_fft.fft_direction()),
                 AvxVector::broadcast_twiddle(5, 11, inner_fft.fft_direction()),
             ],
-            common_data: mixedradix_gen_data!(11, inner_fft),
+            common_data: mixedradix_gen_data!(11, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -702,19 +1105,22 @@ impl<A: AvxNum, T: FftNum> MixedRadix11xnAvx<A, T> {
 pub struct MixedRadix12xnAvx<A: AvxNum, T> {
     twiddles_butterfly4: Rotation90<A::VectorType>,
     twiddles_butterfly3: A::VectorType,
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix12xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix12xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix12xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         let inverse = inner_fft.fft_direction();
         Self {
             twiddles_butterfly4: AvxVector::make_rotation90(inverse),
             twiddles_butterfly3: AvxVector::broadcast_twiddle(1, 3, inverse),
-            common_data: mixedradix_gen_data!(12, inner_fft),
+            common_data: mixedradix_gen_data!(12, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
@@ -743,14 +1149,16 @@ impl<A: AvxNum, T: FftNum> MixedRadix12xnAvx<A, T> {
 pub struct MixedRadix16xnAvx<A: AvxNum, T> {
     twiddles_butterfly4: Rotation90<A::VectorType>,
     twiddles_butterfly16: [A::VectorType; 2],
-    common_data: CommonSimdData<T, A::VectorType>,
+    common_data: CommonSimdData<T, TwiddleView<T>>,
+    first_row_scale: Option<A::VectorType>,
     _phantom: std::marker::PhantomData<T>,
 }
 boilerplate_avx_fft_commondata!(MixedRadix16xnAvx);
+boilerplate_mixedradix_fused!(MixedRadix16xnAvx);
 
 impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
     #[target_feature(enable = "avx")]
-    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>) -> Self {
+    unsafe fn new_with_avx(inner_fft: Arc<dyn Fft<T>>, twiddles: Option<TwiddleView<T>>) -> Self {
         let inverse = inner_fft.fft_direction();
         Self {
             twiddles_butterfly4: AvxVector::make_rotation90(inner_fft.fft_direction()),
@@ -758,37 +1166,54 @@ impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
                 AvxVector::broadcast_twiddle(1, 16, inverse),
                 AvxVector::broadcast_twiddle(3, 16, inverse),
             ],
-            common_data: mixedradix_gen_data!(16, inner_fft),
+            common_data: mixedradix_gen_data!(16, inner_fft, twiddles),
+            first_row_scale: None,
             _phantom: std::marker::PhantomData,
         }
     }
 
     #[target_feature(enable = "avx", enable = "fma")]
-    unsafe fn perform_column_butterflies(&self, buffer: &mut [Complex<A>]) {
+    unsafe fn perform_column_butterflies(
+        &self,
+        input: RawSlice<Complex<A>>,
+        output_buffer: RawSliceMut<Complex<A>>,
+    ) {
+        self.column_butterflies(&input, output_buffer)
+    }
+
+    // Computes the column FFTs, reading from any kind of array that can be loaded into vectors of A. See mixedradix_column_butterflies
+    #[inline(always)]
+    unsafe fn column_butterflies<I: AvxArray<A> + ?Sized>(
+        &self,
+        input: &I,
+        mut output_buffer: RawSliceMut<Complex<A>>,
+    ) {
         // How many rows this FFT has, ie 2 for 2xn, 4 for 4xn, etc
         const ROW_COUNT: usize = 16;
-        const TWIDDLES_PER_COLUMN: usize = ROW_COUNT - 1;
 
         let len_per_row = self.len() / ROW_COUNT;
         let chunk_count = len_per_row / A::VectorType::COMPLEX_PER_VECTOR;
 
+        // Specialization workaround: See the comments in FftPlannerAvx::new() for why we have to transmute these slices
+        let twiddles: &[Complex<A>] =
+            array_utils::workaround_transmute(self.common_data.twiddles.table());
+        let twiddle_row_stride = self.common_data.twiddles.row_stride();
+
         // process the column FFTs
-        for (c, twiddle_chunk) in self
-            .common_data
-            .twiddles
-            .chunks_exact(TWIDDLES_PER_COLUMN)
-            .take(chunk_count)
-            .enumerate()
-        {
+        for c in 0..chunk_count {
             let index_base = c * A::VectorType::COMPLEX_PER_VECTOR;
 
             column_butterfly16_loadfn!(
-                |index| buffer.load_complex(index_base + len_per_row * index),
+                |index| input.load_complex(index_base + len_per_row * index),
                 |mut data, index| {
                     if index > 0 {
-                        data = AvxVector::mul_complex(data, twiddle_chunk[index - 1]);
+                        let twiddle =
+                            twiddles.load_complex(twiddle_row_stride * index + index_base);
+                        data = AvxVector::mul_complex(data, twiddle);
+                    } else if let Some(scale) = self.first_row_scale {
+                        data = AvxVector::mul(data, scale);
                     }
-                    buffer.store_complex(data, index_base + len_per_row * index)
+                    output_buffer.store_complex(data, index_base + len_per_row * index)
                 },
                 self.twiddles_butterfly16,
                 self.twiddles_butterfly4
@@ -800,21 +1225,23 @@ impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
         let partial_remainder = len_per_row % A::VectorType::COMPLEX_PER_VECTOR;
         if partial_remainder > 0 {
             let partial_remainder_base = chunk_count * A::VectorType::COMPLEX_PER_VECTOR;
-            let partial_remainder_twiddle_base =
-                self.common_data.twiddles.len() - TWIDDLES_PER_COLUMN;
-            let final_twiddle_chunk = &self.common_data.twiddles[partial_remainder_twiddle_base..];
 
             match partial_remainder {
                 1 => {
                     column_butterfly16_loadfn!(
-                        |index| buffer
+                        |index| input
                             .load_partial1_complex(partial_remainder_base + len_per_row * index),
                         |mut data, index| {
                             if index > 0 {
-                                let twiddle: A::VectorType = final_twiddle_chunk[index - 1];
-                                data = AvxVector::mul_complex(data, twiddle.lo());
+                                let twiddle = AvxArray::<A>::load_partial1_complex(
+                                    twiddles,
+                                    twiddle_row_stride * index + partial_remainder_base,
+                                );
+                                data = AvxVector::mul_complex(data, twiddle);
+                            } else if let Some(scale) = self.first_row_scale {
+                                data = AvxVector::mul(data, scale.lo());
                             }
-                            buffer.store_partial1_complex(
+                            output_buffer.store_partial1_complex(
                                 data,
                                 partial_remainder_base + len_per_row * index,
                             )
@@ -828,14 +1255,19 @@ impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
                 }
                 2 => {
                     column_butterfly16_loadfn!(
-                        |index| buffer
+                        |index| input
                             .load_partial2_complex(partial_remainder_base + len_per_row * index),
                         |mut data, index| {
                             if index > 0 {
-                                let twiddle: A::VectorType = final_twiddle_chunk[index - 1];
-                                data = AvxVector::mul_complex(data, twiddle.lo());
+                                let twiddle = AvxArray::<A>::load_partial2_complex(
+                                    twiddles,
+                                    twiddle_row_stride * index + partial_remainder_base,
+                                );
+                                data = AvxVector::mul_complex(data, twiddle);
+                            } else if let Some(scale) = self.first_row_scale {
+                                data = AvxVector::mul(data, scale.lo());
                             }
-                            buffer.store_partial2_complex(
+                            output_buffer.store_partial2_complex(
                                 data,
                                 partial_remainder_base + len_per_row * index,
                             )
@@ -849,13 +1281,18 @@ impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
                 }
                 3 => {
                     column_butterfly16_loadfn!(
-                        |index| buffer
+                        |index| input
                             .load_partial3_complex(partial_remainder_base + len_per_row * index),
                         |mut data, index| {
                             if index > 0 {
-                                data = AvxVector::mul_complex(data, final_twiddle_chunk[index - 1]);
+                                let twiddle = twiddles.load_partial3_complex(
+                                    twiddle_row_stride * index + partial_remainder_base,
+                                );
+                                data = AvxVector::mul_complex(data, twiddle);
+                            } else if let Some(scale) = self.first_row_scale {
+                                data = AvxVector::mul(data, scale);
                             }
-                            buffer.store_partial3_complex(
+                            output_buffer.store_partial3_complex(
                                 data,
                                 partial_remainder_base + len_per_row * index,
                             )
@@ -876,6 +1313,61 @@ impl<A: AvxNum, T: FftNum> MixedRadix16xnAvx<A, T> {
     boilerplate_mixedradix!();
 }
 
+// Mixed radix steps for primes from 13 to 31, built on the column butterflies generated by `column_butterfly_prime!`
+macro_rules! mixedradix_prime {
+    ($struct_name:ident, $row_count:expr, $butterfly_fn:ident) => {
+        pub struct $struct_name<A: AvxNum, T> {
+            twiddles_butterfly: [A::VectorType; $row_count / 2],
+            common_data: CommonSimdData<T, TwiddleView<T>>,
+            first_row_scale: Option<A::VectorType>,
+            _phantom: std::marker::PhantomData<T>,
+        }
+        boilerplate_avx_fft_commondata!($struct_name);
+        boilerplate_mixedradix_fused!($struct_name);
+
+        impl<A: AvxNum, T: FftNum> $struct_name<A, T> {
+            #[target_feature(enable = "avx")]
+            unsafe fn new_with_avx(
+                inner_fft: Arc<dyn Fft<T>>,
+                twiddles: Option<TwiddleView<T>>,
+            ) -> Self {
+                let mut twiddles_butterfly = [AvxVector::zero(); $row_count / 2];
+                for (i, twiddle) in twiddles_butterfly.iter_mut().enumerate() {
+                    *twiddle =
+                        AvxVector::broadcast_twiddle(i + 1, $row_count, inner_fft.fft_direction());
+                }
+                Self {
+                    twiddles_butterfly,
+                    common_data: mixedradix_gen_data!($row_count, inner_fft, twiddles),
+                    first_row_scale: None,
+                    _phantom: std::marker::PhantomData,
+                }
+            }
+            mixedradix_column_butterflies!(
+                $row_count,
+                |columns, this: &Self| AvxVector::$butterfly_fn(columns, this.twiddles_butterfly),
+                |columns, this: &Self| {
+                    let mut twiddles_lo = [AvxVector::zero(); $row_count / 2];
+                    for (twiddle_lo, twiddle) in
+                        twiddles_lo.iter_mut().zip(this.twiddles_butterfly.iter())
+                    {
+                        *twiddle_lo = twiddle.lo();
+                    }
+                    AvxVector::$butterfly_fn(columns, twiddles_lo)
+                }
+            );
+            mixedradix_transpose_unpacked!($row_count);
+            boilerplate_mixedradix!();
+        }
+    };
+}
+mixedradix_prime!(MixedRadix13xnAvx, 13, column_butterfly13);
+mixedradix_prime!(MixedRadix17xnAvx, 17, column_butterfly17);
+mixedradix_prime!(MixedRadix19xnAvx, 19, column_butterfly19);
+mixedradix_prime!(MixedRadix23xnAvx, 23, column_butterfly23);
+mixedradix_prime!(MixedRadix29xnAvx, 29, column_butterfly29);
+mixedradix_prime!(MixedRadix31xnAvx, 31, column_butterfly31);
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -916,6 +1408,23 @@ mod unit_tests {
         )
     }
 
+    #[test]
+    fn test_mixedradix_avx_strided_twiddles() {
+        // A step should be able to read its twiddle factors from every other row of a table with twice as many rows
+        for inner_fft_len in 1..12 {
+            let len = inner_fft_len * 4;
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let table = MixedRadix::<f32>::compute_twiddles(8, inner_fft_len, direction);
+                let twiddles = TwiddleView::strided(table, 8, inner_fft_len, 4, inner_fft_len);
+
+                let inner_fft = Arc::new(Dft::new(inner_fft_len, direction)) as Arc<dyn Fft<f32>>;
+                let fft = MixedRadix4xnAvx::<f32, f32>::new_with_twiddles(inner_fft, twiddles)
+                    .expect("Can't run test because this machine doesn't have the required instruction sets");
+                check_fft_algorithm(&fft, len, direction);
+            }
+        }
+    }
+
     test_avx_mixed_radix!(
         test_mixedradix_2xn_avx_f32,
         test_mixedradix_2xn_avx_f64,
@@ -982,4 +1491,122 @@ mod unit_tests {
         MixedRadix16xnAvx,
         16
     );
+    test_avx_mixed_radix!(
+        test_mixedradix_13xn_avx_f32,
+        test_mixedradix_13xn_avx_f64,
+        MixedRadix13xnAvx,
+        13
+    );
+    test_avx_mixed_radix!(
+        test_mixedradix_17xn_avx_f32,
+        test_mixedradix_17xn_avx_f64,
+        MixedRadix17xnAvx,
+        17
+    );
+    test_avx_mixed_radix!(
+        test_mixedradix_19xn_avx_f32,
+        test_mixedradix_19xn_avx_f64,
+        MixedRadix19xnAvx,
+        19
+    );
+    test_avx_mixed_radix!(
+        test_mixedradix_23xn_avx_f32,
+        test_mixedradix_23xn_avx_f64,
+        MixedRadix23xnAvx,
+        23
+    );
+    test_avx_mixed_radix!(
+        test_mixedradix_29xn_avx_f32,
+        test_mixedradix_29xn_avx_f64,
+        MixedRadix29xnAvx,
+        29
+    );
+    test_avx_mixed_radix!(
+        test_mixedradix_31xn_avx_f32,
+        test_mixedradix_31xn_avx_f64,
+        MixedRadix31xnAvx,
+        31
+    );
+
+    // Checks that the fused f16 passes of `fft` match the f32 FFT of the same data, rounded to f16
+    #[cfg(feature = "half")]
+    fn check_fused_f16(fft: &dyn Fft<f32>) {
+        use crate::test_utils::random_signal;
+        use num_traits::Zero;
+
+        let len = fft.len();
+        let fused_fft = fft
+            .fused_f16()
+            .expect("f32 mixed radix steps should have fused f16 passes");
+
+        let input: Vec<Complex<f16>> = random_signal::<f32>(len)
+            .iter()
+            .map(|element| Complex::new(f16::from_f32(element.re), f16::from_f32(element.im)))
+            .collect();
+        let mut expected: Vec<Complex<f32>> = input
+            .iter()
+            .map(|element| Complex::new(element.re.to_f32(), element.im.to_f32()))
+            .collect();
+        fft.process(&mut expected);
+
+        let check = |output: &[Complex<f16>], method: &str| {
+            for (actual, expected) in output.iter().zip(expected.iter()) {
+                let actual = Complex::new(actual.re.to_f32(), actual.im.to_f32());
+                assert!(
+                    (actual - expected).norm() <= 2e-3 * expected.norm().max(1.0),
+                    "{} failed, length = {}, expected = {}, actual = {}",
+                    method,
+                    len,
+                    expected,
+                    actual
+                );
+            }
+        };
+
+        let mut scratch = vec![Complex::zero(); fused_fft.get_half_scratch_len()];
+        let mut buffer = input.clone();
+        let mut output = vec![Complex::new(f16::from_f32(0.0), f16::from_f32(0.0)); len];
+        // Safety: the fused passes need F16C, which every machine with AVX and FMA has
+        unsafe {
+            fused_fft.process_half_inplace(&mut buffer, &mut scratch);
+            fused_fft.process_half_outofplace(&input, &mut output, &mut scratch);
+        }
+        check(&buffer, "process_half_inplace()");
+        check(&output, "process_half_outofplace()");
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_mixedradix_avx_fused_f16() {
+        assert!(crate::avx::avx_half_precision::has_f16c());
+        for inner_fft_len in 1..32 {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let inner_fft = Arc::new(Dft::new(inner_fft_len, direction)) as Arc<dyn Fft<f32>>;
+                macro_rules! check_structs {
+                    ($($struct_name:ident),*) => {$(
+                        check_fused_f16(&$struct_name::<f32, f32>::new(Arc::clone(&inner_fft)).unwrap());
+                    )*}
+                }
+                check_structs!(
+                    MixedRadix2xnAvx,
+                    MixedRadix3xnAvx,
+                    MixedRadix4xnAvx,
+                    MixedRadix5xnAvx,
+                    MixedRadix6xnAvx,
+                    MixedRadix7xnAvx,
+                    MixedRadix8xnAvx,
+                    MixedRadix9xnAvx,
+                    MixedRadix11xnAvx,
+                    MixedRadix12xnAvx,
+                    MixedRadix13xnAvx,
+                    MixedRadix16xnAvx,
+                    MixedRadix17xnAvx,
+                    MixedRadix19xnAvx,
+                    MixedRadix23xnAvx,
+                    MixedRadix29xnAvx,
+                    MixedRadix31xnAvx
+                );
+            }
+        }
+    }
 }
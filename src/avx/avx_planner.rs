@@ -1,13 +1,15 @@
 use std::sync::Arc;
 use std::{any::TypeId, cmp::min};
 
+use num_integer::div_ceil;
 use primal_check::miller_rabin;
 
+use crate::algorithm::butterflies::*;
 use crate::algorithm::*;
 use crate::common::FftNum;
-use crate::math_utils::PartialFactors;
-use crate::Fft;
-use crate::{algorithm::butterflies::*, fft_cache::FftCache};
+use crate::fft_cache::{exceeds, ChirpSpectrumCache, FftCache, TwiddleStats, TwiddleStore};
+use crate::math_utils::{PartialFactors, PrimeFactors, SmoothCostModel};
+use crate::{Fft, FftNormalization};
 
 use super::*;
 
@@ -15,7 +17,7 @@ fn wrap_fft<T: FftNum>(butterfly: impl Fft<T> + 'static) -> Arc<dyn Fft<T>> {
     Arc::new(butterfly) as Arc<dyn Fft<T>>
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 enum MixedRadixBase {
     // The base will be a butterfly algorithm
     ButterflyBase(usize),
@@ -27,6 +29,10 @@ enum MixedRadixBase {
     // First usize is the base length, second usize is the inner FFT length
     BluesteinsBase(usize, usize),
 
+    // The base will be an instance of the Good-Thomas Algorithm. That will require its own plans for the two internal FFTs, which we'll handle separately.
+    // The two usizes are the coprime inner FFT lengths
+    GoodThomasBase(usize, usize),
+
     // The "base" is a FFT instance we already have cached
     CacheBase(usize),
 }
@@ -36,6 +42,7 @@ impl MixedRadixBase {
             Self::ButterflyBase(len) => *len,
             Self::RadersBase(len) => *len,
             Self::BluesteinsBase(len, _) => *len,
+            Self::GoodThomasBase(width, height) => width * height,
             Self::CacheBase(len) => *len,
         }
     }
@@ -169,7 +176,11 @@ impl<T: FftNum> FftPlannerAvx<T> {
     ///
     /// If this is called multiple times, the planner will attempt to re-use internal data between calls, reducing memory usage and FFT initialization time.
     pub fn plan_fft(&mut self, len: usize, direction: FftDirection) -> Arc<dyn Fft<T>> {
-        self.internal_planner.plan_and_construct_fft(len, direction)
+        let fft = self.internal_planner.plan_and_construct_fft(len, direction);
+        if self.internal_planner.cache().is_bounded() {
+            self.release_unused_data();
+        }
+        fft
     }
     /// Returns a `Fft` instance which uses AVX instructions to compute forward FFTs of size `len`.
     ///
@@ -183,6 +194,161 @@ impl<T: FftNum> FftPlannerAvx<T> {
     pub fn plan_fft_inverse(&mut self, len: usize) -> Arc<dyn Fft<T>> {
         self.plan_fft(len, FftDirection::Inverse)
     }
+    /// Returns a pruned `Fft` instance which computes FFTs of size `len`, where only some of the inputs are nonzero, and only some of the outputs are needed.
+    ///
+    /// The returned instance expects every input element at index `nonzero_inputs` or above to be zero, and may skip reading them. Only the first
+    /// `needed_outputs` output elements are guaranteed to be correct, and the remaining output elements will contain garbage.
+    ///
+    /// If enough sub-FFTs can be skipped, the returned instance is a pruned `MixedRadix` instance whose inner FFTs are planned with AVX instructions.
+    /// Otherwise, it's a regular AVX instance of size `len`.
+    pub fn plan_fft_pruned(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Arc<dyn Fft<T>> {
+        let nonzero_inputs = min(nonzero_inputs, len);
+        let needed_outputs = min(needed_outputs, len);
+        if (nonzero_inputs == len && needed_outputs == len) || len < 2 || miller_rabin(len as u64) {
+            return self.plan_fft(len, direction);
+        }
+
+        let (width_factors, height_factors) = PrimeFactors::compute(len).partition_factors();
+        let width = width_factors.get_product();
+        let height = height_factors.get_product();
+
+        // Splitting the FFT into scalar mixed radix steps is only worth it if it lets us skip a significant fraction of the inner FFTs
+        if nonzero_inputs * 2 > width && needed_outputs * 2 > height {
+            return self.plan_fft(len, direction);
+        }
+
+        let width_fft = self.plan_fft_pruned(
+            width,
+            direction,
+            nonzero_inputs,
+            div_ceil(needed_outputs, height),
+        );
+        let height_fft = self.plan_fft_pruned(
+            height,
+            direction,
+            div_ceil(nonzero_inputs, width),
+            needed_outputs,
+        );
+        let twiddles = self
+            .internal_planner
+            .twiddle_store_mut()
+            .mixed_radix_twiddles(width, height, direction);
+        let mut fft = MixedRadix::new_with_twiddles(width_fft, height_fft, twiddles);
+        fft.prune(nonzero_inputs, needed_outputs);
+
+        if self.internal_planner.cache().is_bounded() {
+            self.release_unused_data();
+        }
+        Arc::new(fft)
+    }
+
+    /// Returns a `Fft` instance which uses AVX instructions to compute FFTs of size `len`, and scales its output according to `normalization`.
+    ///
+    /// The output is multiplied by `normalization.scale_factor(len, direction)`. Wherever possible, the scale is folded into the twiddle factors
+    /// of the outermost step of the FFT, so normalized FFTs are usually just as fast as unnormalized ones.
+    ///
+    /// If the scale factor is 1, this is equivalent to `plan_fft`. Otherwise, the outermost step of the FFT is not cached, but its inner FFTs are.
+    pub fn plan_fft_normalized(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Arc<dyn Fft<T>> {
+        let scale = normalization.scale_factor(len, direction);
+        if scale == 1.0 {
+            return self.plan_fft(len, direction);
+        }
+        let fft = self
+            .internal_planner
+            .plan_and_construct_scaled_fft(len, direction, scale);
+        if self.internal_planner.cache().is_bounded() {
+            self.release_unused_data();
+        }
+        fft
+    }
+
+    /// Sets the maximum number of FFT instances this planner keeps in its cache.
+    ///
+    /// The planner caches every FFT instance it creates, including the inner FFTs of larger FFTs, and each direction counts separately.
+    /// When the cache holds more instances than this, the least recently used ones are evicted. `None` removes the limit, which is the default.
+    ///
+    /// Evicted instances keep working if they were already returned by the planner, or if another FFT instance uses them. Once a capacity is set,
+    /// the planner also releases the precomputed data it shares between FFT instances as soon as no FFT instance uses it anymore.
+    pub fn set_max_cached_ffts(&mut self, count: Option<usize>) {
+        self.internal_planner.cache_mut().set_max_entries(count);
+        self.release_unused_data();
+    }
+
+    /// Sets the maximum memory in bytes that this planner's caches may use.
+    ///
+    /// The memory of each cached FFT instance is estimated as `len * size_of::<Complex<T>>()`, which is roughly the size of the data most FFT algorithms precompute.
+    /// The twiddle factor tables and chirp spectra that the planner shares between FFT instances count with their actual size, for as long as any FFT instance uses them.
+    /// When the caches hold more memory than this, the least recently used instances are evicted. `None` removes the limit, which is the default.
+    pub fn set_max_cache_memory(&mut self, bytes: Option<usize>) {
+        self.internal_planner.cache_mut().set_max_memory(bytes);
+        self.release_unused_data();
+    }
+
+    /// Removes every FFT instance and all shared precomputed data from this planner's caches.
+    ///
+    /// FFT instances that were already returned by the planner keep working. The twiddle statistics are not reset.
+    pub fn clear(&mut self) {
+        self.internal_planner.cache_mut().clear();
+        self.internal_planner.chirp_cache_mut().clear();
+        self.internal_planner.twiddle_store_mut().clear();
+    }
+
+    /// Removes the FFT instances of size `len` from this planner's cache, in both directions.
+    ///
+    /// Their inner FFTs stay in the cache. FFT instances that were already returned by the planner keep working.
+    pub fn evict(&mut self, len: usize) {
+        self.internal_planner.cache_mut().evict(len);
+        self.release_unused_data();
+    }
+
+    /// Returns the number of FFT instances in this planner's cache. Each direction counts separately.
+    pub fn cached_fft_count(&self) -> usize {
+        self.internal_planner.cache().len()
+    }
+
+    /// Returns the estimated memory in bytes used by this planner's caches, as described in [`set_max_cache_memory`](Self::set_max_cache_memory).
+    pub fn cache_memory(&self) -> usize {
+        self.internal_planner.cache().memory()
+            + self.internal_planner.chirp_cache().memory()
+            + self.internal_planner.twiddle_store().memory()
+    }
+
+    // Drops the shared precomputed data that no FFT instance uses anymore, then evicts the least recently used FFT instances
+    // until the instances and the shared data they use fit in the memory limit
+    fn release_unused_data(&mut self) {
+        self.internal_planner.chirp_cache_mut().release_unused();
+        self.internal_planner.twiddle_store_mut().release_unused();
+        while exceeds(
+            self.cache_memory(),
+            self.internal_planner.cache().max_memory(),
+        ) && self
+            .internal_planner
+            .cache_mut()
+            .evict_least_recently_used()
+        {
+            self.internal_planner.chirp_cache_mut().release_unused();
+            self.internal_planner.twiddle_store_mut().release_unused();
+        }
+    }
+
+    /// Returns statistics about the twiddle factor tables this planner has shared between the FFT instances it created.
+    ///
+    /// The AVX mixed radix steps and the scalar `MixedRadix` steps of pruned FFTs share their twiddle factors. A step can also read its twiddle factors
+    /// from the table of a larger step, if its radix divides the larger step's radix and both steps have the same inner FFT length.
+    pub fn twiddle_stats(&self) -> TwiddleStats {
+        self.internal_planner.twiddle_store().stats()
+    }
 
     /// Returns a FFT plan without constructing it
     #[allow(unused)]
@@ -193,11 +359,25 @@ impl<T: FftNum> FftPlannerAvx<T> {
 
 trait AvxPlannerInternalAPI<T: FftNum>: Send {
     fn plan_and_construct_fft(&mut self, len: usize, direction: FftDirection) -> Arc<dyn Fft<T>>;
+    fn plan_and_construct_scaled_fft(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        scale: f64,
+    ) -> Arc<dyn Fft<T>>;
     fn debug_plan_fft(&self, len: usize, direction: FftDirection) -> MixedRadixPlan;
+    fn cache(&self) -> &FftCache<T>;
+    fn cache_mut(&mut self) -> &mut FftCache<T>;
+    fn chirp_cache(&self) -> &ChirpSpectrumCache<T>;
+    fn chirp_cache_mut(&mut self) -> &mut ChirpSpectrumCache<T>;
+    fn twiddle_store(&self) -> &TwiddleStore<T>;
+    fn twiddle_store_mut(&mut self) -> &mut TwiddleStore<T>;
 }
 
 struct AvxPlannerInternal<A: AvxNum, T: FftNum> {
     cache: FftCache<T>,
+    chirp_cache: ChirpSpectrumCache<T>,
+    twiddle_store: TwiddleStore<T>,
     _phantom: std::marker::PhantomData<A>,
 }
 
@@ -214,9 +394,44 @@ impl<T: FftNum> AvxPlannerInternalAPI<T> for AvxPlannerInternal<f32, T> {
             Self::plan_and_construct_fft,
         )
     }
+    fn plan_and_construct_scaled_fft(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        scale: f64,
+    ) -> Arc<dyn Fft<T>> {
+        // The outermost step of a scaled FFT can't come from the cache, so skip the cache check when planning
+        let plan = self.plan_uncached_fft(len, Self::plan_mixed_radix_base);
+
+        self.construct_scaled_plan(
+            plan,
+            direction,
+            scale,
+            Self::construct_butterfly,
+            Self::plan_and_construct_fft,
+        )
+    }
     fn debug_plan_fft(&self, len: usize, direction: FftDirection) -> MixedRadixPlan {
         self.plan_fft(len, direction, Self::plan_mixed_radix_base)
     }
+    fn cache(&self) -> &FftCache<T> {
+        &self.cache
+    }
+    fn cache_mut(&mut self) -> &mut FftCache<T> {
+        &mut self.cache
+    }
+    fn chirp_cache(&self) -> &ChirpSpectrumCache<T> {
+        &self.chirp_cache
+    }
+    fn chirp_cache_mut(&mut self) -> &mut ChirpSpectrumCache<T> {
+        &mut self.chirp_cache
+    }
+    fn twiddle_store(&self) -> &TwiddleStore<T> {
+        &self.twiddle_store
+    }
+    fn twiddle_store_mut(&mut self) -> &mut TwiddleStore<T> {
+        &mut self.twiddle_store
+    }
 }
 impl<T: FftNum> AvxPlannerInternalAPI<T> for AvxPlannerInternal<f64, T> {
     fn plan_and_construct_fft(&mut self, len: usize, direction: FftDirection) -> Arc<dyn Fft<T>> {
@@ -231,9 +446,44 @@ impl<T: FftNum> AvxPlannerInternalAPI<T> for AvxPlannerInternal<f64, T> {
             Self::plan_and_construct_fft,
         )
     }
+    fn plan_and_construct_scaled_fft(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        scale: f64,
+    ) -> Arc<dyn Fft<T>> {
+        // The outermost step of a scaled FFT can't come from the cache, so skip the cache check when planning
+        let plan = self.plan_uncached_fft(len, Self::plan_mixed_radix_base);
+
+        self.construct_scaled_plan(
+            plan,
+            direction,
+            scale,
+            Self::construct_butterfly,
+            Self::plan_and_construct_fft,
+        )
+    }
     fn debug_plan_fft(&self, len: usize, direction: FftDirection) -> MixedRadixPlan {
         self.plan_fft(len, direction, Self::plan_mixed_radix_base)
     }
+    fn cache(&self) -> &FftCache<T> {
+        &self.cache
+    }
+    fn cache_mut(&mut self) -> &mut FftCache<T> {
+        &mut self.cache
+    }
+    fn chirp_cache(&self) -> &ChirpSpectrumCache<T> {
+        &self.chirp_cache
+    }
+    fn chirp_cache_mut(&mut self) -> &mut ChirpSpectrumCache<T> {
+        &mut self.chirp_cache
+    }
+    fn twiddle_store(&self) -> &TwiddleStore<T> {
+        &self.twiddle_store
+    }
+    fn twiddle_store_mut(&mut self) -> &mut TwiddleStore<T> {
+        &mut self.twiddle_store
+    }
 }
 
 //-------------------------------------------------------------------
@@ -250,6 +500,8 @@ impl<T: FftNum> AvxPlannerInternal<f32, T> {
 
         Self {
             cache: FftCache::new(),
+            chirp_cache: ChirpSpectrumCache::new(),
+            twiddle_store: TwiddleStore::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -264,6 +516,24 @@ impl<T: FftNum> AvxPlannerInternal<f32, T> {
                 return MixedRadixPlan::butterfly(other_factors, vec![]);
             }
 
+            // If the "other factors" are all primes that we have butterflies for, use one of them as the base and chain the rest as radix steps
+            if let Some(prime_chain) = Self::plan_prime_butterfly_chain(other_factors) {
+                return prime_chain;
+            }
+
+            // f32 bluestein's packs twice as many elements per vector into its inner FFT as f64, so good-thomas only pays off in narrower cases than for f64.
+            // Benchmarking showed good-thomas 5-50% faster when one of its halves can be computed entirely with butterflies and the "other factors" are at least 4096,
+            // and usually slower otherwise. GoodThomasAvx2 needs AVX2, and without it the scalar good-thomas fallback is slower than bluestein's
+            if other_factors >= 4096 && is_x86_feature_detected!("avx2") {
+                if let Some(good_thomas_base) = Self::plan_good_thomas_base(other_factors) {
+                    if let MixedRadixBase::GoodThomasBase(width, height) = good_thomas_base.base {
+                        if self.is_butterfly_chain(width) || self.is_butterfly_chain(height) {
+                            return good_thomas_base;
+                        }
+                    }
+                }
+            }
+
             // We can only use rader's if `other_factors` is prime
             if miller_rabin(other_factors as u64) {
                 // len is prime, so we can use Rader's Algorithm as a base. Whether or not that's a good idea is a different story
@@ -286,15 +556,7 @@ impl<T: FftNum> AvxPlannerInternal<f32, T> {
             }
 
             // At this point, we know we're using bluestein's algorithm for the base. Next step is to plan the inner size we'll use for bluestein's algorithm.
-            let inner_bluesteins_len =
-                self.plan_bluesteins(other_factors, |(_len, factor2, factor3)| {
-                    if *factor2 > 16 && *factor3 < 3 {
-                        // surprisingly, pure powers of 2 have a pretty steep dropoff in speed after 65536.
-                        // the algorithm is designed to generate candidadtes larger than baseline_candidate, so if we hit a large power of 2, there should be more after it that we can skip to
-                        return false;
-                    }
-                    true
-                });
+            let inner_bluesteins_len = SmoothCostModel::AVX_F32.choose_len(other_factors * 2 - 1);
             return MixedRadixPlan::new(
                 MixedRadixBase::BluesteinsBase(other_factors, inner_bluesteins_len),
                 vec![],
@@ -410,6 +672,11 @@ impl<T: FftNum> AvxPlannerInternal<f32, T> {
         .contains(&len)
     }
 
+    // Returns true if an FFT of size `len` can be computed with a butterfly, or with a chain of prime butterflies
+    fn is_butterfly_chain(&self, len: usize) -> bool {
+        self.is_butterfly(len) || Self::plan_prime_butterfly_chain(len).is_some()
+    }
+
     fn construct_butterfly(&self, len: usize, direction: FftDirection) -> Arc<dyn Fft<T>> {
         match len {
             0 | 1 => wrap_fft(Dft::new(len, direction)),
@@ -423,15 +690,15 @@ impl<T: FftNum> AvxPlannerInternal<f32, T> {
             9 => wrap_fft(Butterfly9Avx::new(direction).unwrap()),
             11 => wrap_fft(Butterfly11Avx::new(direction).unwrap()),
             12 => wrap_fft(Butterfly12Avx::new(direction).unwrap()),
-            13 => wrap_fft(Butterfly13::new(direction)),
+            13 => wrap_fft(Butterfly13Avx::new(direction).unwrap()),
             16 => wrap_fft(Butterfly16Avx::new(direction).unwrap()),
-            17 => wrap_fft(Butterfly17::new(direction)),
-            19 => wrap_fft(Butterfly19::new(direction)),
-            23 => wrap_fft(Butterfly23::new(direction)),
+            17 => wrap_fft(Butterfly17Avx::new(direction).unwrap()),
+            19 => wrap_fft(Butterfly19Avx::new(direction).unwrap()),
+            23 => wrap_fft(Butterfly23Avx::new(direction).unwrap()),
             24 => wrap_fft(Butterfly24Avx::new(direction).unwrap()),
             27 => wrap_fft(Butterfly27Avx::new(direction).unwrap()),
-            29 => wrap_fft(Butterfly29::new(direction)),
-            31 => wrap_fft(Butterfly31::new(direction)),
+            29 => wrap_fft(Butterfly29Avx::new(direction).unwrap()),
+            31 => wrap_fft(Butterfly31Avx::new(direction).unwrap()),
             32 => wrap_fft(Butterfly32Avx::new(direction).unwrap()),
             36 => wrap_fft(Butterfly36Avx::new(direction).unwrap()),
             48 => wrap_fft(Butterfly48Avx::new(direction).unwrap()),
@@ -460,6 +727,8 @@ impl<T: FftNum> AvxPlannerInternal<f64, T> {
 
         Self {
             cache: FftCache::new(),
+            chirp_cache: ChirpSpectrumCache::new(),
+            twiddle_store: TwiddleStore::new(),
             _phantom: std::marker::PhantomData,
         }
     }
@@ -474,6 +743,19 @@ impl<T: FftNum> AvxPlannerInternal<f64, T> {
                 return MixedRadixPlan::butterfly(other_factors, vec![]);
             }
 
+            // If the "other factors" are all primes that we have butterflies for, use one of them as the base and chain the rest as radix steps
+            if let Some(prime_chain) = Self::plan_prime_butterfly_chain(other_factors) {
+                return prime_chain;
+            }
+
+            // If the "other factors" aren't a prime power, split them into coprime halves and use good-thomas, so that we don't need bluestein's for the whole thing.
+            // Benchmarking showed this to be 10-70% faster than bluestein's for f64. GoodThomasAvx2 needs AVX2, and without it the scalar good-thomas fallback is slower than bluestein's
+            if is_x86_feature_detected!("avx2") {
+                if let Some(good_thomas_base) = Self::plan_good_thomas_base(other_factors) {
+                    return good_thomas_base;
+                }
+            }
+
             // We can only use rader's if `other_factors` is prime
             if miller_rabin(other_factors as u64) {
                 // len is prime, so we can use Rader's Algorithm as a base. Whether or not that's a good idea is a different story
@@ -496,16 +778,7 @@ impl<T: FftNum> AvxPlannerInternal<f64, T> {
             }
 
             // At this point, we know we're using bluestein's algorithm for the base. Next step is to plan the inner size we'll use for bluestein's algorithm.
-            let inner_bluesteins_len =
-                self.plan_bluesteins(other_factors, |(_len, factor2, factor3)| {
-                    if *factor3 < 1 && *factor2 > 13 {
-                        return false;
-                    }
-                    if *factor3 < 4 && *factor2 > 14 {
-                        return false;
-                    }
-                    true
-                });
+            let inner_bluesteins_len = SmoothCostModel::AVX_F64.choose_len(other_factors * 2 - 1);
             return MixedRadixPlan::new(
                 MixedRadixBase::BluesteinsBase(other_factors, inner_bluesteins_len),
                 vec![],
@@ -637,16 +910,16 @@ impl<T: FftNum> AvxPlannerInternal<f64, T> {
             9 => wrap_fft(Butterfly9Avx64::new(direction).unwrap()),
             11 => wrap_fft(Butterfly11Avx64::new(direction).unwrap()),
             12 => wrap_fft(Butterfly12Avx64::new(direction).unwrap()),
-            13 => wrap_fft(Butterfly13::new(direction)),
+            13 => wrap_fft(Butterfly13Avx64::new(direction).unwrap()),
             16 => wrap_fft(Butterfly16Avx64::new(direction).unwrap()),
-            17 => wrap_fft(Butterfly17::new(direction)),
+            17 => wrap_fft(Butterfly17Avx64::new(direction).unwrap()),
             18 => wrap_fft(Butterfly18Avx64::new(direction).unwrap()),
-            19 => wrap_fft(Butterfly19::new(direction)),
-            23 => wrap_fft(Butterfly23::new(direction)),
+            19 => wrap_fft(Butterfly19Avx64::new(direction).unwrap()),
+            23 => wrap_fft(Butterfly23Avx64::new(direction).unwrap()),
             24 => wrap_fft(Butterfly24Avx64::new(direction).unwrap()),
             27 => wrap_fft(Butterfly27Avx64::new(direction).unwrap()),
-            29 => wrap_fft(Butterfly29::new(direction)),
-            31 => wrap_fft(Butterfly31::new(direction)),
+            29 => wrap_fft(Butterfly29Avx64::new(direction).unwrap()),
+            31 => wrap_fft(Butterfly31Avx64::new(direction).unwrap()),
             32 => wrap_fft(Butterfly32Avx64::new(direction).unwrap()),
             36 => wrap_fft(Butterfly36Avx64::new(direction).unwrap()),
             64 => wrap_fft(Butterfly64Avx64::new(direction).unwrap()),
@@ -674,18 +947,31 @@ impl<A: AvxNum, T: FftNum> AvxPlannerInternal<A, T> {
             return MixedRadixPlan::cached(len);
         }
 
+        // This length is not cached, so we have to come up with a new plan.
+        let uncached_plan = self.plan_uncached_fft(len, base_fn);
+
+        // Last step: We have a full FFT plan, but some of the steps of that plan may have been cached. If they have, use the largest cached step as the base.
+        self.replan_with_cache(uncached_plan, direction)
+    }
+
+    // Given a length, return a plan for how this FFT should be computed, without taking the cache into account
+    fn plan_uncached_fft(
+        &self,
+        len: usize,
+        base_fn: impl FnOnce(&Self, usize, &PartialFactors) -> MixedRadixPlan,
+    ) -> MixedRadixPlan {
         // We have butterflies for everything below 10, so if it's below 10, just skip the factorization etc
         // Notably, this step is *required* if the len is 0, since we can't compute a prime factorization for zero
         if len < 10 {
             return MixedRadixPlan::butterfly(len, Vec::new());
         }
 
-        // This length is not cached, so we have to come up with a new plan. The first step is to find a suitable base.
+        // The first step is to find a suitable base.
         let factors = PartialFactors::compute(len);
         let base = base_fn(self, len, &factors);
 
         // it's possible that the base planner plans out the whole FFT. it's guaranteed if `len` is a prime number, or if it's a butterfly, for example
-        let uncached_plan = if base.len == len {
+        if base.len == len {
             base
         } else {
             // We have some mixed radix steps to compute! Compute the factors that need to computed by mixed radix steps,
@@ -698,10 +984,7 @@ impl<A: AvxNum, T: FftNum> AvxPlannerInternal<A, T> {
                     )
                 });
             self.plan_mixed_radix(radix_factors, base)
-        };
-
-        // Last step: We have a full FFT plan, but some of the steps of that plan may have been cached. If they have, use the largest cached step as the base.
-        self.replan_with_cache(uncached_plan, direction)
+        }
     }
 
     // Takes a plan and an algorithm cache, and replaces steps of the plan with cached steps, if possible
@@ -872,7 +1155,7 @@ impl<A: AvxNum, T: FftNum> AvxPlannerInternal<A, T> {
         plan: MixedRadixPlan,
         direction: FftDirection,
         construct_butterfly_fn: impl FnOnce(&Self, usize, FftDirection) -> Arc<dyn Fft<T>>,
-        inner_fft_fn: impl FnOnce(&mut Self, usize, FftDirection) -> Arc<dyn Fft<T>>,
+        mut inner_fft_fn: impl FnMut(&mut Self, usize, FftDirection) -> Arc<dyn Fft<T>>,
     ) -> Arc<dyn Fft<T>> {
         let mut fft = match plan.base {
             MixedRadixBase::CacheBase(len) => self.cache.get(len, direction).unwrap(),
@@ -906,30 +1189,63 @@ impl<A: AvxNum, T: FftNum> AvxPlannerInternal<A, T> {
                 let inner_fft = inner_fft_fn(self, inner_fft_len, direction);
 
                 // try to construct our AVX2 rader's algorithm. If that fails (probably because the machine we're running on doesn't have AVX2), fall back to scalar
-                let bluesteins_instance =
-                    wrap_fft(BluesteinsAvx::<A, T>::new(len, inner_fft).unwrap());
+                let chirp_spectrum = self.chirp_cache.get_or_compute(len, &*inner_fft);
+                let bluesteins_instance = wrap_fft(
+                    BluesteinsAvx::<A, T>::new_with_chirp_spectrum(len, inner_fft, chirp_spectrum)
+                        .unwrap(),
+                );
 
                 // Cache this FFT instance for future calls to `plan_fft`
                 self.cache.insert(&bluesteins_instance);
 
                 bluesteins_instance
             }
+            MixedRadixBase::GoodThomasBase(width, height) => {
+                // Good-Thomas has two inner FFTs, and since their sizes are coprime, they're both planned from scratch
+                let width_fft = inner_fft_fn(self, width, direction);
+                let height_fft = inner_fft_fn(self, height, direction);
+
+                // try to construct our AVX2 good-thomas algorithm. If that fails (probably because the machine we're running on doesn't have AVX2), fall back to scalar
+                let good_thomas_instance = if let Ok(good_thomas_avx) =
+                    GoodThomasAvx2::<A, T>::new(Arc::clone(&width_fft), Arc::clone(&height_fft))
+                {
+                    wrap_fft(good_thomas_avx)
+                } else {
+                    wrap_fft(GoodThomasAlgorithm::new(width_fft, height_fft))
+                };
+
+                // Cache this FFT instance for future calls to `plan_fft`
+                self.cache.insert(&good_thomas_instance);
+
+                good_thomas_instance
+            }
         };
 
         // We have constructed our base. Now, construct the radix chain.
         for radix in plan.radixes {
+            // Each step reads its twiddle factors from the planner's shared tables, so steps with the same shape, or whose radix divides the radix of a
+            // larger step with the same inner FFT length, don't compute them again
+            let table =
+                self.twiddle_store
+                    .avx_mixed_radix_twiddles(radix as usize, fft.len(), direction);
             fft = match radix {
-                2 => wrap_fft(MixedRadix2xnAvx::<A, T>::new(fft).unwrap()),
-                3 => wrap_fft(MixedRadix3xnAvx::<A, T>::new(fft).unwrap()),
-                4 => wrap_fft(MixedRadix4xnAvx::<A, T>::new(fft).unwrap()),
-                5 => wrap_fft(MixedRadix5xnAvx::<A, T>::new(fft).unwrap()),
-                6 => wrap_fft(MixedRadix6xnAvx::<A, T>::new(fft).unwrap()),
-                7 => wrap_fft(MixedRadix7xnAvx::<A, T>::new(fft).unwrap()),
-                8 => wrap_fft(MixedRadix8xnAvx::<A, T>::new(fft).unwrap()),
-                9 => wrap_fft(MixedRadix9xnAvx::<A, T>::new(fft).unwrap()),
-                11 => wrap_fft(MixedRadix11xnAvx::<A, T>::new(fft).unwrap()),
-                12 => wrap_fft(MixedRadix12xnAvx::<A, T>::new(fft).unwrap()),
-                16 => wrap_fft(MixedRadix16xnAvx::<A, T>::new(fft).unwrap()),
+                2 => wrap_fft(MixedRadix2xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                3 => wrap_fft(MixedRadix3xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                4 => wrap_fft(MixedRadix4xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                5 => wrap_fft(MixedRadix5xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                6 => wrap_fft(MixedRadix6xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                7 => wrap_fft(MixedRadix7xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                8 => wrap_fft(MixedRadix8xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                9 => wrap_fft(MixedRadix9xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                11 => wrap_fft(MixedRadix11xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                12 => wrap_fft(MixedRadix12xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                16 => wrap_fft(MixedRadix16xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                13 => wrap_fft(MixedRadix13xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                17 => wrap_fft(MixedRadix17xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                19 => wrap_fft(MixedRadix19xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                23 => wrap_fft(MixedRadix23xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                29 => wrap_fft(MixedRadix29xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
+                31 => wrap_fft(MixedRadix31xnAvx::<A, T>::new_with_twiddles(fft, table).unwrap()),
                 _ => unreachable!(),
             };
 
@@ -940,63 +1256,101 @@ impl<A: AvxNum, T: FftNum> AvxPlannerInternal<A, T> {
         fft
     }
 
-    // Plan and return the inner size to be used with Bluestein's Algorithm
-    // Calls `filter_fn` on result candidates, giving the caller the opportunity to reject certain sizes
-    fn plan_bluesteins(
-        &self,
-        len: usize,
-        filter_fn: impl FnMut(&&(usize, u32, u32)) -> bool,
-    ) -> usize {
-        assert!(len > 1); // Internal consistency check: The logic in this method doesn't work for a length of 1
-
-        // Bluestein's computes a FFT of size `len` by reorganizing it as a FFT of ANY size greater than or equal to len * 2 - 1
-        // an obvious choice is the next power of two larger than  len * 2 - 1, but if we can find a smaller FFT that will go faster, we can save a lot of time.
-        // We can very efficiently compute almost any 2^n * 3^m, so we're going to search for all numbers of the form 2^n * 3^m that lie between len * 2 - 1 and the next power of two.
-        let min_len = len * 2 - 1;
-        let baseline_candidate = min_len.checked_next_power_of_two().unwrap();
-
-        // our algorithm here is to start with our next power of 2, and repeatedly divide by 2 and multiply by 3, trying to keep our value in range
-        let mut bluesteins_candidates = Vec::new();
-        let mut candidate = baseline_candidate;
-        let mut factor2 = candidate.trailing_zeros();
-        let mut factor3 = 0;
-
-        let min_factor2 = 2; // benchmarking shows that while 3^n and  2 * 3^n are fast, they're typically slower than the next-higher candidate, so don't bother generating them
-        while factor2 >= min_factor2 {
-            // if this candidate length isn't too small, add it to our candidates list
-            if candidate >= min_len {
-                bluesteins_candidates.push((candidate, factor2, factor3));
-            }
-            // if the candidate is too large, divide it by 2. if it's too small, divide it by 3
-            if candidate >= baseline_candidate {
-                candidate >>= 1;
-                factor2 -= 1;
-            } else {
-                candidate *= 3;
-                factor3 += 1;
-            }
-        }
-        bluesteins_candidates.sort();
-
-        // we now have a list of candidates to choosse from. some 2^n * 3^m FFTs are faster than others, so apply a filter, which will let us skip sizes that benchmarking has shown to be slow
-        let (chosen_size, _, _) =
-            bluesteins_candidates
-                .iter()
-                .find(filter_fn)
-                .unwrap_or_else(|| {
-                    panic!(
-                        "Failed to find a bluestein's candidate for len={}, candidates: {:?}",
-                        len, bluesteins_candidates
-                    )
-                });
+    // Constructs and returns a FFT instance from a FFT plan, with every output multiplied by `scale`.
+    // Only the outermost step of the plan is scaled. Everything below it is constructed with `construct_plan`, so it can be shared with the cache.
+    fn construct_scaled_plan(
+        &mut self,
+        mut plan: MixedRadixPlan,
+        direction: FftDirection,
+        scale: f64,
+        construct_butterfly_fn: impl FnOnce(&Self, usize, FftDirection) -> Arc<dyn Fft<T>>,
+        mut inner_fft_fn: impl FnMut(&mut Self, usize, FftDirection) -> Arc<dyn Fft<T>>,
+    ) -> Arc<dyn Fft<T>> {
+        if let Some(outer_radix) = plan.radixes.pop() {
+            // The rest of the plan is unscaled, so some of it may already be cached
+            let inner_plan =
+                self.replan_with_cache(MixedRadixPlan::new(plan.base, plan.radixes), direction);
+            let inner_fft =
+                self.construct_plan(inner_plan, direction, construct_butterfly_fn, inner_fft_fn);
+
+            // Fold the scale into the twiddle factors of the outermost radix step
+            match outer_radix {
+                2 => wrap_fft(MixedRadix2xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                3 => wrap_fft(MixedRadix3xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                4 => wrap_fft(MixedRadix4xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                5 => wrap_fft(MixedRadix5xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                6 => wrap_fft(MixedRadix6xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                7 => wrap_fft(MixedRadix7xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                8 => wrap_fft(MixedRadix8xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                9 => wrap_fft(MixedRadix9xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                11 => wrap_fft(MixedRadix11xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                12 => wrap_fft(MixedRadix12xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                16 => wrap_fft(MixedRadix16xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                13 => wrap_fft(MixedRadix13xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                17 => wrap_fft(MixedRadix17xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                19 => wrap_fft(MixedRadix19xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                23 => wrap_fft(MixedRadix23xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                29 => wrap_fft(MixedRadix29xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                31 => wrap_fft(MixedRadix31xnAvx::<A, T>::new_scaled(inner_fft, scale).unwrap()),
+                _ => unreachable!(),
+            }
+        } else if let MixedRadixBase::BluesteinsBase(len, inner_fft_len) = plan.base {
+            // Bluestein's algorithm can fold the scale into its precomputed multiplier
+            let inner_fft = inner_fft_fn(self, inner_fft_len, direction);
+            let chirp_spectrum = self.chirp_cache.get_or_compute(len, &*inner_fft);
+            wrap_fft(
+                BluesteinsAvx::<A, T>::new_scaled(len, inner_fft, chirp_spectrum, scale).unwrap(),
+            )
+        } else {
+            // Butterflies, Rader's algorithm and Good-Thomas have nowhere to fold the scale into, so apply it with an extra pass over the data
+            let fft = self.construct_plan(plan, direction, construct_butterfly_fn, inner_fft_fn);
+            wrap_fft(ScaledFft::new(fft, T::from_f64(scale).unwrap()))
+        }
+    }
+
+    // If `len` is a product of primes that we have both butterflies and mixed radix steps for, returns a plan that uses the largest as the butterfly base, and the rest as radix steps.
+    // Benchmarking showed this to be faster than good-thomas, rader's, and bluestein's for every size we tried, for both f32 and f64
+    fn plan_prime_butterfly_chain(len: usize) -> Option<MixedRadixPlan> {
+        let prime_factors = PrimeFactors::compute(len);
+        if prime_factors.get_power_of_two() > 0 || prime_factors.get_power_of_three() > 0 {
+            return None;
+        }
+
+        let mut radixes = Vec::new();
+        for factor in prime_factors.get_other_factors().iter().rev() {
+            if ![13, 17, 19, 23, 29, 31].contains(&factor.value) {
+                return None;
+            }
+            for _ in 0..factor.count {
+                radixes.push(factor.value as u8);
+            }
+        }
 
-        *chosen_size
+        // Our radixes are sorted from largest to smallest, so the first one is the base
+        let base = radixes.remove(0) as usize;
+        Some(MixedRadixPlan::butterfly(base, radixes))
+    }
+
+    // If `len` has at least two distinct prime factors, returns a plan that splits it into two coprime halves with the Good-Thomas Algorithm
+    fn plan_good_thomas_base(len: usize) -> Option<MixedRadixPlan> {
+        let (width_factors, height_factors) =
+            PrimeFactors::compute(len).partition_coprime_factors()?;
+        Some(MixedRadixPlan::new(
+            MixedRadixBase::GoodThomasBase(
+                width_factors.get_product(),
+                height_factors.get_product(),
+            ),
+            vec![],
+        ))
     }
 }
 
 #[cfg(test)]
 mod unit_tests {
     use super::*;
+    use crate::test_utils::{
+        check_fft_algorithm, check_normalized_fft_algorithm, check_pruned_fft_algorithm,
+    };
 
     // We don't need to actually compute anything for a FFT size of zero, but we do need to verify that it doesn't explode
     #[test]
@@ -1009,4 +1363,285 @@ mod unit_tests {
         let fft_zero64 = planner64.plan_fft_forward(0);
         fft_zero64.process(&mut []);
     }
+
+    #[test]
+    fn test_cache_capacity_avx() {
+        let element_size = std::mem::size_of::<num_complex::Complex<f32>>();
+        let mut planner = FftPlannerAvx::<f32>::new().unwrap();
+        planner.set_max_cached_ffts(Some(2));
+
+        // The radix steps of a plan are cached too, so only the last two steps should stay cached
+        let len = 1 << 12;
+        let fft = planner.plan_fft_forward(len);
+        assert_eq!(planner.cached_fft_count(), 2);
+        assert!(planner
+            .internal_planner
+            .cache()
+            .contains_fft(len, FftDirection::Forward));
+
+        // Evicted inner FFTs should keep working, and planning them again should work too
+        planner.set_max_cached_ffts(Some(1));
+        check_fft_algorithm(&*fft, len, FftDirection::Forward);
+
+        // Once the outer FFT is gone, the twiddle table of its outer step is released too
+        drop(fft);
+        let inner_fft = planner.plan_fft_forward(len / 8);
+        check_fft_algorithm(&*inner_fft, len / 8, FftDirection::Forward);
+        assert_eq!(planner.cached_fft_count(), 1);
+        assert_eq!(planner.cache_memory(), len / 8 * element_size);
+
+        planner.evict(len / 8);
+        assert_eq!(planner.cached_fft_count(), 0);
+
+        // Clearing the planner should drop the shared chirp spectra too
+        let len = 1439;
+        let fft = planner.plan_fft_forward(len);
+        drop(fft);
+        planner.clear();
+        assert_eq!(planner.cache_memory(), 0);
+        assert_eq!(
+            planner.internal_planner.chirp_cache_mut().spectrum_count(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_twiddle_sharing_avx() {
+        // Pruned FFTs aren't cached, but the twiddles of their scalar mixed radix steps should be shared with previous pruned FFTs of the same size
+        let len = 1200;
+        let mut planner = FftPlannerAvx::<f32>::new().unwrap();
+        let fft = planner.plan_fft_pruned(len, FftDirection::Forward, 1, 1);
+        check_pruned_fft_algorithm(&*fft, len, FftDirection::Forward, 1, 1, false);
+        let before = planner.twiddle_stats();
+        assert!(before.computed_tables() > 0);
+
+        planner.plan_fft_pruned(len, FftDirection::Forward, 1, 1);
+        let after = planner.twiddle_stats();
+        assert_eq!(after.computed_tables(), before.computed_tables());
+        assert_eq!(after.requests(), before.requests() * 2);
+        assert_eq!(
+            after.bytes_saved() - before.bytes_saved(),
+            before.requested_bytes()
+        );
+    }
+
+    #[test]
+    fn test_twiddle_sharing_avx_steps() {
+        // The AVX mixed radix steps should share their twiddles with steps of the same shape, even after they've been evicted from the FFT cache
+        let len = 3 << 10;
+        let mut planner = FftPlannerAvx::<f64>::new().unwrap();
+        let fft = planner.plan_fft_forward(len);
+        let before = planner.twiddle_stats();
+        assert!(before.computed_tables() > 0);
+
+        planner.internal_planner.cache_mut().clear();
+        let replanned = planner.plan_fft_forward(len);
+        check_fft_algorithm(&*replanned, len, FftDirection::Forward);
+        let after = planner.twiddle_stats();
+        assert_eq!(after.computed_tables(), before.computed_tables());
+        assert_eq!(after.requests(), before.requests() * 2);
+        drop(fft);
+    }
+
+    #[test]
+    fn test_chirp_cache_avx() {
+        // Forward, inverse and scaled instances of Bluestein's algorithm should all share the same chirp spectrum
+        let len = 1439;
+        let factors = PartialFactors::compute(len);
+
+        let mut planner32 = AvxPlannerInternal::<f32, f32>::new();
+        assert!(match planner32.plan_mixed_radix_base(len, &factors).base {
+            MixedRadixBase::BluesteinsBase(..) => true,
+            _ => false,
+        });
+        let mut planner64 = AvxPlannerInternal::<f64, f64>::new();
+        assert!(match planner64.plan_mixed_radix_base(len, &factors).base {
+            MixedRadixBase::BluesteinsBase(..) => true,
+            _ => false,
+        });
+
+        for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+            let fft32 = planner32.plan_and_construct_fft(len, direction);
+            check_fft_algorithm(&*fft32, len, direction);
+
+            let fft64 = planner64.plan_and_construct_fft(len, direction);
+            check_fft_algorithm(&*fft64, len, direction);
+
+            let normalization = FftNormalization::Unitary;
+            let scale = normalization.scale_factor(len, direction);
+            let fft32 = planner32.plan_and_construct_scaled_fft(len, direction, scale);
+            check_normalized_fft_algorithm(&*fft32, len, direction, normalization);
+
+            let fft64 = planner64.plan_and_construct_scaled_fft(len, direction, scale);
+            check_normalized_fft_algorithm(&*fft64, len, direction, normalization);
+        }
+        assert_eq!(planner32.chirp_cache.spectrum_count(), 1);
+        assert_eq!(planner64.chirp_cache.spectrum_count(), 1);
+    }
+
+    #[test]
+    fn test_plan_normalized_avx() {
+        let mut planner32 = FftPlannerAvx::<f32>::new().unwrap();
+        let mut planner64 = FftPlannerAvx::<f64>::new().unwrap();
+
+        // Cover butterfly bases, Rader's and Bluestein's bases, and mixed radix chains with and without partial remainders
+        for &len in &[
+            1, 7, 30, 37, 59, 100, 128, 243, 1000, 1201, 2048, 4096, 4112,
+        ] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::None,
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let fft32 = planner32.plan_fft_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm(&*fft32, len, direction, normalization);
+
+                    let fft64 = planner64.plan_fft_normalized(len, direction, normalization);
+                    check_normalized_fft_algorithm(&*fft64, len, direction, normalization);
+                }
+
+                // Make sure the scaled FFTs didn't leak into the cache
+                let fft32 = planner32.plan_fft(len, direction);
+                check_fft_algorithm(&*fft32, len, direction);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_bluesteins_avx() {
+        // BluesteinsAvx requires its inner FFT length to be a multiple of the number of complex numbers per vector
+        let mut bluesteins_count = 0;
+        for len in 2..3000 {
+            let factors = PartialFactors::compute(len);
+            for &(ref plan, complex_per_vector) in &[
+                (
+                    AvxPlannerInternal::<f32, f32>::new().plan_mixed_radix_base(len, &factors),
+                    4,
+                ),
+                (
+                    AvxPlannerInternal::<f64, f64>::new().plan_mixed_radix_base(len, &factors),
+                    2,
+                ),
+            ] {
+                if let MixedRadixBase::BluesteinsBase(base_len, inner_len) = plan.base {
+                    assert!(inner_len >= base_len * 2 - 1, "len = {}", len);
+                    assert_eq!(inner_len % complex_per_vector, 0, "len = {}", len);
+                    bluesteins_count += 1;
+                }
+            }
+        }
+        assert!(bluesteins_count > 0);
+    }
+
+    #[test]
+    fn test_plan_good_thomas_avx() {
+        // GoodThomasAvx2 needs AVX2, so without it, none of these sizes should get a good-thomas base
+        let has_avx2 = is_x86_feature_detected!("avx2");
+
+        // For f64, sizes whose "other factors" have at least two distinct primes should get a good-thomas base
+        for &(len, width, height) in &[(533, 41, 13), (1517, 41, 37), (1369 * 13, 1369, 13)] {
+            let factors = PartialFactors::compute(len);
+            let base = AvxPlannerInternal::<f64, f64>::new()
+                .plan_mixed_radix_base(len, &factors)
+                .base;
+            assert_eq!(
+                base == MixedRadixBase::GoodThomasBase(width, height),
+                has_avx2,
+                "len = {}",
+                len
+            );
+        }
+
+        // For f32, only sizes of at least 4096 with a half that's made of butterflies should get a good-thomas base
+        for &(len, width, height) in &[(13117, 1009, 13), (11951, 37, 323), (1024 * 5911, 257, 23)]
+        {
+            let factors = PartialFactors::compute(len);
+            let base = AvxPlannerInternal::<f32, f32>::new()
+                .plan_mixed_radix_base(len, &factors)
+                .base;
+            assert_eq!(
+                base == MixedRadixBase::GoodThomasBase(width, height),
+                has_avx2,
+                "len = {}",
+                len
+            );
+        }
+        for &len in &[1517, 3341, 10403] {
+            let factors = PartialFactors::compute(len);
+            let inner_len = SmoothCostModel::AVX_F32.choose_len(len * 2 - 1);
+            assert_eq!(
+                AvxPlannerInternal::<f32, f32>::new()
+                    .plan_mixed_radix_base(len, &factors)
+                    .base,
+                MixedRadixBase::BluesteinsBase(len, inner_len)
+            );
+        }
+
+        // Prime powers can't be split into coprime halves, so they still use rader's or bluestein's
+        let factors = PartialFactors::compute(1369);
+        assert_ne!(
+            AvxPlannerInternal::<f64, f64>::new()
+                .plan_mixed_radix_base(1369, &factors)
+                .base,
+            MixedRadixBase::GoodThomasBase(37, 37)
+        );
+
+        // Cover good-thomas as the whole FFT, and good-thomas as the base of a mixed radix chain
+        let mut planner32 = FftPlannerAvx::<f32>::new().unwrap();
+        let mut planner64 = FftPlannerAvx::<f64>::new().unwrap();
+        for &len in &[533, 2132, 1599, 1517, 3034, 1369 * 13, 5911, 2 * 5911] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let fft32 = planner32.plan_fft(len, direction);
+                check_fft_algorithm(&*fft32, len, direction);
+
+                let fft64 = planner64.plan_fft(len, direction);
+                check_fft_algorithm(&*fft64, len, direction);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_prime_chain_avx() {
+        // Products of the primes we have butterflies and mixed radix steps for should use the largest as the base, and the rest as radix steps
+        for &(len, base, ref radixes) in &[
+            (169, 13, vec![13]),
+            (221, 17, vec![13]),
+            (899, 31, vec![29]),
+            (19 * 23 * 31 * 31, 31, vec![31, 23, 19]),
+        ] {
+            let factors = PartialFactors::compute(len);
+            for plan in &[
+                AvxPlannerInternal::<f32, f32>::new().plan_mixed_radix_base(len, &factors),
+                AvxPlannerInternal::<f64, f64>::new().plan_mixed_radix_base(len, &factors),
+            ] {
+                assert_eq!(plan.base, MixedRadixBase::ButterflyBase(base));
+                assert_eq!(&plan.radixes, radixes);
+                assert_eq!(plan.len, len);
+            }
+        }
+
+        // If any other factor doesn't have a butterfly, we can't build a chain
+        let factors = PartialFactors::compute(13 * 37);
+        assert_ne!(
+            AvxPlannerInternal::<f32, f32>::new()
+                .plan_mixed_radix_base(13 * 37, &factors)
+                .base,
+            MixedRadixBase::ButterflyBase(37)
+        );
+
+        // Cover prime chains as the whole FFT, and as the base of a longer mixed radix chain
+        let mut planner32 = FftPlannerAvx::<f32>::new().unwrap();
+        let mut planner64 = FftPlannerAvx::<f64>::new().unwrap();
+        for &len in &[169, 221, 884, 741, 1334, 2873, 4913] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let fft32 = planner32.plan_fft(len, direction);
+                check_fft_algorithm(&*fft32, len, direction);
+
+                let fft64 = planner64.plan_fft(len, direction);
+                check_fft_algorithm(&*fft64, len, direction);
+            }
+        }
+    }
 }
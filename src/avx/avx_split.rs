@@ -0,0 +1,199 @@
+use num_complex::Complex;
+use num_traits::Zero;
+
+use super::avx_vector::{AvxArray, AvxArrayMut, AvxVector, AvxVector256};
+use super::AvxNum;
+
+// Real parts and imaginary parts in separate slices, which vectors of complex numbers can be loaded from as if they were interleaved.
+// Lets the AVX algorithms read split-complex data in their first pass, instead of interleaving it in a separate pass beforehand
+pub struct SplitSlice<'a, A> {
+    re: &'a [A],
+    im: &'a [A],
+}
+impl<'a, A: AvxNum> SplitSlice<'a, A> {
+    pub fn new(re: &'a [A], im: &'a [A]) -> Self {
+        assert_eq!(re.len(), im.len());
+        Self { re, im }
+    }
+
+    // Interleaves the complex numbers starting at `index` into `output`. Only used for the last few columns, which don't fill a whole vector
+    #[inline(always)]
+    fn gather(&self, index: usize, output: &mut [Complex<A>]) {
+        for (i, element) in output.iter_mut().enumerate() {
+            *element = Complex::new(self.re[index + i], self.im[index + i]);
+        }
+    }
+}
+impl<'a, A: AvxNum> AvxArray<A> for SplitSlice<'a, A> {
+    #[inline(always)]
+    unsafe fn load_complex(&self, index: usize) -> A::VectorType {
+        debug_assert!(self.re.len() >= index + A::VectorType::COMPLEX_PER_VECTOR);
+        A::VectorType::load_split_complex(self.re.as_ptr().add(index), self.im.as_ptr().add(index))
+    }
+    #[inline(always)]
+    unsafe fn load_partial1_complex(
+        &self,
+        index: usize,
+    ) -> <A::VectorType as AvxVector256>::HalfVector {
+        let mut buffer = [Complex::zero(); 1];
+        self.gather(index, &mut buffer);
+        AvxArray::<A>::load_partial1_complex(&buffer[..], 0)
+    }
+    #[inline(always)]
+    unsafe fn load_partial2_complex(
+        &self,
+        index: usize,
+    ) -> <A::VectorType as AvxVector256>::HalfVector {
+        let mut buffer = [Complex::zero(); 2];
+        self.gather(index, &mut buffer);
+        AvxArray::<A>::load_partial2_complex(&buffer[..], 0)
+    }
+    #[inline(always)]
+    unsafe fn load_partial3_complex(&self, index: usize) -> A::VectorType {
+        let mut buffer = [Complex::zero(); 3];
+        self.gather(index, &mut buffer);
+        AvxArray::<A>::load_partial3_complex(&buffer[..], 0)
+    }
+}
+
+// Real parts and imaginary parts in separate slices, which vectors of complex numbers can be stored to as if they were interleaved.
+// Lets the AVX algorithms write split-complex data in their last pass, instead of splitting it in a separate pass afterwards
+pub struct SplitSliceMut<'a, A> {
+    re: &'a mut [A],
+    im: &'a mut [A],
+}
+impl<'a, A: AvxNum> SplitSliceMut<'a, A> {
+    pub fn new(re: &'a mut [A], im: &'a mut [A]) -> Self {
+        assert_eq!(re.len(), im.len());
+        Self { re, im }
+    }
+
+    // Splits the complex numbers in `input` out to `index` and onwards. Only used for the last few columns, which don't fill a whole vector
+    #[inline(always)]
+    fn scatter(&mut self, input: &[Complex<A>], index: usize) {
+        for (i, element) in input.iter().enumerate() {
+            self.re[index + i] = element.re;
+            self.im[index + i] = element.im;
+        }
+    }
+}
+impl<'a, A: AvxNum> AvxArrayMut<A> for SplitSliceMut<'a, A> {
+    #[inline(always)]
+    unsafe fn store_complex(&mut self, data: A::VectorType, index: usize) {
+        debug_assert!(self.re.len() >= index + A::VectorType::COMPLEX_PER_VECTOR);
+        A::VectorType::store_split_complex(
+            self.re.as_mut_ptr().add(index),
+            self.im.as_mut_ptr().add(index),
+            data,
+        )
+    }
+    #[inline(always)]
+    unsafe fn store_partial1_complex(
+        &mut self,
+        data: <A::VectorType as AvxVector256>::HalfVector,
+        index: usize,
+    ) {
+        let mut buffer = [Complex::zero(); 1];
+        AvxArrayMut::<A>::store_partial1_complex(&mut buffer[..], data, 0);
+        self.scatter(&buffer, index);
+    }
+    #[inline(always)]
+    unsafe fn store_partial2_complex(
+        &mut self,
+        data: <A::VectorType as AvxVector256>::HalfVector,
+        index: usize,
+    ) {
+        let mut buffer = [Complex::zero(); 2];
+        AvxArrayMut::<A>::store_partial2_complex(&mut buffer[..], data, 0);
+        self.scatter(&buffer, index);
+    }
+    #[inline(always)]
+    unsafe fn store_partial3_complex(&mut self, data: A::VectorType, index: usize) {
+        let mut buffer = [Complex::zero(); 3];
+        AvxArrayMut::<A>::store_partial3_complex(&mut buffer[..], data, 0);
+        self.scatter(&buffer, index);
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use num_traits::Float;
+    use rand::distributions::uniform::SampleUniform;
+
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::avx::avx_bluesteins::BluesteinsAvx;
+    use crate::avx::avx_mixed_radix::{MixedRadix3xnAvx, MixedRadix4xnAvx, MixedRadix5xnAvx};
+    use crate::test_utils::{compare_vectors, random_signal};
+    use crate::{Fft, FftDirection};
+    use std::sync::Arc;
+
+    // process_split() should compute the same output as process() on the interleaved data. Process two chunks at once, so that each call covers
+    // more than one FFT, and use inner lengths that aren't multiples of the vector width, so that the partial loads and stores get covered too
+    fn compare_split_with_interleaved<T: AvxNum + Float + SampleUniform>(fft: &dyn Fft<T>) {
+        let len = fft.len();
+        let input = random_signal::<T>(len * 2);
+
+        let mut expected = input.clone();
+        fft.process(&mut expected);
+
+        let mut re: Vec<T> = input.iter().map(|c| c.re).collect();
+        let mut im: Vec<T> = input.iter().map(|c| c.im).collect();
+        let mut scratch = vec![Complex::zero(); fft.get_split_scratch_len()];
+        fft.process_split(&mut re, &mut im, &mut scratch);
+
+        let output: Vec<Complex<T>> = re
+            .iter()
+            .zip(im.iter())
+            .map(|(re, im)| Complex::new(*re, *im))
+            .collect();
+        assert!(
+            compare_vectors(&expected, &output),
+            "len = {}, direction = {}",
+            len,
+            fft.fft_direction()
+        );
+    }
+
+    fn test_split_mixed_radix<T: AvxNum + Float + SampleUniform>() {
+        for inner_fft_len in 1..20 {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let inner_fft = Arc::new(Dft::new(inner_fft_len, direction)) as Arc<dyn Fft<T>>;
+                let fft3 = MixedRadix3xnAvx::<T, T>::new(Arc::clone(&inner_fft)).expect("Can't run test because this machine doesn't have the required instruction sets");
+                compare_split_with_interleaved(&fft3);
+                let fft4 = MixedRadix4xnAvx::<T, T>::new(Arc::clone(&inner_fft)).expect("Can't run test because this machine doesn't have the required instruction sets");
+                compare_split_with_interleaved(&fft4);
+                let fft5 = MixedRadix5xnAvx::<T, T>::new(inner_fft).expect("Can't run test because this machine doesn't have the required instruction sets");
+                compare_split_with_interleaved(&fft5);
+            }
+        }
+    }
+
+    fn test_split_bluesteins<T: AvxNum + Float + SampleUniform>() {
+        for &len in &[2usize, 3, 5, 13, 29, 97] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let inner_fft_len = (len * 2 - 1).next_power_of_two().max(16);
+                let inner_fft = Arc::new(Dft::new(inner_fft_len, direction)) as Arc<dyn Fft<T>>;
+                let fft = BluesteinsAvx::<T, T>::new(len, inner_fft).expect("Can't run test because this machine doesn't have the required instruction sets");
+                compare_split_with_interleaved(&fft);
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_mixed_radix_f32() {
+        test_split_mixed_radix::<f32>();
+    }
+    #[test]
+    fn test_split_mixed_radix_f64() {
+        test_split_mixed_radix::<f64>();
+    }
+    #[test]
+    fn test_split_bluesteins_f32() {
+        test_split_bluesteins::<f32>();
+    }
+    #[test]
+    fn test_split_bluesteins_f64() {
+        test_split_bluesteins::<f64>();
+    }
+}
@@ -1,6 +1,8 @@
 use std::arch::x86_64::*;
 use std::fmt::Debug;
 
+#[cfg(feature = "half")]
+use half::f16;
 use num_complex::Complex;
 use num_traits::Zero;
 
@@ -11,6 +13,64 @@ use crate::{
 
 use super::AvxNum;
 
+// Generates a column butterfly for an odd prime size, using the same algorithm as column_butterfly7 and column_butterfly11.
+// Instead of writing out every multiply by hand, we loop over the rows. The generated function takes the twiddle factors 1 through len/2, each broadcast across a whole vector.
+// The last argument must list 1 through len/2. We use it to manually unroll the outer loops, because the compiler won't unroll them on its own for the larger sizes,
+// and the twiddle indexes are only compile-time constants if the loops are unrolled
+macro_rules! column_butterfly_prime {
+    ($fn_name:ident, $len:expr, [$($half_index:expr),*]) => {
+        #[inline(always)]
+        unsafe fn $fn_name(rows: [Self; $len], twiddles: [Self; $len / 2]) -> [Self; $len] {
+            const LEN: usize = $len;
+            const HALF_LEN: usize = $len / 2;
+
+            // do some prep work before we can start applying twiddle factors. Row i gets paired with row LEN - i, and their difference gets rotated by 90 degrees
+            let rotation = Self::make_rotation90(FftDirection::Inverse);
+            let mut sums = [Self::zero(); HALF_LEN];
+            let mut rotated_diffs = [Self::zero(); HALF_LEN];
+            let mut output0 = rows[0];
+            $(
+                let [sum, diff] = Self::column_butterfly2([rows[$half_index], rows[LEN - $half_index]]);
+                sums[$half_index - 1] = sum;
+                rotated_diffs[$half_index - 1] = Self::rotate90(diff, rotation);
+                output0 = Self::add(output0, sum);
+            )*
+
+            // Split the twiddles into their real and imaginary parts. Twiddles past the halfway point are conjugates of the ones before it, so negate their imaginaries
+            let mut twiddles_re = [Self::zero(); LEN];
+            let mut twiddles_im = [Self::zero(); LEN];
+            $(
+                let (twiddle_re, twiddle_im) = Self::duplicate_complex_components(twiddles[$half_index - 1]);
+                twiddles_re[$half_index] = twiddle_re;
+                twiddles_im[$half_index] = twiddle_im;
+                twiddles_re[LEN - $half_index] = twiddle_re;
+                twiddles_im[LEN - $half_index] = Self::neg(twiddle_im);
+            )*
+
+            // Output k multiplies pair i by twiddle (k * i) % LEN. The real twiddles apply to the sums, and the imaginary twiddles apply to the rotated differences,
+            // and output LEN - k uses the same products, with the imaginary half subtracted instead of added
+            let mut output = [Self::zero(); LEN];
+            output[0] = output0;
+            $(
+                let k = $half_index;
+                let mut twiddled_re = Self::fmadd(twiddles_re[k], sums[0], rows[0]);
+                let mut twiddled_im = Self::mul(twiddles_im[k], rotated_diffs[0]);
+                for i in 1..HALF_LEN {
+                    let twiddle_index = (k * (i + 1)) % LEN;
+                    twiddled_re = Self::fmadd(twiddles_re[twiddle_index], sums[i], twiddled_re);
+                    twiddled_im =
+                        Self::fmadd(twiddles_im[twiddle_index], rotated_diffs[i], twiddled_im);
+                }
+
+                let [output_k, output_mirrored] = Self::column_butterfly2([twiddled_re, twiddled_im]);
+                output[k] = output_k;
+                output[LEN - k] = output_mirrored;
+            )*
+            output
+        }
+    };
+}
+
 /// A SIMD vector of complex numbers, stored with the real values and imaginary values interleaved.
 /// Implemented for __m128, __m128d, __m256, __m256d, but these all require the AVX instruction set.
 ///
@@ -480,6 +540,21 @@ pub trait AvxVector: Copy + Debug + Send + Sync {
             output2[3], output3[3],
         ]
     }
+
+    column_butterfly_prime!(column_butterfly13, 13, [1, 2, 3, 4, 5, 6]);
+    column_butterfly_prime!(column_butterfly17, 17, [1, 2, 3, 4, 5, 6, 7, 8]);
+    column_butterfly_prime!(column_butterfly19, 19, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    column_butterfly_prime!(column_butterfly23, 23, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    column_butterfly_prime!(
+        column_butterfly29,
+        29,
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]
+    );
+    column_butterfly_prime!(
+        column_butterfly31,
+        31,
+        [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]
+    );
 }
 
 /// A 256-bit SIMD vector of complex numbers, stored with the real values and imaginary values interleaved.
@@ -508,6 +583,11 @@ pub trait AvxVector256: AvxVector {
     unsafe fn load_complex(ptr: *const Complex<Self::ScalarType>) -> Self;
     unsafe fn store_complex(ptr: *mut Complex<Self::ScalarType>, data: Self);
 
+    // loads/stores of complex numbers kept in separate arrays of real parts and imaginary parts. Each array pointer covers
+    // COMPLEX_PER_VECTOR scalars, and the complex numbers are returned/accepted as a single vector of interleaved complex numbers
+    unsafe fn load_split_complex(re: *const Self::ScalarType, im: *const Self::ScalarType) -> Self;
+    unsafe fn store_split_complex(re: *mut Self::ScalarType, im: *mut Self::ScalarType, data: Self);
+
     // Gather 4 complex numbers (for f32) or 2 complex numbers (for f64) using 4 i32 indexes (for index32) or 4 i64 indexes (for index64).
     // For f32, there should be 1 index per complex. For f64, there should be 2 indexes, each duplicated
     // (So to load the complex<f64> at index 5 and 7, the index vector should contain 5,5,7,7. this api sucks but it's internal so whatever.)
@@ -1152,6 +1232,25 @@ impl AvxVector256 for __m256 {
         _mm256_storeu_ps(ptr as *mut Self::ScalarType, data)
     }
     #[inline(always)]
+    unsafe fn load_split_complex(re: *const Self::ScalarType, im: *const Self::ScalarType) -> Self {
+        let re = _mm_loadu_ps(re);
+        let im = _mm_loadu_ps(im);
+        let lo = _mm_unpacklo_ps(re, im);
+        let hi = _mm_unpackhi_ps(re, im);
+        _mm256_insertf128_ps(_mm256_castps128_ps256(lo), hi, 1)
+    }
+    #[inline(always)]
+    unsafe fn store_split_complex(
+        re: *mut Self::ScalarType,
+        im: *mut Self::ScalarType,
+        data: Self,
+    ) {
+        let lo = _mm256_castps256_ps128(data);
+        let hi = _mm256_extractf128_ps(data, 1);
+        _mm_storeu_ps(re, _mm_shuffle_ps(lo, hi, 0x88));
+        _mm_storeu_ps(im, _mm_shuffle_ps(lo, hi, 0xDD));
+    }
+    #[inline(always)]
     unsafe fn gather_complex_avx2_index32(
         ptr: *const Complex<Self::ScalarType>,
         indexes: __m128i,
@@ -1196,6 +1295,68 @@ impl AvxVector256 for __m256 {
     }
 }
 
+/// A 256-bit SIMD vector of f32 complex numbers that can be loaded from and stored to half-precision memory.
+/// Implemented for __m256. The conversions use the F16C instruction set, so callers must enable the "f16c" target feature in addition to "avx"
+#[cfg(feature = "half")]
+pub trait AvxVectorF16: AvxVector256<ScalarType = f32> {
+    unsafe fn load_complex_f16(ptr: *const Complex<f16>) -> Self;
+    unsafe fn store_complex_f16(ptr: *mut Complex<f16>, data: Self);
+
+    unsafe fn load_partial1_complex_f16(ptr: *const Complex<f16>) -> Self::HalfVector;
+    unsafe fn load_partial2_complex_f16(ptr: *const Complex<f16>) -> Self::HalfVector;
+    unsafe fn load_partial3_complex_f16(ptr: *const Complex<f16>) -> Self;
+    unsafe fn store_partial1_complex_f16(ptr: *mut Complex<f16>, data: Self::HalfVector);
+    unsafe fn store_partial2_complex_f16(ptr: *mut Complex<f16>, data: Self::HalfVector);
+    unsafe fn store_partial3_complex_f16(ptr: *mut Complex<f16>, data: Self);
+}
+#[cfg(feature = "half")]
+impl AvxVectorF16 for __m256 {
+    #[inline(always)]
+    unsafe fn load_complex_f16(ptr: *const Complex<f16>) -> Self {
+        _mm256_cvtph_ps(_mm_loadu_si128(ptr as *const __m128i))
+    }
+    #[inline(always)]
+    unsafe fn store_complex_f16(ptr: *mut Complex<f16>, data: Self) {
+        _mm_storeu_si128(
+            ptr as *mut __m128i,
+            _mm256_cvtps_ph(data, _MM_FROUND_TO_NEAREST_INT),
+        )
+    }
+
+    #[inline(always)]
+    unsafe fn load_partial1_complex_f16(ptr: *const Complex<f16>) -> Self::HalfVector {
+        let data = std::ptr::read_unaligned(ptr as *const i32);
+        _mm_cvtph_ps(_mm_cvtsi32_si128(data))
+    }
+    #[inline(always)]
+    unsafe fn load_partial2_complex_f16(ptr: *const Complex<f16>) -> Self::HalfVector {
+        _mm_cvtph_ps(_mm_loadl_epi64(ptr as *const __m128i))
+    }
+    #[inline(always)]
+    unsafe fn load_partial3_complex_f16(ptr: *const Complex<f16>) -> Self {
+        let lo = Self::load_partial2_complex_f16(ptr);
+        let hi = Self::load_partial1_complex_f16(ptr.add(2));
+        Self::merge(lo, hi)
+    }
+    #[inline(always)]
+    unsafe fn store_partial1_complex_f16(ptr: *mut Complex<f16>, data: Self::HalfVector) {
+        let converted = _mm_cvtps_ph(data, _MM_FROUND_TO_NEAREST_INT);
+        std::ptr::write_unaligned(ptr as *mut i32, _mm_cvtsi128_si32(converted));
+    }
+    #[inline(always)]
+    unsafe fn store_partial2_complex_f16(ptr: *mut Complex<f16>, data: Self::HalfVector) {
+        _mm_storel_epi64(
+            ptr as *mut __m128i,
+            _mm_cvtps_ph(data, _MM_FROUND_TO_NEAREST_INT),
+        );
+    }
+    #[inline(always)]
+    unsafe fn store_partial3_complex_f16(ptr: *mut Complex<f16>, data: Self) {
+        Self::store_partial2_complex_f16(ptr, data.lo());
+        Self::store_partial1_complex_f16(ptr.add(2), data.hi());
+    }
+}
+
 impl AvxVector for __m128 {
     const SCALAR_PER_VECTOR: usize = 4;
     const COMPLEX_PER_VECTOR: usize = 2;
@@ -1311,7 +1472,7 @@ impl AvxVector for __m128 {
     #[inline(always)]
     unsafe fn transpose3_packed(rows: [Self; 3]) -> [Self; 3] {
         let unpacked0 = Self::unpacklo_complex([rows[0], rows[1]]);
-        let blended = _mm_blend_ps(rows[0], rows[2], 0x33);
+        let blended = _mm_blend_ps(rows[0], rows[2], 0x03);
         let unpacked2 = Self::unpackhi_complex([rows[1], rows[2]]);
 
         [unpacked0, blended, unpacked2]
@@ -1328,7 +1489,7 @@ impl AvxVector for __m128 {
         [
             Self::unpacklo_complex([rows[0], rows[1]]),
             Self::unpacklo_complex([rows[2], rows[3]]),
-            _mm_blend_ps(rows[0], rows[4], 0x33),
+            _mm_blend_ps(rows[0], rows[4], 0x03),
             Self::unpackhi_complex([rows[1], rows[2]]),
             Self::unpackhi_complex([rows[3], rows[4]]),
         ]
@@ -1576,7 +1737,7 @@ impl AvxVector for __m256d {
     #[inline(always)]
     unsafe fn transpose3_packed(rows: [Self; 3]) -> [Self; 3] {
         let unpacked0 = Self::unpacklo_complex([rows[0], rows[1]]);
-        let blended = _mm256_blend_pd(rows[0], rows[2], 0x33);
+        let blended = _mm256_blend_pd(rows[0], rows[2], 0x03);
         let unpacked2 = Self::unpackhi_complex([rows[1], rows[2]]);
 
         [unpacked0, blended, unpacked2]
@@ -1593,7 +1754,7 @@ impl AvxVector for __m256d {
         [
             Self::unpacklo_complex([rows[0], rows[1]]),
             Self::unpacklo_complex([rows[2], rows[3]]),
-            _mm256_blend_pd(rows[0], rows[4], 0x33),
+            _mm256_blend_pd(rows[0], rows[4], 0x03),
             Self::unpackhi_complex([rows[1], rows[2]]),
             Self::unpackhi_complex([rows[3], rows[4]]),
         ]
@@ -1614,7 +1775,7 @@ impl AvxVector for __m256d {
             Self::unpacklo_complex([rows[0], rows[1]]),
             Self::unpacklo_complex([rows[2], rows[3]]),
             Self::unpacklo_complex([rows[4], rows[5]]),
-            _mm256_blend_pd(rows[0], rows[6], 0x33),
+            _mm256_blend_pd(rows[0], rows[6], 0x03),
             Self::unpackhi_complex([rows[1], rows[2]]),
             Self::unpackhi_complex([rows[3], rows[4]]),
             Self::unpackhi_complex([rows[5], rows[6]]),
@@ -1739,6 +1900,25 @@ impl AvxVector256 for __m256d {
         _mm256_storeu_pd(ptr as *mut Self::ScalarType, data)
     }
     #[inline(always)]
+    unsafe fn load_split_complex(re: *const Self::ScalarType, im: *const Self::ScalarType) -> Self {
+        let re = _mm_loadu_pd(re);
+        let im = _mm_loadu_pd(im);
+        let lo = _mm_unpacklo_pd(re, im);
+        let hi = _mm_unpackhi_pd(re, im);
+        _mm256_insertf128_pd(_mm256_castpd128_pd256(lo), hi, 1)
+    }
+    #[inline(always)]
+    unsafe fn store_split_complex(
+        re: *mut Self::ScalarType,
+        im: *mut Self::ScalarType,
+        data: Self,
+    ) {
+        let lo = _mm256_castpd256_pd128(data);
+        let hi = _mm256_extractf128_pd(data, 1);
+        _mm_storeu_pd(re, _mm_unpacklo_pd(lo, hi));
+        _mm_storeu_pd(im, _mm_unpackhi_pd(lo, hi));
+    }
+    #[inline(always)]
     unsafe fn gather_complex_avx2_index32(
         ptr: *const Complex<Self::ScalarType>,
         indexes: __m128i,
@@ -1856,11 +2036,11 @@ impl AvxVector for __m128d {
 
     #[inline(always)]
     unsafe fn swap_complex_components(self) -> Self {
-        _mm_permute_pd(self, 0x05)
+        _mm_permute_pd(self, 0x01)
     }
     #[inline(always)]
     unsafe fn duplicate_complex_components(self) -> (Self, Self) {
-        (_mm_movedup_pd(self), _mm_permute_pd(self, 0x0F))
+        (_mm_movedup_pd(self), _mm_permute_pd(self, 0x03))
     }
 
     #[inline(always)]
@@ -2123,6 +2303,55 @@ impl<T: AvxNum> AvxArrayMut<T> for RawSliceMut<Complex<T>> {
     }
 }
 
+// Half-precision buffers can be used anywhere an f32 buffer can, converting to and from f32 as the data is loaded and stored.
+// The conversions use the F16C instruction set, so callers must enable the "f16c" target feature in addition to "avx"
+#[cfg(feature = "half")]
+impl AvxArray<f32> for [Complex<f16>] {
+    #[inline(always)]
+    unsafe fn load_complex(&self, index: usize) -> __m256 {
+        debug_assert!(self.len() >= index + __m256::COMPLEX_PER_VECTOR);
+        __m256::load_complex_f16(self.as_ptr().add(index))
+    }
+    #[inline(always)]
+    unsafe fn load_partial1_complex(&self, index: usize) -> __m128 {
+        debug_assert!(self.len() > index);
+        __m256::load_partial1_complex_f16(self.as_ptr().add(index))
+    }
+    #[inline(always)]
+    unsafe fn load_partial2_complex(&self, index: usize) -> __m128 {
+        debug_assert!(self.len() >= index + 2);
+        __m256::load_partial2_complex_f16(self.as_ptr().add(index))
+    }
+    #[inline(always)]
+    unsafe fn load_partial3_complex(&self, index: usize) -> __m256 {
+        debug_assert!(self.len() >= index + 3);
+        __m256::load_partial3_complex_f16(self.as_ptr().add(index))
+    }
+}
+#[cfg(feature = "half")]
+impl AvxArrayMut<f32> for [Complex<f16>] {
+    #[inline(always)]
+    unsafe fn store_complex(&mut self, data: __m256, index: usize) {
+        debug_assert!(self.len() >= index + __m256::COMPLEX_PER_VECTOR);
+        __m256::store_complex_f16(self.as_mut_ptr().add(index), data);
+    }
+    #[inline(always)]
+    unsafe fn store_partial1_complex(&mut self, data: __m128, index: usize) {
+        debug_assert!(self.len() > index);
+        __m256::store_partial1_complex_f16(self.as_mut_ptr().add(index), data)
+    }
+    #[inline(always)]
+    unsafe fn store_partial2_complex(&mut self, data: __m128, index: usize) {
+        debug_assert!(self.len() >= index + 2);
+        __m256::store_partial2_complex_f16(self.as_mut_ptr().add(index), data)
+    }
+    #[inline(always)]
+    unsafe fn store_partial3_complex(&mut self, data: __m256, index: usize) {
+        debug_assert!(self.len() >= index + 3);
+        __m256::store_partial3_complex_f16(self.as_mut_ptr().add(index), data)
+    }
+}
+
 // A custom butterfly-16 function that calls a lambda to load/store data instead of taking an array
 // This is particularly useful for butterfly 16, because the whole problem doesn't fit into registers, and the compiler isn't smart enough to only load data when it's needed
 // So the version that takes an array ends up loading data and immediately re-storing it on the stack. By lazily loading and storing exactly when we need to, we can avoid some data reshuffling
@@ -17,16 +17,25 @@ impl AvxNum for f64 {
 // Algorithms aren't required to use this struct, but it allows for a lot of reduction in code duplication
 struct CommonSimdData<T, V> {
     inner_fft: Arc<dyn Fft<T>>,
-    twiddles: Box<[V]>,
+    twiddles: V,
 
     len: usize,
 
     inplace_scratch_len: usize,
     outofplace_scratch_len: usize,
+    split_scratch_len: usize,
 
     direction: FftDirection,
 }
 
+// Lets the algorithms that use CommonSimdData fuse data conversions into their first and last passes. By default, nothing is fused
+#[cfg(feature = "half")]
+trait FusedPasses {
+    fn fused_f16(&self) -> Option<&dyn crate::half_precision::FusedHalfFft<half::f16>> {
+        None
+    }
+}
+
 macro_rules! boilerplate_avx_fft {
     ($struct_name:ident, $len_fn:expr, $inplace_scratch_len_fn:expr, $out_of_place_scratch_len_fn:expr) => {
         impl<A: AvxNum, T: FftNum> Fft<T> for $struct_name<A, T> {
@@ -211,6 +220,89 @@ macro_rules! boilerplate_avx_fft_commondata {
                     );
                 }
             }
+            fn process_immutable(
+                &self,
+                input: &[Complex<T>],
+                output: &mut [Complex<T>],
+                scratch: &mut [Complex<T>],
+            ) {
+                if self.len() == 0 {
+                    return;
+                }
+
+                let required_scratch = self.get_immutable_scratch_len();
+                if scratch.len() < required_scratch
+                    || input.len() < self.len()
+                    || output.len() != input.len()
+                {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(
+                        self.len(),
+                        input.len(),
+                        output.len(),
+                        self.get_immutable_scratch_len(),
+                        scratch.len(),
+                    );
+                    return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+                }
+
+                let scratch = &mut scratch[..required_scratch];
+                let result = array_utils::iter_chunks_zipped_immutable(
+                    input,
+                    output,
+                    self.len(),
+                    |in_chunk, out_chunk| self.perform_fft_immutable(in_chunk, out_chunk, scratch),
+                );
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the FFT size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(
+                        self.len(),
+                        input.len(),
+                        output.len(),
+                        self.get_immutable_scratch_len(),
+                        scratch.len(),
+                    );
+                }
+            }
+            fn process_split(&self, re: &mut [T], im: &mut [T], scratch: &mut [Complex<T>]) {
+                if self.len() == 0 {
+                    return;
+                }
+
+                let required_scratch = self.get_split_scratch_len();
+                if scratch.len() < required_scratch || re.len() < self.len() || im.len() != re.len()
+                {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    crate::common::fft_error_split(
+                        self.len(),
+                        re.len(),
+                        im.len(),
+                        self.get_split_scratch_len(),
+                        scratch.len(),
+                    );
+                    return; // Unreachable, because fft_error_split asserts, but it helps codegen to put it here
+                }
+
+                let scratch = &mut scratch[..required_scratch];
+                let result =
+                    array_utils::iter_chunks_zipped(re, im, self.len(), |re_chunk, im_chunk| {
+                        self.perform_fft_split(re_chunk, im_chunk, scratch)
+                    });
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the FFT size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    crate::common::fft_error_split(
+                        self.len(),
+                        re.len(),
+                        im.len(),
+                        self.get_split_scratch_len(),
+                        scratch.len(),
+                    );
+                }
+            }
             #[inline(always)]
             fn get_inplace_scratch_len(&self) -> usize {
                 self.common_data.inplace_scratch_len
@@ -219,6 +311,19 @@ macro_rules! boilerplate_avx_fft_commondata {
             fn get_outofplace_scratch_len(&self) -> usize {
                 self.common_data.outofplace_scratch_len
             }
+            #[inline(always)]
+            fn get_immutable_scratch_len(&self) -> usize {
+                // Every algorithm that uses CommonSimdData can compute an immutable FFT with the same scratch as an in-place FFT
+                self.common_data.inplace_scratch_len
+            }
+            #[inline(always)]
+            fn get_split_scratch_len(&self) -> usize {
+                self.common_data.split_scratch_len
+            }
+            #[cfg(feature = "half")]
+            fn fused_f16(&self) -> Option<&dyn crate::half_precision::FusedHalfFft<half::f16>> {
+                crate::avx::FusedPasses::fused_f16(self)
+            }
         }
         impl<A: AvxNum, T> Length for $struct_name<A, T> {
             #[inline(always)]
@@ -245,30 +350,38 @@ mod avx64_butterflies;
 mod avx64_utils;
 
 mod avx_bluesteins;
+mod avx_good_thomas;
+#[cfg(feature = "half")]
+pub(crate) mod avx_half_precision;
 mod avx_mixed_radix;
 mod avx_raders;
+mod avx_split;
 
 pub mod avx_planner;
 
 pub use self::avx32_butterflies::{
-    Butterfly11Avx, Butterfly128Avx, Butterfly12Avx, Butterfly16Avx, Butterfly24Avx,
-    Butterfly256Avx, Butterfly27Avx, Butterfly32Avx, Butterfly36Avx, Butterfly48Avx,
+    Butterfly11Avx, Butterfly128Avx, Butterfly12Avx, Butterfly13Avx, Butterfly16Avx,
+    Butterfly17Avx, Butterfly19Avx, Butterfly23Avx, Butterfly24Avx, Butterfly256Avx,
+    Butterfly27Avx, Butterfly29Avx, Butterfly31Avx, Butterfly32Avx, Butterfly36Avx, Butterfly48Avx,
     Butterfly512Avx, Butterfly54Avx, Butterfly5Avx, Butterfly64Avx, Butterfly72Avx, Butterfly7Avx,
     Butterfly8Avx, Butterfly9Avx,
 };
 
 pub use self::avx64_butterflies::{
-    Butterfly11Avx64, Butterfly128Avx64, Butterfly12Avx64, Butterfly16Avx64, Butterfly18Avx64,
-    Butterfly24Avx64, Butterfly256Avx64, Butterfly27Avx64, Butterfly32Avx64, Butterfly36Avx64,
-    Butterfly512Avx64, Butterfly5Avx64, Butterfly64Avx64, Butterfly7Avx64, Butterfly8Avx64,
-    Butterfly9Avx64,
+    Butterfly11Avx64, Butterfly128Avx64, Butterfly12Avx64, Butterfly13Avx64, Butterfly16Avx64,
+    Butterfly17Avx64, Butterfly18Avx64, Butterfly19Avx64, Butterfly23Avx64, Butterfly24Avx64,
+    Butterfly256Avx64, Butterfly27Avx64, Butterfly29Avx64, Butterfly31Avx64, Butterfly32Avx64,
+    Butterfly36Avx64, Butterfly512Avx64, Butterfly5Avx64, Butterfly64Avx64, Butterfly7Avx64,
+    Butterfly8Avx64, Butterfly9Avx64,
 };
 
 pub use self::avx_bluesteins::BluesteinsAvx;
+pub use self::avx_good_thomas::GoodThomasAvx2;
 pub use self::avx_mixed_radix::{
-    MixedRadix11xnAvx, MixedRadix12xnAvx, MixedRadix16xnAvx, MixedRadix2xnAvx, MixedRadix3xnAvx,
-    MixedRadix4xnAvx, MixedRadix5xnAvx, MixedRadix6xnAvx, MixedRadix7xnAvx, MixedRadix8xnAvx,
-    MixedRadix9xnAvx,
+    MixedRadix11xnAvx, MixedRadix12xnAvx, MixedRadix13xnAvx, MixedRadix16xnAvx, MixedRadix17xnAvx,
+    MixedRadix19xnAvx, MixedRadix23xnAvx, MixedRadix29xnAvx, MixedRadix2xnAvx, MixedRadix31xnAvx,
+    MixedRadix3xnAvx, MixedRadix4xnAvx, MixedRadix5xnAvx, MixedRadix6xnAvx, MixedRadix7xnAvx,
+    MixedRadix8xnAvx, MixedRadix9xnAvx,
 };
 pub use self::avx_raders::RadersAvx2;
 use self::avx_vector::AvxVector256;
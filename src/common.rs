@@ -1,11 +1,90 @@
+use core::fmt::Debug;
 use num_traits::{FromPrimitive, Signed};
-use std::fmt::Debug;
+
+use crate::{Fft, FftDirection, FftError};
 
 /// Generic floating point number, implemented for f32 and f64
 pub trait FftNum: Copy + FromPrimitive + Signed + Sync + Send + Debug + 'static {}
 
 impl<T> FftNum for T where T: Copy + FromPrimitive + Signed + Sync + Send + Debug + 'static {}
 
+// Checks the buffer and scratch lengths passed to an in-place FFT algorithm's `process_with_scratch` method
+// `expected_len` must be nonzero
+pub fn validate_inplace(
+    expected_len: usize,
+    actual_len: usize,
+    expected_scratch: usize,
+    actual_scratch: usize,
+) -> Result<(), FftError> {
+    if actual_len < expected_len {
+        return Err(FftError::BufferTooSmall {
+            expected_len,
+            actual_len,
+        });
+    }
+    let remainder = actual_len % expected_len;
+    if remainder > 0 {
+        return Err(FftError::BufferNotMultiple {
+            expected_multiple: expected_len,
+            actual_len,
+        });
+    }
+    if actual_scratch < expected_scratch {
+        return Err(FftError::ScratchTooSmall {
+            expected_len: expected_scratch,
+            actual_len: actual_scratch,
+        });
+    }
+    Ok(())
+}
+
+// Checks the buffer and scratch lengths passed to a FFT algorithm's `process_outofplace_with_scratch` method
+// `expected_len` must be nonzero
+pub fn validate_outofplace(
+    expected_len: usize,
+    actual_input: usize,
+    actual_output: usize,
+    expected_scratch: usize,
+    actual_scratch: usize,
+) -> Result<(), FftError> {
+    if actual_input != actual_output {
+        return Err(FftError::MismatchedBuffers {
+            input_len: actual_input,
+            output_len: actual_output,
+        });
+    }
+    validate_inplace(expected_len, actual_input, expected_scratch, actual_scratch)
+}
+
+// Checks that the width and height FFTs passed to a two-dimensional FFT algorithm's constructor compute FFTs in the same direction
+pub fn validate_directions(
+    width_direction: FftDirection,
+    height_direction: FftDirection,
+) -> Result<(), FftError> {
+    if width_direction != height_direction {
+        return Err(FftError::MismatchedDirections {
+            width_direction,
+            height_direction,
+        });
+    }
+    Ok(())
+}
+
+// Checks that an inner FFT passed to MixedRadixSmall or GoodThomasAlgorithmSmall requires no out-of-place scratch,
+// and no more in-place scratch than its own length
+pub fn validate_small_inner_fft<T: FftNum>(fft: &dyn Fft<T>) -> Result<(), FftError> {
+    let inplace_scratch_len = fft.get_inplace_scratch_len();
+    let outofplace_scratch_len = fft.get_outofplace_scratch_len();
+    if outofplace_scratch_len > 0 || inplace_scratch_len > fft.len() {
+        return Err(FftError::InnerFftScratchTooLarge {
+            len: fft.len(),
+            inplace_scratch_len,
+            outofplace_scratch_len,
+        });
+    }
+    Ok(())
+}
+
 // Prints an error raised by an in-place FFT algorithm's `process_inplace` method
 // Marked cold and inline never to keep all formatting code out of the many monomorphized process_inplace methods
 #[cold]
@@ -16,25 +95,9 @@ pub fn fft_error_inplace(
     expected_scratch: usize,
     actual_scratch: usize,
 ) {
-    assert!(
-        actual_len >= expected_len,
-        "Provided FFT buffer was too small. Expected len = {}, got len = {}",
-        expected_len,
-        actual_len
-    );
-    assert_eq!(
-        actual_len % expected_len,
-        0,
-        "Input FFT buffer must be a multiple of FFT length. Expected multiple of {}, got len = {}",
-        expected_len,
-        actual_len
-    );
-    assert!(
-        actual_scratch >= expected_scratch,
-        "Not enough scratch space was provided. Expected scratch len >= {}, got scratch len = {}",
-        expected_scratch,
-        actual_scratch
-    );
+    if let Err(err) = validate_inplace(expected_len, actual_len, expected_scratch, actual_scratch) {
+        panic!("{}", err);
+    }
 }
 
 // Prints an error raised by an in-place FFT algorithm's `process_inplace` method
@@ -48,19 +111,41 @@ pub fn fft_error_outofplace(
     expected_scratch: usize,
     actual_scratch: usize,
 ) {
-    assert_eq!(actual_input, actual_output, "Provided FFT input buffer and output buffer must have the same length. Got input.len() = {}, output.len() = {}", actual_input, actual_output);
+    if let Err(err) = validate_outofplace(
+        expected_len,
+        actual_input,
+        actual_output,
+        expected_scratch,
+        actual_scratch,
+    ) {
+        panic!("{}", err);
+    }
+}
+
+// Prints an error raised by a FFT algorithm's `process_split` method
+// Marked cold and inline never to keep all formatting code out of the many monomorphized process_split methods
+#[cold]
+#[inline(never)]
+pub fn fft_error_split(
+    expected_len: usize,
+    actual_re: usize,
+    actual_im: usize,
+    expected_scratch: usize,
+    actual_scratch: usize,
+) {
+    assert_eq!(actual_re, actual_im, "Provided FFT real buffer and imaginary buffer must have the same length. Got re.len() = {}, im.len() = {}", actual_re, actual_im);
     assert!(
-        actual_input >= expected_len,
+        actual_re >= expected_len,
         "Provided FFT buffer was too small. Expected len = {}, got len = {}",
         expected_len,
-        actual_input
+        actual_re
     );
     assert_eq!(
-        actual_input % expected_len,
+        actual_re % expected_len,
         0,
         "Input FFT buffer must be a multiple of FFT length. Expected multiple of {}, got len = {}",
         expected_len,
-        actual_input
+        actual_re
     );
     assert!(
         actual_scratch >= expected_scratch,
@@ -138,6 +223,37 @@ macro_rules! boilerplate_fft_oop {
                     );
                 }
             }
+            fn process_immutable(
+                &self,
+                input: &[Complex<T>],
+                output: &mut [Complex<T>],
+                _scratch: &mut [Complex<T>],
+            ) {
+                if self.len() == 0 {
+                    return;
+                }
+
+                if input.len() < self.len() || output.len() != input.len() {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                    return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+                }
+
+                let result = array_utils::iter_chunks_zipped_immutable(
+                    input,
+                    output,
+                    self.len(),
+                    |in_chunk, out_chunk| {
+                        self.perform_fft_out_of_place(in_chunk, out_chunk, &mut [])
+                    },
+                );
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the FFT size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(self.len(), input.len(), output.len(), 0, 0);
+                }
+            }
             #[inline(always)]
             fn get_inplace_scratch_len(&self) -> usize {
                 self.len()
@@ -146,6 +262,10 @@ macro_rules! boilerplate_fft_oop {
             fn get_outofplace_scratch_len(&self) -> usize {
                 0
             }
+            #[inline(always)]
+            fn get_immutable_scratch_len(&self) -> usize {
+                0
+            }
         }
         impl<T> Length for $struct_name<T> {
             #[inline(always)]
@@ -163,7 +283,7 @@ macro_rules! boilerplate_fft_oop {
 }
 
 macro_rules! boilerplate_fft {
-    ($struct_name:ident, $len_fn:expr, $inplace_scratch_len_fn:expr, $out_of_place_scratch_len_fn:expr) => {
+    (@impl $struct_name:ident, $len_fn:expr, $inplace_scratch_len_fn:expr, $out_of_place_scratch_len_fn:expr, { $($extra_methods:tt)* }) => {
         impl<T: FftNum> Fft<T> for $struct_name<T> {
             fn process_outofplace_with_scratch(
                 &self,
@@ -254,6 +374,7 @@ macro_rules! boilerplate_fft {
             fn get_outofplace_scratch_len(&self) -> usize {
                 $out_of_place_scratch_len_fn(self)
             }
+            $($extra_methods)*
         }
         impl<T: FftNum> Length for $struct_name<T> {
             #[inline(always)]
@@ -268,4 +389,65 @@ macro_rules! boilerplate_fft {
             }
         }
     };
+    ($struct_name:ident, $len_fn:expr, $inplace_scratch_len_fn:expr, $out_of_place_scratch_len_fn:expr) => {
+        boilerplate_fft!(@impl $struct_name, $len_fn, $inplace_scratch_len_fn, $out_of_place_scratch_len_fn, {});
+    };
+    // Algorithms that can compute a FFT without writing to their input can pass a fifth closure, returning their `process_immutable` scratch len.
+    // They must implement `perform_fft_immutable`, which will be used instead of the default copy-then-process-in-place implementation.
+    ($struct_name:ident, $len_fn:expr, $inplace_scratch_len_fn:expr, $out_of_place_scratch_len_fn:expr, $immutable_scratch_len_fn:expr) => {
+        boilerplate_fft!(@impl $struct_name, $len_fn, $inplace_scratch_len_fn, $out_of_place_scratch_len_fn, {
+            fn process_immutable(
+                &self,
+                input: &[Complex<T>],
+                output: &mut [Complex<T>],
+                scratch: &mut [Complex<T>],
+            ) {
+                if self.len() == 0 {
+                    return;
+                }
+
+                let required_scratch = self.get_immutable_scratch_len();
+                if scratch.len() < required_scratch
+                    || input.len() < self.len()
+                    || output.len() != input.len()
+                {
+                    // We want to trigger a panic, but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(
+                        self.len(),
+                        input.len(),
+                        output.len(),
+                        self.get_immutable_scratch_len(),
+                        scratch.len(),
+                    );
+                    return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+                }
+
+                let scratch = &mut scratch[..required_scratch];
+                let result = array_utils::iter_chunks_zipped_immutable(
+                    input,
+                    output,
+                    self.len(),
+                    |in_chunk, out_chunk| {
+                        self.perform_fft_immutable(in_chunk, out_chunk, scratch)
+                    },
+                );
+
+                if result.is_err() {
+                    // We want to trigger a panic, because the buffer sizes weren't cleanly divisible by the FFT size,
+                    // but we want to avoid doing it in this function to reduce code size, so call a function marked cold and inline(never) that will do it for us
+                    fft_error_outofplace(
+                        self.len(),
+                        input.len(),
+                        output.len(),
+                        self.get_immutable_scratch_len(),
+                        scratch.len(),
+                    );
+                }
+            }
+            #[inline(always)]
+            fn get_immutable_scratch_len(&self) -> usize {
+                $immutable_scratch_len_fn(self)
+            }
+        });
+    };
 }
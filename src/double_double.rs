@@ -0,0 +1,479 @@
+use core::cmp::Ordering;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use num_complex::Complex;
+use num_traits::{Float, FromPrimitive, Num, One, Signed, ToPrimitive, Zero};
+
+use crate::{FftDirection, FftNormalization};
+
+/// An extended-precision floating point number, represented as the unevaluated sum of two f64 values.
+///
+/// A `DoubleDouble` carries about 106 bits of mantissa, giving roughly 32 significant decimal digits, with the same
+/// exponent range as a f64. It implements [`FftNum`](crate::FftNum), so it can be used with `FftPlanner` and with every
+/// scalar FFT algorithm.
+///
+/// Twiddle factors and normalization scale factors for `DoubleDouble` FFTs are computed in double-double arithmetic, so
+/// they're accurate to the full precision of the type rather than to the precision of a f64.
+///
+/// ~~~
+/// use rustfft::{FftPlanner, num_complex::Complex};
+/// use rustfft::double_double::DoubleDouble;
+///
+/// let mut planner = FftPlanner::<DoubleDouble>::new();
+/// let fft = planner.plan_fft_forward(1234);
+///
+/// let mut buffer = vec![Complex{ re: DoubleDouble::from(1.0), im: DoubleDouble::from(0.0) }; 1234];
+/// fft.process(&mut buffer);
+/// ~~~
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+// Computes the sum of a and b, and the rounding error of that sum, exactly
+#[inline(always)]
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+// Same as two_sum, but requires that |a| >= |b|
+#[inline(always)]
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    (sum, b - (sum - a))
+}
+
+// Splits a into two non-overlapping halves with 26 significant bits each
+#[inline(always)]
+fn split(a: f64) -> (f64, f64) {
+    const SPLITTER: f64 = 134217729.0; // 2^27 + 1
+    let t = SPLITTER * a;
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+// Computes the product of a and b, and the rounding error of that product, exactly
+#[inline(always)]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let error = ((a_hi * b_hi - product) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    (product, error)
+}
+
+impl DoubleDouble {
+    /// Pi, accurate to the full precision of a `DoubleDouble`
+    pub const PI: Self = Self {
+        hi: core::f64::consts::PI,
+        lo: 1.2246467991473532e-16,
+    };
+
+    const FRAC_PI_4: Self = Self {
+        hi: core::f64::consts::FRAC_PI_4,
+        lo: 3.061616997868383e-17,
+    };
+
+    /// Creates a `DoubleDouble` from the unevaluated sum `hi + lo`. The two halves don't need to be normalized.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        Self { hi, lo }
+    }
+
+    /// Returns the leading f64 of this value, which is this value rounded to the nearest f64
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Returns the trailing f64 of this value, which holds the rounding error of `hi()`
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Returns the square root of this value. Returns NaN if this value is negative.
+    pub fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            // Zero stays zero, and negative values produce NaN
+            return Self::from(Float::sqrt(self.hi));
+        }
+
+        // One Newton step from the f64 approximation doubles the number of correct bits
+        let inverse_sqrt = 1.0 / Float::sqrt(self.hi);
+        let approximation = self.hi * inverse_sqrt;
+        let (square, square_error) = two_prod(approximation, approximation);
+        let residual = self - Self::new(square, square_error);
+        let (hi, lo) = quick_two_sum(approximation, residual.hi * (inverse_sqrt * 0.5));
+        Self { hi, lo }
+    }
+
+    /// Returns the integer part of this value, rounding towards zero
+    pub fn trunc(self) -> Self {
+        let hi = Float::trunc(self.hi);
+        if hi != self.hi {
+            // The low half is too small to move the value across an integer boundary
+            return Self { hi, lo: 0.0 };
+        }
+        let lo = if self.hi > 0.0 {
+            Float::floor(self.lo)
+        } else {
+            Float::ceil(self.lo)
+        };
+        Self::new(hi, lo)
+    }
+
+    // Computes (sin(angle), cos(angle)) with a Taylor series. The angle must be in [0, pi/4] for the series to converge quickly.
+    fn sin_cos_taylor(angle: Self) -> (Self, Self) {
+        let threshold = 1e-33;
+        let angle_squared = angle * angle;
+
+        let mut sin = angle;
+        let mut cos = Self::one();
+        let mut sin_term = angle;
+        let mut cos_term = Self::one();
+        let mut k = 1;
+        while Float::abs(cos_term.hi) >= threshold {
+            cos_term = -cos_term * angle_squared / Self::from((2 * k - 1) * (2 * k));
+            sin_term = -sin_term * angle_squared / Self::from((2 * k) * (2 * k + 1));
+            cos += cos_term;
+            sin += sin_term;
+            k += 1;
+        }
+        (sin, cos)
+    }
+}
+
+// The `TwiddlePrecision` implementation for `DoubleDouble` calls these, instead of computing values in f64 and converting them
+impl DoubleDouble {
+    // Returns e^(-2 * pi * i * index / fft_len), accurate to double-double precision
+    pub(crate) fn precise_forward_twiddle(index: usize, fft_len: usize) -> Complex<Self> {
+        // Reduce the angle to the first octant with integer arithmetic, so that the reduction itself is exact.
+        // The angle is 2pi * index / fft_len = pi/4 * (8 * index / fft_len)
+        let fft_len = fft_len as u128;
+        let scaled_index = (index as u128 % fft_len) * 8;
+        let octant = scaled_index / fft_len;
+        let remainder = scaled_index % fft_len;
+
+        // The angle is pi/4 * (octant + remainder / fft_len). For odd octants, measure the offset from the end of the octant
+        // instead, so that the offset is always in [0, pi/4] and the Taylor series converges quickly
+        let (quadrant, offset_numerator, offset_sign) = if octant & 1 == 0 {
+            (octant / 2, remainder, Self::one())
+        } else {
+            (octant / 2 + 1, fft_len - remainder, -Self::one())
+        };
+        let offset = Self::FRAC_PI_4 * Self::from_u128(offset_numerator).unwrap()
+            / Self::from_u128(fft_len).unwrap();
+        let (offset_sin, cos) = Self::sin_cos_taylor(offset);
+        let sin = offset_sin * offset_sign;
+
+        // Rotate the offset by the quadrant, then negate the imaginary part for a forward twiddle
+        let (cos, sin) = match quadrant % 4 {
+            0 => (cos, sin),
+            1 => (-sin, cos),
+            2 => (-cos, -sin),
+            _ => (sin, -cos),
+        };
+        Complex { re: cos, im: -sin }
+    }
+
+    // Returns the same value as `normalization.scale_factor(len, direction)`, accurate to double-double precision
+    pub(crate) fn precise_scale_factor(
+        normalization: FftNormalization,
+        len: usize,
+        direction: FftDirection,
+    ) -> Self {
+        if normalization.scale_factor(len, direction) == 1.0 {
+            return Self::one();
+        }
+        let len = Self::from_usize(len).unwrap();
+        match normalization {
+            FftNormalization::Unitary => Self::one() / len.sqrt(),
+            _ => Self::one() / len,
+        }
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+}
+impl From<f32> for DoubleDouble {
+    fn from(value: f32) -> Self {
+        Self::from(value as f64)
+    }
+}
+impl From<i32> for DoubleDouble {
+    fn from(value: i32) -> Self {
+        Self::from(value as f64)
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        let (sum, mut error) = two_sum(self.hi, rhs.hi);
+        let (lo_sum, lo_error) = two_sum(self.lo, rhs.lo);
+        error += lo_sum;
+        let (sum, mut error) = quick_two_sum(sum, error);
+        error += lo_error;
+        let (hi, lo) = quick_two_sum(sum, error);
+        Self { hi, lo }
+    }
+}
+impl Sub for DoubleDouble {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+impl Mul for DoubleDouble {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        let (product, mut error) = two_prod(self.hi, rhs.hi);
+        error += self.hi * rhs.lo + self.lo * rhs.hi;
+        let (hi, lo) = quick_two_sum(product, error);
+        Self { hi, lo }
+    }
+}
+impl Div for DoubleDouble {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        // Long division, computing one f64 of the quotient at a time
+        let q1 = self.hi / rhs.hi;
+        let remainder = self - rhs * Self::from(q1);
+        let q2 = remainder.hi / rhs.hi;
+        let remainder = remainder - rhs * Self::from(q2);
+        let q3 = remainder.hi / rhs.hi;
+
+        let (hi, lo) = quick_two_sum(q1, q2);
+        Self { hi, lo } + Self::from(q3)
+    }
+}
+impl Rem for DoubleDouble {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self {
+        self - rhs * (self / rhs).trunc()
+    }
+}
+impl Neg for DoubleDouble {
+    type Output = Self;
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($trait_name:ident, $fn_name:ident, $op:tt) => {
+        impl $trait_name for DoubleDouble {
+            fn $fn_name(&mut self, rhs: Self) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+impl_assign_op!(AddAssign, add_assign, +);
+impl_assign_op!(SubAssign, sub_assign, -);
+impl_assign_op!(MulAssign, mul_assign, *);
+impl_assign_op!(DivAssign, div_assign, /);
+impl_assign_op!(RemAssign, rem_assign, %);
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self {
+        Self::from(0.0)
+    }
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+impl One for DoubleDouble {
+    fn one() -> Self {
+        Self::from(1.0)
+    }
+}
+impl Num for DoubleDouble {
+    type FromStrRadixErr = <f64 as Num>::FromStrRadixErr;
+
+    /// Parses the string as a f64, so the result only has the precision of a f64
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        f64::from_str_radix(s, radix).map(Self::from)
+    }
+}
+impl Signed for DoubleDouble {
+    fn abs(&self) -> Self {
+        if self.is_negative() {
+            -*self
+        } else {
+            *self
+        }
+    }
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            Self::zero()
+        } else {
+            *self - *other
+        }
+    }
+    fn signum(&self) -> Self {
+        match self.partial_cmp(&Self::zero()) {
+            Some(Ordering::Greater) => Self::one(),
+            Some(Ordering::Less) => -Self::one(),
+            _ => *self,
+        }
+    }
+    fn is_positive(&self) -> bool {
+        self.hi > 0.0
+    }
+    fn is_negative(&self) -> bool {
+        self.hi < 0.0
+    }
+}
+
+impl FromPrimitive for DoubleDouble {
+    fn from_i64(n: i64) -> Option<Self> {
+        Self::from_i128(n as i128)
+    }
+    fn from_u64(n: u64) -> Option<Self> {
+        Self::from_u128(n as u128)
+    }
+    fn from_i128(n: i128) -> Option<Self> {
+        let hi = n as f64;
+        // hi is within 2^-53 relative of n, so for any n that fits in 106 bits the rounding error is exact as a f64
+        let lo = n.wrapping_sub(hi as i128) as f64;
+        Some(Self::new(hi, lo))
+    }
+    fn from_u128(n: u128) -> Option<Self> {
+        let hi = n as f64;
+        let lo = if hi as u128 > n {
+            -(((hi as u128) - n) as f64)
+        } else {
+            (n - hi as u128) as f64
+        };
+        Some(Self::new(hi, lo))
+    }
+    fn from_f32(n: f32) -> Option<Self> {
+        Some(Self::from(n))
+    }
+    fn from_f64(n: f64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+}
+
+impl ToPrimitive for DoubleDouble {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|n| n.to_i64())
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.to_i128().and_then(|n| n.to_u64())
+    }
+    fn to_i128(&self) -> Option<i128> {
+        let truncated = self.trunc();
+        let hi = truncated.hi.to_i128()?;
+        hi.checked_add(truncated.lo.to_i128()?)
+    }
+    fn to_f64(&self) -> Option<f64> {
+        Some(self.hi + self.lo)
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::twiddles;
+    use crate::FftDirection;
+
+    fn assert_close(actual: DoubleDouble, expected: DoubleDouble, tolerance: f64) {
+        let error = (actual - expected).abs();
+        assert!(
+            error.hi <= tolerance,
+            "actual = {:?}, expected = {:?}, error = {:?}",
+            actual,
+            expected,
+            error
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let one = DoubleDouble::one();
+        let three = DoubleDouble::from(3.0);
+        let third = one / three;
+
+        // 1/3 isn't representable as a f64, so the low half has to carry the rest of the digits
+        assert!(third.lo() != 0.0);
+        assert_close(third * three, one, 1e-31);
+        assert_close(third + third + third, one, 1e-31);
+        assert_close((one - third) - third, third, 1e-31);
+
+        let two = DoubleDouble::from(2.0);
+        let root2 = two.sqrt();
+        assert_close(root2 * root2, two, 1e-31);
+
+        assert_close(DoubleDouble::from(7.5) % two, DoubleDouble::from(1.5), 0.0);
+        assert_close(
+            DoubleDouble::from(-7.5) % two,
+            DoubleDouble::from(-1.5),
+            0.0,
+        );
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        // u64::MAX isn't representable as a f64, so the low half has to carry the difference
+        let max = DoubleDouble::from_u64(core::u64::MAX).unwrap();
+        assert_eq!(max.to_u64(), Some(core::u64::MAX));
+        let min = DoubleDouble::from_i64(core::i64::MIN + 1).unwrap();
+        assert_eq!(min.to_i64(), Some(core::i64::MIN + 1));
+
+        let near_integer = DoubleDouble::new(5.0, -1e-20);
+        assert_eq!(near_integer.to_i64(), Some(4));
+        let near_negative_integer = DoubleDouble::new(-5.0, 1e-20);
+        assert_eq!(near_negative_integer.to_i64(), Some(-4));
+    }
+
+    #[test]
+    fn test_twiddles() {
+        for &len in &[1, 2, 3, 5, 7, 8, 12, 31, 100, 1000, 65537] {
+            for index in 0..len.min(50) {
+                let twiddle: Complex<DoubleDouble> =
+                    twiddles::compute_twiddle(index, len, FftDirection::Forward);
+                let magnitude = twiddle.re * twiddle.re + twiddle.im * twiddle.im;
+                assert_close(magnitude, DoubleDouble::one(), 1e-31);
+
+                // the f64 twiddle should match to f64 precision
+                let twiddle_f64: Complex<f64> =
+                    twiddles::compute_twiddle(index, len, FftDirection::Forward);
+                assert!((twiddle.re.to_f64().unwrap() - twiddle_f64.re).abs() < 1e-15);
+                assert!((twiddle.im.to_f64().unwrap() - twiddle_f64.im).abs() < 1e-15);
+
+                let inverse: Complex<DoubleDouble> =
+                    twiddles::compute_twiddle(index, len, FftDirection::Inverse);
+                assert_eq!(inverse, twiddle.conj());
+            }
+        }
+
+        // Check a few angles with known exact values
+        let half = DoubleDouble::from(0.5);
+        let twiddle: Complex<DoubleDouble> = twiddles::compute_twiddle(1, 6, FftDirection::Forward);
+        assert_close(twiddle.re, half, 1e-31);
+        assert_close(twiddle.im, -(DoubleDouble::from(3.0).sqrt() * half), 1e-31);
+
+        let twiddle: Complex<DoubleDouble> = twiddles::compute_twiddle(3, 8, FftDirection::Forward);
+        let root_half = half.sqrt();
+        assert_close(twiddle.re, -root_half, 1e-31);
+        assert_close(twiddle.im, -root_half, 1e-31);
+
+        let twiddle: Complex<DoubleDouble> =
+            twiddles::compute_twiddle(5, 12, FftDirection::Forward);
+        assert_close(twiddle.re, -(DoubleDouble::from(3.0).sqrt() * half), 1e-31);
+        assert_close(twiddle.im, -half, 1e-31);
+    }
+}
@@ -1,39 +1,513 @@
-use std::{collections::HashMap, sync::Arc};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+use core::mem::size_of;
 
-use crate::{Fft, FftDirection};
+use num_complex::Complex;
 
+use crate::algorithm::{BluesteinsAlgorithm, MixedRadix, Radix4, Radix8};
+use crate::twiddles::TwiddleView;
+use crate::{Fft, FftDirection, FftNum};
+
+// A map that keeps track of the order its entries were used in, so that it can evict the least recently used entry.
+// Both `get` and `insert` count as a use
+pub(crate) struct LruMap<K, V> {
+    entries: BTreeMap<K, (V, u64)>,
+    usage_order: BTreeMap<u64, K>,
+    next_use: u64,
+}
+impl<K: Ord + Copy, V> LruMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            usage_order: BTreeMap::new(),
+            next_use: 0,
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let entry = self.entries.get_mut(key)?;
+        self.usage_order.remove(&entry.1);
+        self.usage_order.insert(self.next_use, *key);
+        entry.1 = self.next_use;
+        self.next_use += 1;
+        Some(&entry.0)
+    }
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let previous = self.remove(&key);
+        self.entries.insert(key, (value, self.next_use));
+        self.usage_order.insert(self.next_use, key);
+        self.next_use += 1;
+        previous
+    }
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, last_use) = self.entries.remove(key)?;
+        self.usage_order.remove(&last_use);
+        Some(value)
+    }
+    pub fn remove_least_recently_used(&mut self) -> Option<(K, V)> {
+        let key = *self.usage_order.values().next()?;
+        self.remove(&key).map(|value| (key, value))
+    }
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.usage_order.clear();
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct FftKey {
+    len: usize,
+    inverse: bool,
+}
+impl FftKey {
+    fn new(len: usize, direction: FftDirection) -> Self {
+        Self {
+            len,
+            inverse: direction == FftDirection::Inverse,
+        }
+    }
+}
+
+// Caches FFT instances by length and direction. If the cache holds more instances or more memory than its capacity allows,
+// the least recently used instances are evicted. An instance's memory is estimated as one complex number per element,
+// which is roughly the size of the twiddle factors most algorithms precompute. The memory of the tables that the planner
+// shares between instances isn't included here, so the planners evict more instances with `evict_least_recently_used` to fit it
 pub(crate) struct FftCache<T> {
-    forward_cache: HashMap<usize, Arc<dyn Fft<T>>>,
-    inverse_cache: HashMap<usize, Arc<dyn Fft<T>>>,
+    cache: LruMap<FftKey, Arc<dyn Fft<T>>>,
+    memory: usize,
+    max_entries: Option<usize>,
+    max_memory: Option<usize>,
 }
 impl<T> FftCache<T> {
     pub fn new() -> Self {
         Self {
-            forward_cache: HashMap::new(),
-            inverse_cache: HashMap::new(),
+            cache: LruMap::new(),
+            memory: 0,
+            max_entries: None,
+            max_memory: None,
         }
     }
-    #[allow(unused)]
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
     pub fn contains_fft(&self, len: usize, direction: FftDirection) -> bool {
-        match direction {
-            FftDirection::Forward => self.forward_cache.contains_key(&len),
-            FftDirection::Inverse => self.inverse_cache.contains_key(&len),
+        self.cache.contains_key(&FftKey::new(len, direction))
+    }
+    pub fn get(&mut self, len: usize, direction: FftDirection) -> Option<Arc<dyn Fft<T>>> {
+        self.cache.get(&FftKey::new(len, direction)).map(Arc::clone)
+    }
+    pub fn insert(&mut self, fft: &Arc<dyn Fft<T>>) {
+        let len = fft.len();
+        let fft_memory = Self::estimate_memory(len);
+
+        // Don't evict everything else to make room for an instance that doesn't fit anyways
+        if self.max_entries == Some(0) || exceeds(fft_memory, self.max_memory) {
+            return;
+        }
+
+        let key = FftKey::new(len, fft.fft_direction());
+        if self.cache.insert(key, Arc::clone(fft)).is_none() {
+            self.memory += fft_memory;
         }
+        self.shrink_to_capacity();
     }
-    pub fn get(&self, len: usize, direction: FftDirection) -> Option<Arc<dyn Fft<T>>> {
-        match direction {
-            FftDirection::Forward => self.forward_cache.get(&len),
-            FftDirection::Inverse => self.inverse_cache.get(&len),
+
+    // Removes both directions of `len` from the cache
+    pub fn evict(&mut self, len: usize) {
+        for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+            if self.cache.remove(&FftKey::new(len, direction)).is_some() {
+                self.memory -= Self::estimate_memory(len);
+            }
         }
-        .map(Arc::clone)
     }
-    pub fn insert(&mut self, fft: &Arc<dyn Fft<T>>) {
-        let cloned = Arc::clone(fft);
-        let len = cloned.len();
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.memory = 0;
+    }
+
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+    pub fn memory(&self) -> usize {
+        self.memory
+    }
+    pub fn is_bounded(&self) -> bool {
+        self.max_entries.is_some() || self.max_memory.is_some()
+    }
+    pub fn set_max_entries(&mut self, max_entries: Option<usize>) {
+        self.max_entries = max_entries;
+        self.shrink_to_capacity();
+    }
+    pub fn set_max_memory(&mut self, max_memory: Option<usize>) {
+        self.max_memory = max_memory;
+        self.shrink_to_capacity();
+    }
+    pub fn max_memory(&self) -> Option<usize> {
+        self.max_memory
+    }
+
+    // Evicts the least recently used instance. Returns false if the cache was empty
+    pub fn evict_least_recently_used(&mut self) -> bool {
+        match self.cache.remove_least_recently_used() {
+            Some((key, _)) => {
+                self.memory -= Self::estimate_memory(key.len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn shrink_to_capacity(&mut self) {
+        while exceeds(self.cache.len(), self.max_entries) || exceeds(self.memory, self.max_memory) {
+            if !self.evict_least_recently_used() {
+                break;
+            }
+        }
+    }
+    fn estimate_memory(len: usize) -> usize {
+        len.saturating_mul(size_of::<Complex<T>>())
+    }
+}
 
-        match cloned.fft_direction() {
-            FftDirection::Forward => self.forward_cache.insert(len, cloned),
-            FftDirection::Inverse => self.inverse_cache.insert(len, cloned),
+// Returns true if `value` is over `limit`. A limit of `None` means unlimited
+pub(crate) fn exceeds(value: usize, limit: Option<usize>) -> bool {
+    match limit {
+        Some(limit) => value > limit,
+        None => false,
+    }
+}
+
+// Caches the chirp spectra of Bluestein's algorithm, keyed by FFT length and inner FFT length.
+// A spectrum doesn't depend on the FFT direction, so forward and inverse FFTs of the same length share one.
+pub(crate) struct ChirpSpectrumCache<T> {
+    cache: BTreeMap<(usize, usize), Arc<[Complex<T>]>>,
+}
+impl<T: FftNum> ChirpSpectrumCache<T> {
+    pub fn new() -> Self {
+        Self {
+            cache: BTreeMap::new(),
+        }
+    }
+    #[cfg(test)]
+    pub fn spectrum_count(&self) -> usize {
+        self.cache.len()
+    }
+    pub fn get_or_compute(&mut self, len: usize, inner_fft: &dyn Fft<T>) -> Arc<[Complex<T>]> {
+        let spectrum = self
+            .cache
+            .entry((len, inner_fft.len()))
+            .or_insert_with(|| BluesteinsAlgorithm::compute_chirp_spectrum(len, inner_fft));
+        Arc::clone(spectrum)
+    }
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+    // Drops the spectra that no FFT instance uses anymore
+    pub fn release_unused(&mut self) {
+        release_unused_tables(&mut self.cache);
+    }
+    // Returns the size in bytes of the cached spectra
+    pub fn memory(&self) -> usize {
+        tables_memory(&self.cache)
+    }
+}
+
+// Removes every table from `tables` that isn't referenced anywhere else
+fn release_unused_tables<K: Ord + Copy, V: ?Sized>(tables: &mut BTreeMap<K, Arc<V>>) {
+    let unused: Vec<K> = tables
+        .iter()
+        .filter(|(_, table)| Arc::strong_count(table) == 1)
+        .map(|(key, _)| *key)
+        .collect();
+    for key in unused {
+        tables.remove(&key);
+    }
+}
+
+// Returns the combined size in bytes of the tables in `tables`
+fn tables_memory<K, T>(tables: &BTreeMap<K, Arc<[Complex<T>]>>) -> usize {
+    tables
+        .values()
+        .map(|table| table.len() * size_of::<Complex<T>>())
+        .sum()
+}
+
+/// Statistics about the twiddle factor tables a planner has handed out to the FFT instances it created.
+///
+/// The planner shares twiddle factor tables between FFT instances that can use the same table: `Radix4` instances of different sizes
+/// share one table if they have the same base length, and so do `Radix8` instances. Mixed radix steps, including the AVX ones, share the table of any earlier mixed radix step
+/// whose width and height are multiples of theirs, reading every n-th twiddle factor of it. These statistics report how much memory that sharing saved.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub struct TwiddleStats {
+    requests: usize,
+    shared_requests: usize,
+    computed_tables: usize,
+    computed_bytes: usize,
+    requested_bytes: usize,
+}
+impl TwiddleStats {
+    /// Returns the number of twiddle factor tables that FFT instances requested from the planner
+    pub fn requests(&self) -> usize {
+        self.requests
+    }
+    /// Returns the number of requests that were served by sharing a table that was computed earlier
+    pub fn shared_requests(&self) -> usize {
+        self.shared_requests
+    }
+    /// Returns the number of twiddle factor tables the planner computed
+    pub fn computed_tables(&self) -> usize {
+        self.computed_tables
+    }
+    /// Returns the total size in bytes of the twiddle factor tables the planner computed
+    pub fn computed_bytes(&self) -> usize {
+        self.computed_bytes
+    }
+    /// Returns the total size in bytes of the twiddle factors the FFT instances use. Without sharing, each instance would own a table of this combined size
+    pub fn requested_bytes(&self) -> usize {
+        self.requested_bytes
+    }
+    /// Returns the number of bytes saved by sharing twiddle factor tables between FFT instances
+    ///
+    /// When a larger `Radix4` or `Radix8` table replaces a smaller one, the smaller table is still counted as computed, so this is a lower bound.
+    pub fn bytes_saved(&self) -> usize {
+        self.requested_bytes.saturating_sub(self.computed_bytes)
+    }
+
+    fn record_request(&mut self, bytes: usize) {
+        self.requests += 1;
+        self.requested_bytes += bytes;
+    }
+    fn record_computed(&mut self, bytes: usize) {
+        self.computed_tables += 1;
+        self.computed_bytes += bytes;
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum TwiddleKey {
+    Radix4 { base_len: usize },
+    Radix8 { base_len: usize },
+    MixedRadix { width: usize, height: usize },
+}
+
+// Hands out twiddle factor tables, sharing them between FFT instances whenever they can use the same table.
+// Twiddle factors depend on the FFT direction, so forward and inverse FFTs never share a table
+pub(crate) struct TwiddleStore<T> {
+    forward_tables: BTreeMap<TwiddleKey, Arc<[Complex<T>]>>,
+    inverse_tables: BTreeMap<TwiddleKey, Arc<[Complex<T>]>>,
+    stats: TwiddleStats,
+}
+impl<T: FftNum> TwiddleStore<T> {
+    pub fn new() -> Self {
+        Self {
+            forward_tables: BTreeMap::new(),
+            inverse_tables: BTreeMap::new(),
+            stats: TwiddleStats::default(),
+        }
+    }
+    pub fn stats(&self) -> TwiddleStats {
+        self.stats
+    }
+    pub fn clear(&mut self) {
+        self.forward_tables.clear();
+        self.inverse_tables.clear();
+    }
+    // Drops the tables that no FFT instance uses anymore
+    pub fn release_unused(&mut self) {
+        release_unused_tables(&mut self.forward_tables);
+        release_unused_tables(&mut self.inverse_tables);
+    }
+    // Returns the size in bytes of the stored tables
+    pub fn memory(&self) -> usize {
+        tables_memory(&self.forward_tables) + tables_memory(&self.inverse_tables)
+    }
+
+    // Returns twiddles for `Radix4::new_with_twiddles`. The twiddles of a `Radix4` instance are a prefix of the twiddles of every larger instance
+    // with the same base length, so we only keep the largest table for each base length
+    pub fn radix4_twiddles(&mut self, len: usize, direction: FftDirection) -> Arc<[Complex<T>]> {
+        let key = TwiddleKey::Radix4 {
+            base_len: Radix4::<T>::base_len(len),
         };
+        let needed_len = Radix4::<T>::twiddle_len(len);
+        self.get_or_compute(key, direction, needed_len, || {
+            Radix4::compute_twiddles(len, direction)
+        })
+    }
+
+    // Returns twiddles for `Radix8::new_with_twiddles`. Like `Radix4` tables, we only keep the largest table for each base length
+    pub fn radix8_twiddles(&mut self, len: usize, direction: FftDirection) -> Arc<[Complex<T>]> {
+        let key = TwiddleKey::Radix8 {
+            base_len: Radix8::<T>::base_len(len),
+        };
+        let needed_len = Radix8::<T>::twiddle_len(len);
+        self.get_or_compute(key, direction, needed_len, || {
+            Radix8::compute_twiddles(len, direction)
+        })
+    }
+
+    // Returns twiddles for `MixedRadix::new_with_twiddles` and `MixedRadixSmall::new_with_twiddles`
+    pub fn mixed_radix_twiddles(
+        &mut self,
+        width: usize,
+        height: usize,
+        direction: FftDirection,
+    ) -> TwiddleView<T> {
+        self.get_or_compute_mixed_radix(width, height, direction, false)
+    }
+
+    // Returns twiddles for the AVX mixed radix steps with `rows` rows of `len_per_row`. They load several consecutive twiddles of a row at once,
+    // so they can only share a table with a step that has the same number of columns
+    #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+    pub fn avx_mixed_radix_twiddles(
+        &mut self,
+        rows: usize,
+        len_per_row: usize,
+        direction: FftDirection,
+    ) -> TwiddleView<T> {
+        self.get_or_compute_mixed_radix(rows, len_per_row, direction, true)
+    }
+
+    // Returns a view of the first table we have whose width and height are multiples of `width` and `height`, or computes a new one
+    fn get_or_compute_mixed_radix(
+        &mut self,
+        width: usize,
+        height: usize,
+        direction: FftDirection,
+        contiguous_rows: bool,
+    ) -> TwiddleView<T> {
+        let tables = match direction {
+            FftDirection::Forward => &mut self.forward_tables,
+            FftDirection::Inverse => &mut self.inverse_tables,
+        };
+        self.stats
+            .record_request(width * height * size_of::<Complex<T>>());
+
+        let key = TwiddleKey::MixedRadix { width, height };
+        let shared_size = if tables.contains_key(&key) {
+            Some((width, height))
+        } else {
+            tables.keys().find_map(|table_key| match *table_key {
+                TwiddleKey::MixedRadix {
+                    width: table_width,
+                    height: table_height,
+                } if table_width % width == 0
+                    && table_height % height == 0
+                    && (table_height == height || !contiguous_rows) =>
+                {
+                    Some((table_width, table_height))
+                }
+                _ => None,
+            })
+        };
+        if let Some((table_width, table_height)) = shared_size {
+            let table_key = TwiddleKey::MixedRadix {
+                width: table_width,
+                height: table_height,
+            };
+            self.stats.shared_requests += 1;
+            return TwiddleView::strided(
+                Arc::clone(&tables[&table_key]),
+                table_width,
+                table_height,
+                width,
+                height,
+            );
+        }
+
+        let table = MixedRadix::compute_twiddles(width, height, direction);
+        self.stats
+            .record_computed(table.len() * size_of::<Complex<T>>());
+        tables.insert(key, Arc::clone(&table));
+        TwiddleView::new(table, width, height)
+    }
+
+    fn get_or_compute(
+        &mut self,
+        key: TwiddleKey,
+        direction: FftDirection,
+        needed_len: usize,
+        compute: impl FnOnce() -> Arc<[Complex<T>]>,
+    ) -> Arc<[Complex<T>]> {
+        let tables = match direction {
+            FftDirection::Forward => &mut self.forward_tables,
+            FftDirection::Inverse => &mut self.inverse_tables,
+        };
+        self.stats
+            .record_request(needed_len * size_of::<Complex<T>>());
+
+        if let Some(table) = tables.get(&key) {
+            if table.len() >= needed_len {
+                self.stats.shared_requests += 1;
+                return Arc::clone(table);
+            }
+        }
+
+        let table = compute();
+        self.stats
+            .record_computed(table.len() * size_of::<Complex<T>>());
+        tables.insert(key, Arc::clone(&table));
+        table
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_map() {
+        let mut map = LruMap::new();
+        for key in 0..4 {
+            assert!(map.insert(key, key * 10).is_none());
+        }
+        assert_eq!(map.len(), 4);
+
+        // Using an entry should move it to the back of the eviction order
+        assert_eq!(map.get(&0), Some(&0));
+        assert_eq!(map.remove_least_recently_used(), Some((1, 10)));
+
+        // Replacing an entry counts as a use too
+        assert_eq!(map.insert(2, 21), Some(20));
+        assert_eq!(map.remove_least_recently_used(), Some((3, 30)));
+
+        assert_eq!(map.remove(&0), Some(0));
+        assert!(!map.contains_key(&0));
+        assert_eq!(map.remove_least_recently_used(), Some((2, 21)));
+        assert_eq!(map.remove_least_recently_used(), None);
+        assert_eq!(map.len(), 0);
+    }
+    #[test]
+    fn test_twiddle_store_strided_views() {
+        use crate::algorithm::{Dft, MixedRadix};
+        use crate::test_utils::check_fft_algorithm;
+        use crate::{Fft, FftDirection};
+
+        let direction = FftDirection::Forward;
+        let mut store = TwiddleStore::<f64>::new();
+        let _large = store.mixed_radix_twiddles(8, 12, direction);
+        assert_eq!(store.stats().computed_tables(), 1);
+
+        // A smaller step whose width and height divide the larger table's should read every other row and column of it
+        let view = store.mixed_radix_twiddles(4, 6, direction);
+        assert_eq!(store.stats().computed_tables(), 1);
+        assert_eq!((view.row_stride(), view.column_stride()), (24, 2));
+
+        let width_fft = Arc::new(Dft::new(4, direction)) as Arc<dyn Fft<f64>>;
+        let height_fft = Arc::new(Dft::new(6, direction)) as Arc<dyn Fft<f64>>;
+        let fft = MixedRadix::new_with_twiddles(width_fft, height_fft, view);
+        check_fft_algorithm(&fft, 24, direction);
+
+        // The AVX steps need contiguous rows, so they can only skip rows
+        #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+        {
+            let view = store.avx_mixed_radix_twiddles(2, 12, direction);
+            assert_eq!((view.row_stride(), view.column_stride()), (48, 1));
+            let view = store.avx_mixed_radix_twiddles(4, 6, direction);
+            assert_eq!((view.row_stride(), view.column_stride()), (6, 1));
+            assert_eq!(store.stats().computed_tables(), 2);
+        }
     }
 }
@@ -0,0 +1,1134 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+use num_complex::Complex;
+use num_traits::{Bounded, Float, One, PrimInt, Signed, ToPrimitive, Zero};
+
+use crate::math_utils::{self, SmoothCostModel};
+use crate::plan::{FftPlannerScalar, Recipe};
+use crate::twiddles;
+use crate::{Direction, FftDirection, Length};
+
+/// A fixed-point number format which `FixedFft` can compute FFTs of. Implemented for `i16` (Q15) and `i32` (Q31).
+///
+/// Values are interpreted as fractions in the range `[-1, 1)`, with `FRACTIONAL_BITS` bits after the binary point.
+pub trait FixedPoint: Copy + Default + PartialEq + Debug + Send + Sync + 'static {
+    /// A signed integer type wide enough to hold the sum of many products of two values of this type
+    type Accumulator: PrimInt + Signed + Debug;
+
+    /// The number of bits after the binary point. 15 for Q15, 31 for Q31.
+    const FRACTIONAL_BITS: usize;
+
+    /// Converts this value to the accumulator type, without changing its scale
+    fn to_accumulator(self) -> Self::Accumulator;
+
+    /// Converts `value` to this type, without changing its scale. If `value` is out of range, it's clamped to the nearest representable value.
+    fn saturate(value: Self::Accumulator) -> Self;
+
+    /// Rounds the fraction `value` to the nearest representable value of this type. Values outside `[-1, 1)` are clamped.
+    fn from_f64(value: f64) -> Self;
+
+    /// Converts this value to the fraction it represents
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_fixed_point {
+    ($fixed_type:ty, $accumulator_type:ty, $fractional_bits:expr) => {
+        impl FixedPoint for $fixed_type {
+            type Accumulator = $accumulator_type;
+
+            const FRACTIONAL_BITS: usize = $fractional_bits;
+
+            #[inline(always)]
+            fn to_accumulator(self) -> Self::Accumulator {
+                self as $accumulator_type
+            }
+            #[inline(always)]
+            fn saturate(value: Self::Accumulator) -> Self {
+                if value > <$fixed_type>::max_value() as $accumulator_type {
+                    <$fixed_type>::max_value()
+                } else if value < <$fixed_type>::min_value() as $accumulator_type {
+                    <$fixed_type>::min_value()
+                } else {
+                    value as $fixed_type
+                }
+            }
+            fn from_f64(value: f64) -> Self {
+                let scaled = Float::round(value * (1u64 << $fractional_bits) as f64);
+                if scaled >= <$fixed_type>::max_value() as f64 {
+                    <$fixed_type>::max_value()
+                } else if scaled <= <$fixed_type>::min_value() as f64 {
+                    <$fixed_type>::min_value()
+                } else {
+                    scaled as $fixed_type
+                }
+            }
+            fn to_f64(self) -> f64 {
+                self as f64 / (1u64 << $fractional_bits) as f64
+            }
+        }
+    };
+}
+impl_fixed_point!(i16, i64, 15);
+impl_fixed_point!(i32, i128, 31);
+
+/// How `FixedFft` rounds results when it discards low bits
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Discard the low bits, rounding toward negative infinity. This is what a plain arithmetic right shift does.
+    Truncate,
+    /// Round to the nearest value, with ties rounded toward positive infinity.
+    RoundHalfUp,
+    /// Round to the nearest value, with ties rounded to the nearest even value. Also known as convergent rounding.
+    RoundHalfEven,
+}
+
+/// How `FixedFft` scales its data between stages, to keep it from overflowing
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FixedScaling {
+    /// Never scale. Outputs that don't fit in the fixed-point type are saturated.
+    ///
+    /// An FFT of size N can grow its input by a factor of N, so this is only useful for inputs that are known to be small.
+    None,
+    /// Scale the output of every radix-`r` stage by `2^-ceil(log2(r))`.
+    ///
+    /// The scaling of each stage is at least as large as the stage's growth, so the output never saturates as long as every input lies
+    /// within the unit circle. The returned exponent is always the same for a given FFT.
+    FixedShift,
+    /// Block floating point. Before each stage, find the largest magnitude in the buffer, and scale by the smallest power of two that
+    /// guarantees the stage won't overflow.
+    ///
+    /// This keeps as much precision as possible for small inputs. The returned exponent depends on the input.
+    BlockFloatingPoint,
+}
+
+// A twiddle factor for a fixed-point FFT stage.
+#[derive(Copy, Clone, Debug)]
+enum FixedTwiddle<T> {
+    // A multiple of a quarter turn, ie (-i)^k. These are applied exactly, by swapping and negating components.
+    // This matters because 1.0 isn't representable in fixed point, so multiplying by it would add rounding error to every butterfly
+    QuarterTurn(usize),
+    Other(Complex<T>),
+}
+
+impl<T: FixedPoint> FixedTwiddle<T> {
+    fn new(index: usize, fft_len: usize, direction: FftDirection) -> Self {
+        let quarter_turns = 4 * index / fft_len;
+        if quarter_turns * fft_len == 4 * index {
+            match direction {
+                FftDirection::Forward => FixedTwiddle::QuarterTurn(quarter_turns),
+                FftDirection::Inverse => FixedTwiddle::QuarterTurn((4 - quarter_turns) % 4),
+            }
+        } else {
+            let twiddle = twiddles::compute_twiddle::<f64>(index, fft_len, direction);
+            FixedTwiddle::Other(Complex::new(
+                T::from_f64(twiddle.re),
+                T::from_f64(twiddle.im),
+            ))
+        }
+    }
+}
+
+// Computes cyclic convolutions with a fixed kernel, by multiplying spectra computed with a pair of inner fixed-point FFTs.
+// The inner FFTs always use block floating point scaling, so every convolution keeps as much precision as its input allows
+struct FixedConvolution<T> {
+    forward_fft: FixedFft<T>,
+    inverse_fft: FixedFft<T>,
+
+    // The forward FFT of the kernel, divided by the inner FFT's length to normalize the inverse FFT,
+    // then multiplied by 2^spectrum_shift, to use as much of the fixed-point range as possible
+    kernel_spectrum: Box<[Complex<T>]>,
+    spectrum_shift: usize,
+}
+
+impl<T: FixedPoint> FixedConvolution<T> {
+    fn new(
+        mut kernel: Vec<Complex<f64>>,
+        inner_recipe: &Recipe,
+        rounding: RoundingMode,
+        planner: &mut FftPlannerScalar<f64>,
+    ) -> Self {
+        let len = inner_recipe.len();
+        assert_eq!(kernel.len(), len);
+        planner.plan_fft_forward(len).process(&mut kernel);
+
+        let max_norm = kernel
+            .iter()
+            .map(|element| element.norm() / len as f64)
+            .fold(0.0, f64::max);
+        let mut spectrum_shift = 0;
+        while max_norm * ((1u64 << (spectrum_shift + 1)) as f64) < 1.0 && spectrum_shift < 62 {
+            spectrum_shift += 1;
+        }
+        let scale = (1u64 << spectrum_shift) as f64 / len as f64;
+        let kernel_spectrum = kernel
+            .iter()
+            .map(|element| {
+                Complex::new(
+                    T::from_f64(element.re * scale),
+                    T::from_f64(element.im * scale),
+                )
+            })
+            .collect();
+
+        Self {
+            forward_fft: FixedFft::from_recipe(
+                inner_recipe,
+                FftDirection::Forward,
+                FixedScaling::BlockFloatingPoint,
+                rounding,
+                planner,
+            ),
+            inverse_fft: FixedFft::from_recipe(
+                inner_recipe,
+                FftDirection::Inverse,
+                FixedScaling::BlockFloatingPoint,
+                rounding,
+                planner,
+            ),
+            kernel_spectrum,
+            spectrum_shift,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.kernel_spectrum.len()
+    }
+
+    fn get_scratch_len(&self) -> usize {
+        self.len()
+            + core::cmp::max(
+                self.forward_fft.get_inplace_scratch_len(),
+                self.inverse_fft.get_inplace_scratch_len(),
+            )
+    }
+
+    // Convolves the first self.len() elements of `scratch` with the kernel, in place, using the rest of `scratch` as scratch space.
+    // Returns the exponent `e` of the result: the true convolution is `scratch * 2^e`
+    fn convolve(&self, scratch: &mut [Complex<T>], rounding: RoundingMode) -> isize {
+        let (buffer, inner_scratch) = scratch.split_at_mut(self.len());
+
+        let forward_exponent = self.forward_fft.process_with_scratch(buffer, inner_scratch);
+        for (element, spectrum) in buffer.iter_mut().zip(self.kernel_spectrum.iter()) {
+            let product = multiply_twiddle(*element, FixedTwiddle::Other(*spectrum));
+            *element = round_complex::<T>(product, T::FRACTIONAL_BITS as isize, rounding);
+        }
+        let inverse_exponent = self.inverse_fft.process_with_scratch(buffer, inner_scratch);
+
+        (forward_exponent + inverse_exponent) as isize - self.spectrum_shift as isize
+    }
+}
+
+// How a fixed-point FFT stage computes each of its butterflies
+enum FixedButterfly<T> {
+    // Directly, as a sum of every input times a twiddle factor, which takes radix^2 multiplies
+    Direct,
+    // With Rader's algorithm, for prime radixes. Every input but the first is reordered by powers of a primitive root,
+    // and convolved with the correspondingly reordered twiddle factors. The convolution may be zero-padded, like RadersAlgorithmPadded
+    Raders {
+        // input_indexes[q] = g^q mod radix, and output_indexes[q] = g^-q mod radix, where g is a primitive root
+        input_indexes: Box<[usize]>,
+        output_indexes: Box<[usize]>,
+        convolution: FixedConvolution<T>,
+    },
+    // With Bluestein's algorithm: Multiply the input by a chirp, convolve it with the conjugate chirp, and multiply by the chirp again
+    Bluesteins {
+        chirp: Box<[FixedTwiddle<T>]>,
+        convolution: FixedConvolution<T>,
+    },
+}
+
+// One stage of a mixed-radix Stockham FFT. Splits `input` into sub-FFTs of size `sub_len` with elements `stride` apart,
+// computes a radix-`radix` butterfly down each of them, applies twiddle factors, and writes the result to `output`
+struct FixedStage<T> {
+    radix: usize,
+    sub_len: usize,
+    stride: usize,
+
+    // twiddles[i] = e^(-2 pi i * i / sub_len) for a forward FFT. Since radix divides sub_len, this table also contains the butterfly's own twiddles
+    twiddles: Box<[FixedTwiddle<T>]>,
+
+    // How many bits FixedScaling::FixedShift shifts by for this stage, ie ceil(log2(radix))
+    fixed_shift: usize,
+
+    butterfly: FixedButterfly<T>,
+}
+
+impl<T: FixedPoint> FixedStage<T> {
+    fn new(
+        radix: usize,
+        sub_len: usize,
+        stride: usize,
+        direction: FftDirection,
+        butterfly: FixedButterfly<T>,
+    ) -> Self {
+        let twiddles = (0..sub_len)
+            .map(|index| FixedTwiddle::new(index, sub_len, direction))
+            .collect();
+
+        let fixed_shift = (0..).find(|bits| 1usize << bits >= radix).unwrap();
+
+        Self {
+            radix,
+            sub_len,
+            stride,
+            twiddles,
+            fixed_shift,
+            butterfly,
+        }
+    }
+
+    // Creates the butterfly for a stage that the recipe computes with a convolution: Rader's algorithm, padded or not, or Bluestein's algorithm
+    fn convolution_butterfly(
+        recipe: &Recipe,
+        direction: FftDirection,
+        rounding: RoundingMode,
+        planner: &mut FftPlannerScalar<f64>,
+    ) -> FixedButterfly<T> {
+        let radix = recipe.len();
+        match recipe {
+            Recipe::RadersAlgorithm { .. } | Recipe::RadersAlgorithmPadded { .. } => {
+                // RadersAlgorithmPadded splits its convolution into a cyclic and a negacyclic half, whose results would come back with different exponents.
+                // So compute the whole convolution at once instead, zero-padded to the inner length Bluestein's algorithm would use for one size smaller
+                let inner_fft = match recipe {
+                    Recipe::RadersAlgorithm { inner_fft } => Arc::clone(inner_fft),
+                    _ => planner
+                        .design_fft_for_len(SmoothCostModel::SCALAR.choose_len(2 * radix - 3)),
+                };
+
+                let primitive_root = math_utils::primitive_root(radix as u64).unwrap();
+                let primitive_root_inverse =
+                    math_utils::modular_exponent(primitive_root, radix as u64 - 2, radix as u64);
+
+                let mut input_indexes = Vec::with_capacity(radix - 1);
+                let mut output_indexes = Vec::with_capacity(radix - 1);
+                let mut input_index = 1;
+                let mut output_index = 1;
+                for _ in 1..radix {
+                    input_indexes.push(input_index as usize);
+                    output_indexes.push(output_index as usize);
+                    input_index = (input_index * primitive_root) % radix as u64;
+                    output_index = (output_index * primitive_root_inverse) % radix as u64;
+                }
+
+                // The kernel is the reordered twiddles. If the convolution is padded, wrap the end of the kernel around to the end of the padded buffer
+                let inner_len = inner_fft.len();
+                let mut kernel = vec![Complex::zero(); inner_len];
+                for (q, &output_index) in output_indexes.iter().enumerate() {
+                    let twiddle = twiddles::compute_twiddle(output_index, radix, direction);
+                    kernel[q] = twiddle;
+                    if q > 0 {
+                        kernel[inner_len - (radix - 1) + q] = twiddle;
+                    }
+                }
+
+                FixedButterfly::Raders {
+                    input_indexes: input_indexes.into_boxed_slice(),
+                    output_indexes: output_indexes.into_boxed_slice(),
+                    convolution: FixedConvolution::new(kernel, &inner_fft, rounding, planner),
+                }
+            }
+            Recipe::BluesteinsAlgorithm { inner_fft, .. } => {
+                // chirp[k] = e^(-pi i * k^2 / radix) for a forward FFT, ie twiddle k^2 of a FFT of size 2 * radix
+                let chirp_index = |k: usize| (k * k) % (2 * radix);
+                let chirp = (0..radix)
+                    .map(|k| FixedTwiddle::new(chirp_index(k), 2 * radix, direction))
+                    .collect();
+
+                // The kernel is the conjugate chirp, at offsets from -(radix - 1) to radix - 1. Negative offsets wrap around to the end
+                let inner_len = inner_fft.len();
+                let mut kernel = vec![Complex::zero(); inner_len];
+                for k in 0..radix {
+                    let twiddle =
+                        twiddles::compute_twiddle::<f64>(chirp_index(k), 2 * radix, direction)
+                            .conj();
+                    kernel[k] = twiddle;
+                    if k > 0 {
+                        kernel[inner_len - k] = twiddle;
+                    }
+                }
+
+                FixedButterfly::Bluesteins {
+                    chirp,
+                    convolution: FixedConvolution::new(kernel, inner_fft, rounding, planner),
+                }
+            }
+            _ => panic!("Only Rader's and Bluestein's algorithms are computed as convolutions"),
+        }
+    }
+
+    fn get_scratch_len(&self) -> usize {
+        match &self.butterfly {
+            FixedButterfly::Direct => 0,
+            FixedButterfly::Raders { convolution, .. }
+            | FixedButterfly::Bluesteins { convolution, .. } => convolution.get_scratch_len(),
+        }
+    }
+
+    // Returns the number of bits this stage needs to shift `input` by, to guarantee that it won't overflow
+    fn block_floating_point_shift(&self, input: &[Complex<T>]) -> usize {
+        // Every output of a radix-r stage is a sum of r inputs, rotated by twiddle factors, so its magnitude is at most r times the largest input magnitude.
+        // Each rounding step can add one more unit in the last place to each component, so leave a little room for that.
+        let max_squared = input
+            .iter()
+            .map(|element| {
+                let re = element.re.to_accumulator().to_i128().unwrap();
+                let im = element.im.to_accumulator().to_i128().unwrap();
+                re * re + im * im
+            })
+            .max()
+            .unwrap_or(0);
+        let radix = self.radix as i128;
+        let growth_squared = (radix * radix).saturating_mul(max_squared);
+
+        let limit = T::saturate(T::Accumulator::max_value())
+            .to_accumulator()
+            .to_i128()
+            .unwrap()
+            - 2;
+        let mut limit_squared = limit * limit;
+        let mut shift = 0;
+        while growth_squared > limit_squared {
+            limit_squared = limit_squared.saturating_mul(4);
+            shift += 1;
+        }
+        shift
+    }
+
+    fn process(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+        scaling: FixedScaling,
+        rounding: RoundingMode,
+    ) -> usize {
+        let shift = match scaling {
+            FixedScaling::None => 0,
+            FixedScaling::FixedShift => self.fixed_shift,
+            FixedScaling::BlockFloatingPoint => self.block_floating_point_shift(input),
+        };
+
+        let radix = self.radix;
+        let columns = self.sub_len / radix;
+        let stride = self.stride;
+
+        for column in 0..columns {
+            for offset in 0..stride {
+                let input_index = |r: usize| offset + stride * (column + columns * r);
+                let output_index = |k: usize| offset + stride * (radix * column + k);
+
+                // Every butterfly output is rounded once, with this stage's scaling folded in, then multiplied by its twiddle factor and rounded again
+                let mut store_output = |k: usize, butterfly_output: Complex<T>| {
+                    let product = multiply_twiddle(butterfly_output, self.twiddles[column * k]);
+                    output[output_index(k)] =
+                        round_complex::<T>(product, T::FRACTIONAL_BITS as isize, rounding);
+                };
+
+                match &self.butterfly {
+                    FixedButterfly::Direct => {
+                        for k in 0..radix {
+                            // Compute the butterfly output in the accumulator, then round once
+                            let mut sum =
+                                Complex::new(T::Accumulator::zero(), T::Accumulator::zero());
+                            for r in 0..radix {
+                                let twiddle = self.twiddles[((r * k) % radix) * columns];
+                                sum = sum + multiply_twiddle(input[input_index(r)], twiddle);
+                            }
+                            store_output(
+                                k,
+                                round_complex::<T>(
+                                    sum,
+                                    (T::FRACTIONAL_BITS + shift) as isize,
+                                    rounding,
+                                ),
+                            );
+                        }
+                    }
+                    FixedButterfly::Raders {
+                        input_indexes,
+                        output_indexes,
+                        convolution,
+                    } => {
+                        // Gather the reordered inputs into the convolution buffer, and sum every input for output 0
+                        let first_input = input[input_index(0)];
+                        let mut sum = to_accumulator(first_input);
+                        for (element, &index) in scratch.iter_mut().zip(input_indexes.iter()) {
+                            *element = input[input_index(index)];
+                            sum = sum + to_accumulator(*element);
+                        }
+                        for element in scratch[input_indexes.len()..convolution.len()].iter_mut() {
+                            *element = Complex::new(T::default(), T::default());
+                        }
+                        store_output(0, round_complex::<T>(sum, shift as isize, rounding));
+
+                        // Every other output is the first input plus one element of the convolution. Add them at the smaller of their two exponents, so neither loses precision
+                        let exponent = convolution.convolve(scratch, rounding);
+                        let common_exponent = core::cmp::min(exponent, 0);
+                        let first_input = shift_left(to_accumulator(first_input), -common_exponent);
+                        for (element, &k) in scratch.iter().zip(output_indexes.iter()) {
+                            let element =
+                                shift_left(to_accumulator(*element), exponent - common_exponent);
+                            store_output(
+                                k,
+                                round_complex::<T>(
+                                    first_input + element,
+                                    shift as isize - common_exponent,
+                                    rounding,
+                                ),
+                            );
+                        }
+                    }
+                    FixedButterfly::Bluesteins { chirp, convolution } => {
+                        for (r, (element, &twiddle)) in
+                            scratch.iter_mut().zip(chirp.iter()).enumerate()
+                        {
+                            let product = multiply_twiddle(input[input_index(r)], twiddle);
+                            *element =
+                                round_complex::<T>(product, T::FRACTIONAL_BITS as isize, rounding);
+                        }
+                        for element in scratch[radix..convolution.len()].iter_mut() {
+                            *element = Complex::new(T::default(), T::default());
+                        }
+
+                        let exponent = convolution.convolve(scratch, rounding);
+                        for (k, (element, &twiddle)) in scratch.iter().zip(chirp.iter()).enumerate()
+                        {
+                            let product = multiply_twiddle(*element, twiddle);
+                            store_output(
+                                k,
+                                round_complex::<T>(
+                                    product,
+                                    (T::FRACTIONAL_BITS + shift) as isize - exponent,
+                                    rounding,
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        shift
+    }
+}
+
+// Multiplies `value` by `twiddle`. The result is in the accumulator, scaled by an extra 2^FRACTIONAL_BITS
+#[inline(always)]
+fn multiply_twiddle<T: FixedPoint>(
+    value: Complex<T>,
+    twiddle: FixedTwiddle<T>,
+) -> Complex<T::Accumulator> {
+    let re = value.re.to_accumulator();
+    let im = value.im.to_accumulator();
+    match twiddle {
+        FixedTwiddle::QuarterTurn(0) => Complex::new(re, im) * scale_up::<T>(),
+        FixedTwiddle::QuarterTurn(1) => Complex::new(im, -re) * scale_up::<T>(),
+        FixedTwiddle::QuarterTurn(2) => Complex::new(-re, -im) * scale_up::<T>(),
+        FixedTwiddle::QuarterTurn(_) => Complex::new(-im, re) * scale_up::<T>(),
+        FixedTwiddle::Other(twiddle) => {
+            let twiddle_re = twiddle.re.to_accumulator();
+            let twiddle_im = twiddle.im.to_accumulator();
+            Complex::new(
+                re * twiddle_re - im * twiddle_im,
+                re * twiddle_im + im * twiddle_re,
+            )
+        }
+    }
+}
+
+#[inline(always)]
+fn scale_up<T: FixedPoint>() -> T::Accumulator {
+    T::Accumulator::one() << T::FRACTIONAL_BITS
+}
+
+#[inline(always)]
+fn to_accumulator<T: FixedPoint>(value: Complex<T>) -> Complex<T::Accumulator> {
+    Complex::new(value.re.to_accumulator(), value.im.to_accumulator())
+}
+
+// Multiplies `value` by 2^shift. Shifts are always small enough that this can't overflow the accumulator
+#[inline(always)]
+fn shift_left<A: PrimInt>(value: Complex<A>, shift: isize) -> Complex<A> {
+    Complex::new(value.re << shift as usize, value.im << shift as usize)
+}
+
+// Divides `value` by 2^shift, rounding according to `rounding`, and saturates the result. A negative shift multiplies instead
+#[inline(always)]
+fn round_complex<T: FixedPoint>(
+    value: Complex<T::Accumulator>,
+    shift: isize,
+    rounding: RoundingMode,
+) -> Complex<T> {
+    if shift < 0 {
+        let value = shift_left(value, -shift);
+        Complex::new(T::saturate(value.re), T::saturate(value.im))
+    } else {
+        Complex::new(
+            T::saturate(round_shift(value.re, shift as usize, rounding)),
+            T::saturate(round_shift(value.im, shift as usize, rounding)),
+        )
+    }
+}
+
+// Divides `value` by 2^shift, rounding according to `rounding`
+#[inline(always)]
+fn round_shift<A: PrimInt + Signed>(value: A, shift: usize, rounding: RoundingMode) -> A {
+    if shift == 0 {
+        return value;
+    }
+    let floor = value >> shift;
+    match rounding {
+        RoundingMode::Truncate => floor,
+        RoundingMode::RoundHalfUp => (value + (A::one() << (shift - 1))) >> shift,
+        RoundingMode::RoundHalfEven => {
+            let remainder = value - (floor << shift);
+            let half = A::one() << (shift - 1);
+            if remainder > half || (remainder == half && floor & A::one() == A::one()) {
+                floor + A::one()
+            } else {
+                floor
+            }
+        }
+    }
+}
+
+/// Computes FFTs of fixed-point data, using saturating integer arithmetic, a configurable rounding mode, and per-stage scaling.
+///
+/// The FFT is computed as a sequence of mixed-radix stages. Every stage computes each of its radix-`r` butterflies in a wide accumulator,
+/// then rounds and saturates the result once, with the stage's scaling folded in. It then multiplies by a twiddle factor, and rounds and
+/// saturates again. Twiddle factors are rounded to the nearest fixed-point value, except for multiples of a quarter turn, which are applied
+/// exactly. Since every step is integer arithmetic, the output is bit-exact and reproducible on every platform.
+///
+/// When planned with `FftPlannerFixed`, prime sizes that the recipe computes with Rader's or Bluestein's algorithm get a stage which computes its
+/// butterflies the same way, as a convolution with a pair of inner fixed-point FFTs. The inner FFTs always use block floating point scaling.
+///
+/// Each call to `process` returns an exponent `e`: The true DFT of the input is `output * 2^e`.
+///
+/// ~~~
+/// // Perform a forward Q15 FFT of size 1024, with block floating point scaling
+/// use rustfft::{FftPlannerFixed, FftDirection, num_complex::Complex};
+/// use rustfft::fixed_point::{FixedScaling, RoundingMode};
+///
+/// let mut planner = FftPlannerFixed::<i16>::new();
+/// let fft = planner.plan_fft(1024, FftDirection::Forward, FixedScaling::BlockFloatingPoint, RoundingMode::RoundHalfEven);
+///
+/// let mut buffer = vec![Complex{ re: 0i16, im: 0i16 }; 1024];
+/// let exponent = fft.process(&mut buffer);
+/// ~~~
+pub struct FixedFft<T> {
+    len: usize,
+    direction: FftDirection,
+    stages: Box<[FixedStage<T>]>,
+    scaling: FixedScaling,
+    rounding: RoundingMode,
+
+    // The largest scratch space any one stage needs for its own use, in addition to the scratch the stages bounce data through
+    stage_scratch_len: usize,
+}
+
+// One stage of a FFT designed from a recipe, before its twiddle factors are computed
+enum StagePlan<'a> {
+    Direct(usize),
+    Convolution(&'a Recipe),
+}
+
+impl<T: FixedPoint> FixedFft<T> {
+    /// Creates a FFT which computes one stage for each entry in `radixes`, in order. The FFT's length is the product of the radixes.
+    ///
+    /// Use this instead of the planner when the stage order needs to match another implementation, for bit-exact results.
+    ///
+    /// # Panics
+    /// Panics if any radix is less than 2.
+    pub fn new(
+        radixes: &[usize],
+        direction: FftDirection,
+        scaling: FixedScaling,
+        rounding: RoundingMode,
+    ) -> Self {
+        assert!(
+            radixes.iter().all(|&radix| radix >= 2),
+            "Every radix of a FixedFft must be at least 2. Got radixes = {:?}",
+            radixes
+        );
+        let len = radixes.iter().product();
+
+        let mut sub_len = len;
+        let mut stride = 1;
+        let mut stages = Vec::with_capacity(radixes.len());
+        for &radix in radixes {
+            stages.push(FixedStage::new(
+                radix,
+                sub_len,
+                stride,
+                direction,
+                FixedButterfly::Direct,
+            ));
+            sub_len /= radix;
+            stride *= radix;
+        }
+
+        Self::from_stages(len, stages, direction, scaling, rounding)
+    }
+
+    // Creates a FFT from a recipe designed by the scalar planner, computing one stage per radix the recipe factors into.
+    // Sizes that the recipe computes with Rader's or Bluestein's algorithm (including padded Rader's) get a single stage,
+    // which computes its butterflies as convolutions with the recipe's inner FFTs. The inner FFTs are planned from the same recipe.
+    pub(crate) fn from_recipe(
+        recipe: &Recipe,
+        direction: FftDirection,
+        scaling: FixedScaling,
+        rounding: RoundingMode,
+        planner: &mut FftPlannerScalar<f64>,
+    ) -> Self {
+        let mut stage_plans = Vec::new();
+        Self::collect_stages(recipe, &mut stage_plans);
+
+        let mut sub_len = recipe.len();
+        let mut stride = 1;
+        let mut stages = Vec::with_capacity(stage_plans.len());
+        for stage_plan in stage_plans {
+            let (radix, butterfly) = match stage_plan {
+                StagePlan::Direct(radix) => (radix, FixedButterfly::Direct),
+                StagePlan::Convolution(recipe) => (
+                    recipe.len(),
+                    FixedStage::convolution_butterfly(recipe, direction, rounding, planner),
+                ),
+            };
+            stages.push(FixedStage::new(
+                radix, sub_len, stride, direction, butterfly,
+            ));
+            sub_len /= radix;
+            stride *= radix;
+        }
+
+        Self::from_stages(recipe.len(), stages, direction, scaling, rounding)
+    }
+
+    fn collect_stages<'a>(recipe: &'a Recipe, stages: &mut Vec<StagePlan<'a>>) {
+        match recipe {
+            Recipe::MixedRadix {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::SixStep {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::GoodThomasAlgorithm {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::MixedRadixSmall {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::GoodThomasAlgorithmSmall {
+                left_fft,
+                right_fft,
+            } => {
+                Self::collect_stages(left_fft, stages);
+                Self::collect_stages(right_fft, stages);
+            }
+            // Split powers of two into radix-4 stages, with a radix-2 stage if necessary, like the scalar Radix4 algorithm
+            Recipe::Radix4(_)
+            | Recipe::Radix8(_)
+            | Recipe::Butterfly4
+            | Recipe::Butterfly8
+            | Recipe::Butterfly16
+            | Recipe::Butterfly32 => {
+                let mut remaining = recipe.len();
+                while remaining >= 4 {
+                    stages.push(StagePlan::Direct(4));
+                    remaining /= 4;
+                }
+                if remaining == 2 {
+                    stages.push(StagePlan::Direct(2));
+                }
+            }
+            Recipe::Butterfly6 => {
+                stages.push(StagePlan::Direct(2));
+                stages.push(StagePlan::Direct(3));
+            }
+            Recipe::Butterfly9Winograd => {
+                stages.push(StagePlan::Direct(3));
+                stages.push(StagePlan::Direct(3));
+            }
+            // Use the same passes as the scalar Stockham algorithm
+            Recipe::Stockham(len) => {
+                let mut remaining = *len;
+                while remaining & 3 == 0 {
+                    stages.push(StagePlan::Direct(4));
+                    remaining /= 4;
+                }
+                if remaining & 1 == 0 {
+                    stages.push(StagePlan::Direct(2));
+                    remaining /= 2;
+                }
+                while remaining > 1 {
+                    stages.push(StagePlan::Direct(3));
+                    remaining /= 3;
+                }
+            }
+            Recipe::RadersAlgorithm { .. }
+            | Recipe::RadersAlgorithmPadded { .. }
+            | Recipe::BluesteinsAlgorithm { .. } => stages.push(StagePlan::Convolution(recipe)),
+            _ => {
+                if recipe.len() > 1 {
+                    stages.push(StagePlan::Direct(recipe.len()));
+                }
+            }
+        }
+    }
+
+    fn from_stages(
+        len: usize,
+        stages: Vec<FixedStage<T>>,
+        direction: FftDirection,
+        scaling: FixedScaling,
+        rounding: RoundingMode,
+    ) -> Self {
+        let stage_scratch_len = stages
+            .iter()
+            .map(FixedStage::get_scratch_len)
+            .max()
+            .unwrap_or(0);
+
+        Self {
+            len,
+            direction,
+            stages: stages.into_boxed_slice(),
+            scaling,
+            rounding,
+            stage_scratch_len,
+        }
+    }
+
+    /// Computes a FFT in-place, and returns its exponent. The true DFT of the input is `buffer * 2^exponent`.
+    ///
+    /// Convenience method that allocates a scratch buffer of size `self.get_inplace_scratch_len()`, then calls `process_with_scratch`.
+    ///
+    /// # Panics
+    /// Panics if `buffer.len() != self.len()`.
+    pub fn process(&self, buffer: &mut [Complex<T>]) -> usize {
+        let mut scratch =
+            vec![Complex::new(T::default(), T::default()); self.get_inplace_scratch_len()];
+        self.process_with_scratch(buffer, &mut scratch)
+    }
+
+    /// Computes a FFT in-place, and returns its exponent. The true DFT of the input is `buffer * 2^exponent`.
+    ///
+    /// Unlike the floating point FFTs, a fixed-point FFT only processes a single FFT per call, because each FFT gets its own exponent.
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage after calling.
+    ///
+    /// # Panics
+    /// Panics if `buffer.len() != self.len()`, or if `scratch.len() < self.get_inplace_scratch_len()`.
+    pub fn process_with_scratch(
+        &self,
+        buffer: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) -> usize {
+        assert_eq!(
+            buffer.len(),
+            self.len(),
+            "Provided FFT buffer must be the same length as a fixed-point FFT. Expected len = {}, got len = {}",
+            self.len(),
+            buffer.len()
+        );
+        assert!(
+            scratch.len() >= self.get_inplace_scratch_len(),
+            "Not enough scratch space was provided. Expected scratch len >= {}, got scratch len = {}",
+            self.get_inplace_scratch_len(),
+            scratch.len()
+        );
+        let (scratch, stage_scratch) = scratch.split_at_mut(self.len());
+
+        // Stockham stages can't run in place, so bounce the data back and forth between the buffer and the scratch
+        let mut exponent = 0;
+        for (i, stage) in self.stages.iter().enumerate() {
+            exponent += if i % 2 == 0 {
+                stage.process(buffer, scratch, stage_scratch, self.scaling, self.rounding)
+            } else {
+                stage.process(scratch, buffer, stage_scratch, self.scaling, self.rounding)
+            };
+        }
+        if self.stages.len() % 2 == 1 {
+            buffer.copy_from_slice(scratch);
+        }
+
+        exponent
+    }
+
+    /// Returns the size of the scratch buffer required by `process_with_scratch`
+    pub fn get_inplace_scratch_len(&self) -> usize {
+        self.len + self.stage_scratch_len
+    }
+}
+impl<T> Length for FixedFft<T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+impl<T> Direction for FixedFft<T> {
+    fn fft_direction(&self) -> FftDirection {
+        self.direction
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::test_utils::random_signal;
+    use crate::{Fft, FftPlannerFixed};
+    use alloc::sync::Arc;
+
+    // Computes `fft` on a random signal with the given amplitude, and returns the RMS error relative to a f64 DFT, as a fraction of the output's RMS
+    fn fixed_fft_error<T: FixedPoint>(fft: &FixedFft<T>, amplitude: f64) -> f64 {
+        let signal: Vec<Complex<f64>> = random_signal(fft.len());
+        let mut buffer: Vec<Complex<T>> = signal
+            .iter()
+            .map(|element| {
+                // random_signal generates values in [0, 10), so recenter them, and keep them inside the unit circle
+                let element = (element - Complex::new(5.0, 5.0)) * (amplitude / 10.0);
+                Complex::new(T::from_f64(element.re), T::from_f64(element.im))
+            })
+            .collect();
+
+        let mut expected: Vec<Complex<f64>> = buffer
+            .iter()
+            .map(|element| Complex::new(element.re.to_f64(), element.im.to_f64()))
+            .collect();
+        Dft::new(fft.len(), fft.fft_direction()).process(&mut expected);
+
+        let exponent = fft.process(&mut buffer);
+        let scale = (2.0f64).powi(exponent as i32);
+
+        let mut error_sum = 0.0;
+        let mut expected_sum = 0.0;
+        for (actual, expected) in buffer.iter().zip(expected.iter()) {
+            let actual = Complex::new(actual.re.to_f64(), actual.im.to_f64()) * scale;
+            error_sum += (actual - expected).norm_sqr();
+            expected_sum += expected.norm_sqr();
+        }
+        (error_sum / expected_sum).sqrt()
+    }
+
+    #[test]
+    fn test_fixed_fft_accuracy() {
+        let roundings = [
+            RoundingMode::Truncate,
+            RoundingMode::RoundHalfUp,
+            RoundingMode::RoundHalfEven,
+        ];
+        let mut planner16 = FftPlannerFixed::<i16>::new();
+        let mut planner32 = FftPlannerFixed::<i32>::new();
+
+        // Cover butterflies, mixed radix, radix-4 with and without a radix-2 stage, Stockham, and primes that the scalar planner computes with Rader's or Bluestein's
+        for &len in &[
+            2, 3, 4, 6, 8, 12, 15, 16, 30, 32, 37, 64, 100, 128, 173, 256, 360, 1728,
+        ] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &rounding in &roundings {
+                    let fft16 = planner16.plan_fft(
+                        len,
+                        direction,
+                        FixedScaling::BlockFloatingPoint,
+                        rounding,
+                    );
+                    let error = fixed_fft_error(&fft16, 0.5);
+                    assert!(error < 2e-3, "Q15 block floating point, len = {}, direction = {}, rounding = {:?}, error = {}", len, direction, rounding, error);
+
+                    let fft16 =
+                        planner16.plan_fft(len, direction, FixedScaling::FixedShift, rounding);
+                    let error = fixed_fft_error(&fft16, 0.5);
+                    assert!(
+                        error < 2e-2,
+                        "Q15 fixed shift, len = {}, direction = {}, rounding = {:?}, error = {}",
+                        len,
+                        direction,
+                        rounding,
+                        error
+                    );
+
+                    let fft32 = planner32.plan_fft(
+                        len,
+                        direction,
+                        FixedScaling::BlockFloatingPoint,
+                        rounding,
+                    );
+                    let error = fixed_fft_error(&fft32, 0.5);
+                    assert!(error < 1e-7, "Q31 block floating point, len = {}, direction = {}, rounding = {:?}, error = {}", len, direction, rounding, error);
+
+                    let fft32 =
+                        planner32.plan_fft(len, direction, FixedScaling::FixedShift, rounding);
+                    let error = fixed_fft_error(&fft32, 0.5);
+                    assert!(
+                        error < 1e-6,
+                        "Q31 fixed shift, len = {}, direction = {}, rounding = {:?}, error = {}",
+                        len,
+                        direction,
+                        rounding,
+                        error
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_fft_convolution_stages() {
+        // Primes that the scalar planner computes with Rader's or Bluestein's algorithm should follow the same structure, instead of computing a direct O(p^2) stage.
+        // The planner picks padded Rader's instead of Bluestein's for all of these sizes, so also build a Bluestein's recipe by hand
+        let mut planner = FftPlannerScalar::new();
+        let mut recipes = vec![Arc::new(Recipe::BluesteinsAlgorithm {
+            len: 107,
+            inner_fft: Arc::new(Recipe::Stockham(216)),
+        })];
+        for &len in &[101, 107, 167, 179, 227, 263, 347, 359, 383] {
+            recipes.push(planner.design_fft_for_len(len));
+        }
+
+        let mut found_raders = false;
+        let mut found_raders_padded = false;
+        let mut found_bluesteins = false;
+        for recipe in &recipes {
+            let len = recipe.len();
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &scaling in &[FixedScaling::BlockFloatingPoint, FixedScaling::FixedShift] {
+                    let fft32 = FixedFft::<i32>::from_recipe(
+                        recipe,
+                        direction,
+                        scaling,
+                        RoundingMode::RoundHalfEven,
+                        &mut planner,
+                    );
+                    assert_eq!(fft32.stages.len(), 1);
+                    match &fft32.stages[0].butterfly {
+                        FixedButterfly::Direct => {
+                            panic!("len = {} was computed with a direct stage", len)
+                        }
+                        FixedButterfly::Raders { convolution, .. } => {
+                            if convolution.len() == len - 1 {
+                                found_raders = true;
+                            } else {
+                                found_raders_padded = true;
+                            }
+                        }
+                        FixedButterfly::Bluesteins { .. } => found_bluesteins = true,
+                    }
+                    let error = fixed_fft_error(&fft32, 0.5);
+                    assert!(
+                        error < 1e-6,
+                        "Q31, len = {}, direction = {}, scaling = {:?}, error = {}",
+                        len,
+                        direction,
+                        scaling,
+                        error
+                    );
+
+                    let fft16 = FixedFft::<i16>::from_recipe(
+                        recipe,
+                        direction,
+                        scaling,
+                        RoundingMode::RoundHalfEven,
+                        &mut planner,
+                    );
+                    let error = fixed_fft_error(&fft16, 0.5);
+                    assert!(
+                        error < 2e-2,
+                        "Q15, len = {}, direction = {}, scaling = {:?}, error = {}",
+                        len,
+                        direction,
+                        scaling,
+                        error
+                    );
+                }
+            }
+        }
+        assert!(found_raders && found_raders_padded && found_bluesteins);
+    }
+
+    #[test]
+    fn test_fixed_fft_unscaled() {
+        // Small enough inputs don't need any scaling
+        for &len in &[4, 12, 64] {
+            let fft = FixedFft::<i32>::new(
+                &[len],
+                FftDirection::Forward,
+                FixedScaling::None,
+                RoundingMode::RoundHalfEven,
+            );
+            let error = fixed_fft_error(&fft, 0.5 / len as f64);
+            assert!(error < 1e-6, "len = {}, error = {}", len, error);
+        }
+    }
+
+    #[test]
+    fn test_fixed_fft_saturates() {
+        // Without scaling, the DC bin of a constant signal overflows. It should saturate instead of wrapping around
+        let fft = FixedFft::<i16>::new(
+            &[4, 4],
+            FftDirection::Forward,
+            FixedScaling::None,
+            RoundingMode::Truncate,
+        );
+        let mut buffer = vec![Complex::new(i16::max_value(), i16::min_value()); 16];
+        let exponent = fft.process(&mut buffer);
+        assert_eq!(exponent, 0);
+        assert_eq!(buffer[0], Complex::new(i16::max_value(), i16::min_value()));
+    }
+
+    #[test]
+    fn test_fixed_fft_exponent() {
+        // A radix-2 stage with a fixed shift halves its output
+        let fft = FixedFft::<i16>::new(
+            &[2],
+            FftDirection::Forward,
+            FixedScaling::FixedShift,
+            RoundingMode::Truncate,
+        );
+        let mut buffer = vec![Complex::new(16384, 0), Complex::new(16384, -1)];
+        let exponent = fft.process(&mut buffer);
+        assert_eq!(exponent, 1);
+        assert_eq!(buffer, vec![Complex::new(16384, -1), Complex::new(0, 0)]);
+
+        // Block floating point doesn't shift small inputs, and shifts large inputs just enough to avoid overflow
+        let fft = FixedFft::<i16>::new(
+            &[2, 2],
+            FftDirection::Forward,
+            FixedScaling::BlockFloatingPoint,
+            RoundingMode::RoundHalfEven,
+        );
+        let mut buffer = vec![Complex::new(100, 0); 4];
+        assert_eq!(fft.process(&mut buffer), 0);
+        assert_eq!(buffer[0], Complex::new(400, 0));
+
+        let mut buffer = vec![Complex::new(20000, 0); 4];
+        assert_eq!(fft.process(&mut buffer), 2);
+        assert_eq!(buffer[0], Complex::new(20000, 0));
+    }
+
+    #[test]
+    fn test_round_shift() {
+        // (value, truncate, half up, half even), for a shift of 1
+        let cases: [(i64, i64, i64, i64); 6] = [
+            (5, 2, 3, 2),
+            (7, 3, 4, 4),
+            (6, 3, 3, 3),
+            (-5, -3, -2, -2),
+            (-7, -4, -3, -4),
+            (-6, -3, -3, -3),
+        ];
+        for &(value, truncate, half_up, half_even) in &cases {
+            assert_eq!(round_shift(value, 1, RoundingMode::Truncate), truncate);
+            assert_eq!(round_shift(value, 1, RoundingMode::RoundHalfUp), half_up);
+            assert_eq!(
+                round_shift(value, 1, RoundingMode::RoundHalfEven),
+                half_even
+            );
+        }
+    }
+}
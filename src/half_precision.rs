@@ -0,0 +1,482 @@
+use alloc::sync::Arc;
+
+use half::{bf16, f16};
+use num_complex::Complex;
+use num_traits::Zero;
+
+use crate::array_utils;
+use crate::common::{fft_error_inplace, fft_error_outofplace};
+use crate::{Direction, Fft, FftDirection, Length};
+
+/// A half-precision floating point type which `HalfPrecisionFft` can read and write. Implemented for `f16` and `bf16`.
+pub trait HalfFloat: Copy + Send + Sync + 'static {
+    /// Rounds `value` to the nearest representable value of this type
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts this value to f32. Every half-precision value is exactly representable as a f32, so this is lossless.
+    fn to_f32(self) -> f32;
+
+    /// Converts every element of `input` to f32, and writes it to the corresponding element of `output`.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` have different lengths.
+    fn widen_complex(input: &[Complex<Self>], output: &mut [Complex<f32>]) {
+        assert_eq!(input.len(), output.len());
+        if Self::has_simd_conversions() {
+            // Safety: We just checked that this machine supports the SIMD conversions
+            unsafe { Self::widen_complex_simd(input, output) }
+        } else {
+            widen_complex_scalar(input, output)
+        }
+    }
+
+    /// Rounds every element of `input` to the nearest representable value of this type, and writes it to the corresponding element of `output`.
+    ///
+    /// # Panics
+    /// Panics if `input` and `output` have different lengths.
+    fn narrow_complex(input: &[Complex<f32>], output: &mut [Complex<Self>]) {
+        assert_eq!(input.len(), output.len());
+        if Self::has_simd_conversions() {
+            // Safety: We just checked that this machine supports the SIMD conversions
+            unsafe { Self::narrow_complex_simd(input, output) }
+        } else {
+            narrow_complex_scalar(input, output)
+        }
+    }
+
+    /// Returns true if this machine supports the instruction sets that this type's SIMD conversions and fused FFT passes require.
+    /// `HalfPrecisionFft` checks this once, when it's created.
+    fn has_simd_conversions() -> bool {
+        false
+    }
+
+    // Same as widen_complex, but without the check. Unsafe because callers must check has_simd_conversions() first
+    #[doc(hidden)]
+    unsafe fn widen_complex_simd(input: &[Complex<Self>], output: &mut [Complex<f32>]) {
+        widen_complex_scalar(input, output)
+    }
+
+    // Same as narrow_complex, but without the check. Unsafe because callers must check has_simd_conversions() first
+    #[doc(hidden)]
+    unsafe fn narrow_complex_simd(input: &[Complex<f32>], output: &mut [Complex<Self>]) {
+        narrow_complex_scalar(input, output)
+    }
+
+    // Returns `fft`'s fused conversion passes for this type, if it has them. They require the same instruction sets as the SIMD conversions
+    #[doc(hidden)]
+    fn fused_fft(_fft: &dyn Fft<f32>) -> Option<&dyn FusedHalfFft<Self>> {
+        None
+    }
+}
+
+/// A f32 FFT algorithm that can read half-precision input in its first pass, and write half-precision output in its last pass,
+/// so that `HalfPrecisionFft` doesn't need separate passes to convert to and from f32.
+///
+/// Returned by [`Fft::fused_f16`](crate::Fft::fused_f16). RustFFT's AVX mixed radix steps implement it for `f16`.
+pub trait FusedHalfFft<H>: Sync + Send {
+    /// Computes a FFT on `buffer`, which must be exactly one FFT long.
+    ///
+    /// # Safety
+    /// The conversions may use instruction sets that the caller must check for first, with [`HalfFloat::has_simd_conversions`].
+    unsafe fn process_half_inplace(&self, buffer: &mut [Complex<H>], scratch: &mut [Complex<f32>]);
+
+    /// Computes a FFT from `input` into `output`, which must both be exactly one FFT long.
+    ///
+    /// # Safety
+    /// Same requirements as [`process_half_inplace`](FusedHalfFft::process_half_inplace).
+    unsafe fn process_half_outofplace(
+        &self,
+        input: &[Complex<H>],
+        output: &mut [Complex<H>],
+        scratch: &mut [Complex<f32>],
+    );
+
+    /// Returns the size of the f32 scratch buffer required by both process methods
+    fn get_half_scratch_len(&self) -> usize;
+}
+
+fn widen_complex_scalar<H: HalfFloat>(input: &[Complex<H>], output: &mut [Complex<f32>]) {
+    assert_eq!(input.len(), output.len());
+    for (input_element, output_element) in input.iter().zip(output.iter_mut()) {
+        *output_element = Complex::new(input_element.re.to_f32(), input_element.im.to_f32());
+    }
+}
+
+fn narrow_complex_scalar<H: HalfFloat>(input: &[Complex<f32>], output: &mut [Complex<H>]) {
+    assert_eq!(input.len(), output.len());
+    for (input_element, output_element) in input.iter().zip(output.iter_mut()) {
+        *output_element =
+            Complex::new(H::from_f32(input_element.re), H::from_f32(input_element.im));
+    }
+}
+
+impl HalfFloat for f16 {
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+    fn has_simd_conversions() -> bool {
+        crate::avx::avx_half_precision::has_f16c()
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+    unsafe fn widen_complex_simd(input: &[Complex<Self>], output: &mut [Complex<f32>]) {
+        // Safety: widen_f16 requires the "avx" and "f16c" instruction sets, which has_simd_conversions() checks for
+        crate::avx::avx_half_precision::widen_f16(input, output)
+    }
+
+    #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+    unsafe fn narrow_complex_simd(input: &[Complex<f32>], output: &mut [Complex<Self>]) {
+        // Safety: narrow_f16 requires the "avx" and "f16c" instruction sets, which has_simd_conversions() checks for
+        crate::avx::avx_half_precision::narrow_f16(input, output)
+    }
+
+    fn fused_fft(fft: &dyn Fft<f32>) -> Option<&dyn FusedHalfFft<Self>> {
+        fft.fused_f16()
+    }
+}
+
+// There are no x86 instructions for converting bf16, so bf16 always uses the scalar conversions
+impl HalfFloat for bf16 {
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+}
+
+/// Computes FFTs of half-precision data, by converting each chunk to f32, computing an f32 FFT, then converting the result back.
+///
+/// Storing data as `Complex<f16>` or `Complex<bf16>` halves the memory bandwidth of `Complex<f32>`, while computing in f32 keeps the
+/// FFT's rounding error far below the precision of the half-precision output. On x86_64 machines that support the `avx` and `f16c`
+/// instruction sets, `f16` conversions use F16C instructions, and if the inner FFT's first step is one of the AVX planner's mixed radix
+/// steps, the conversions happen as that step loads its input and stores its output, instead of in separate passes. `bf16` conversions are always scalar.
+///
+/// Requires RustFFT's `half` feature flag.
+///
+/// ~~~
+/// // Perform a forward FFT of size 1234, on f16 data
+/// use std::sync::Arc;
+/// use rustfft::{FftPlanner, num_complex::Complex, half_precision::HalfPrecisionFft};
+/// use half::f16;
+///
+/// let mut planner = FftPlanner::new();
+/// let fft = HalfPrecisionFft::<f16>::new(planner.plan_fft_forward(1234));
+///
+/// let mut buffer = vec![Complex{ re: f16::from_f32(0.0), im: f16::from_f32(0.0) }; 1234];
+/// fft.process(&mut buffer);
+/// ~~~
+pub struct HalfPrecisionFft<H> {
+    inner_fft: Arc<dyn Fft<f32>>,
+    simd_conversions: bool,
+    _phantom: core::marker::PhantomData<H>,
+}
+
+impl<H: HalfFloat> HalfPrecisionFft<H> {
+    /// Creates a FFT instance which computes `inner_fft` on half-precision data
+    pub fn new(inner_fft: Arc<dyn Fft<f32>>) -> Self {
+        Self {
+            inner_fft,
+            simd_conversions: H::has_simd_conversions(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    // Returns the inner FFT's fused conversion passes, if it has them and this machine supports them
+    fn fused_fft(&self) -> Option<&dyn FusedHalfFft<H>> {
+        if self.simd_conversions {
+            H::fused_fft(self.inner_fft.as_ref())
+        } else {
+            None
+        }
+    }
+
+    fn widen(&self, input: &[Complex<H>], output: &mut [Complex<f32>]) {
+        if self.simd_conversions {
+            // Safety: We checked that this machine supports the SIMD conversions in our constructor
+            unsafe { H::widen_complex_simd(input, output) }
+        } else {
+            widen_complex_scalar(input, output)
+        }
+    }
+
+    fn narrow(&self, input: &[Complex<f32>], output: &mut [Complex<H>]) {
+        if self.simd_conversions {
+            // Safety: We checked that this machine supports the SIMD conversions in our constructor
+            unsafe { H::narrow_complex_simd(input, output) }
+        } else {
+            narrow_complex_scalar(input, output)
+        }
+    }
+
+    /// Computes a FFT in-place.
+    ///
+    /// Convenience method that allocates a scratch buffer of size `self.get_inplace_scratch_len()`, then calls `process_with_scratch`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// - `buffer.len() % self.len() > 0`
+    /// - `buffer.len() < self.len()`
+    pub fn process(&self, buffer: &mut [Complex<H>]) {
+        let mut scratch = vec![Complex::zero(); self.get_inplace_scratch_len()];
+        self.process_with_scratch(buffer, &mut scratch);
+    }
+
+    /// Divides `buffer` into chunks of size `self.len()`, and computes a FFT on each chunk.
+    ///
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage after calling.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// - `buffer.len() % self.len() > 0`
+    /// - `buffer.len() < self.len()`
+    /// - `scratch.len() < self.get_inplace_scratch_len()`
+    pub fn process_with_scratch(&self, buffer: &mut [Complex<H>], scratch: &mut [Complex<f32>]) {
+        if self.len() == 0 {
+            return;
+        }
+
+        let required_scratch = self.get_inplace_scratch_len();
+        if scratch.len() < required_scratch || buffer.len() < self.len() {
+            fft_error_inplace(self.len(), buffer.len(), required_scratch, scratch.len());
+            return; // Unreachable, because fft_error_inplace asserts, but it helps codegen to put it here
+        }
+
+        let scratch = &mut scratch[..required_scratch];
+        let result = if let Some(fused_fft) = self.fused_fft() {
+            array_utils::iter_chunks(buffer, self.len(), |chunk| {
+                // Safety: fused_fft() only returns Some if this machine supports the SIMD conversions
+                unsafe { fused_fft.process_half_inplace(chunk, scratch) }
+            })
+        } else {
+            let (work_buffer, inner_scratch) = scratch.split_at_mut(self.len());
+            array_utils::iter_chunks(buffer, self.len(), |chunk| {
+                self.widen(chunk, work_buffer);
+                self.inner_fft
+                    .process_with_scratch(work_buffer, inner_scratch);
+                self.narrow(work_buffer, chunk);
+            })
+        };
+
+        if result.is_err() {
+            fft_error_inplace(self.len(), buffer.len(), required_scratch, scratch.len());
+        }
+    }
+
+    /// Divides `input` and `output` into chunks of size `self.len()`, and computes a FFT on each chunk, without modifying `input`.
+    ///
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage after calling.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// - `output.len() != input.len()`
+    /// - `input.len() % self.len() > 0`
+    /// - `input.len() < self.len()`
+    /// - `scratch.len() < self.get_outofplace_scratch_len()`
+    pub fn process_outofplace_with_scratch(
+        &self,
+        input: &[Complex<H>],
+        output: &mut [Complex<H>],
+        scratch: &mut [Complex<f32>],
+    ) {
+        if self.len() == 0 {
+            return;
+        }
+
+        let required_scratch = self.get_outofplace_scratch_len();
+        if scratch.len() < required_scratch
+            || input.len() < self.len()
+            || output.len() != input.len()
+        {
+            fft_error_outofplace(
+                self.len(),
+                input.len(),
+                output.len(),
+                required_scratch,
+                scratch.len(),
+            );
+            return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+        }
+
+        let scratch = &mut scratch[..required_scratch];
+        let result = if let Some(fused_fft) = self.fused_fft() {
+            array_utils::iter_chunks_zipped_immutable(
+                input,
+                output,
+                self.len(),
+                |in_chunk, out_chunk| {
+                    // Safety: fused_fft() only returns Some if this machine supports the SIMD conversions
+                    unsafe { fused_fft.process_half_outofplace(in_chunk, out_chunk, scratch) }
+                },
+            )
+        } else {
+            let (work_buffer, inner_scratch) = scratch.split_at_mut(self.len());
+            array_utils::iter_chunks_zipped_immutable(
+                input,
+                output,
+                self.len(),
+                |in_chunk, out_chunk| {
+                    self.widen(in_chunk, work_buffer);
+                    self.inner_fft
+                        .process_with_scratch(work_buffer, inner_scratch);
+                    self.narrow(work_buffer, out_chunk);
+                },
+            )
+        };
+
+        if result.is_err() {
+            fft_error_outofplace(
+                self.len(),
+                input.len(),
+                output.len(),
+                required_scratch,
+                scratch.len(),
+            );
+        }
+    }
+
+    /// Returns the size of the f32 scratch buffer required by `process_with_scratch`
+    pub fn get_inplace_scratch_len(&self) -> usize {
+        match self.fused_fft() {
+            Some(fused_fft) => fused_fft.get_half_scratch_len(),
+            None => self.len() + self.inner_fft.get_inplace_scratch_len(),
+        }
+    }
+
+    /// Returns the size of the f32 scratch buffer required by `process_outofplace_with_scratch`
+    pub fn get_outofplace_scratch_len(&self) -> usize {
+        self.get_inplace_scratch_len()
+    }
+}
+impl<H> Length for HalfPrecisionFft<H> {
+    fn len(&self) -> usize {
+        self.inner_fft.len()
+    }
+}
+impl<H> Direction for HalfPrecisionFft<H> {
+    fn fft_direction(&self) -> FftDirection {
+        self.inner_fft.fft_direction()
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::test_utils::random_signal;
+    use crate::FftPlanner;
+
+    // Checks that a half-precision FFT matches an f32 FFT of the same (already rounded) data, to within the precision of the output type
+    fn check_half_precision_fft<H: HalfFloat>(len: usize, direction: FftDirection, tolerance: f32) {
+        let mut planner = FftPlanner::new();
+        let fft = HalfPrecisionFft::<H>::new(planner.plan_fft(len, direction));
+        assert_eq!(fft.len(), len);
+        assert_eq!(fft.fft_direction(), direction);
+
+        // Process two chunks at once, to make sure multi-chunk buffers work
+        let half_zero = Complex::new(H::from_f32(0.0), H::from_f32(0.0));
+        let signal: Vec<Complex<f32>> = random_signal(len * 2);
+        let mut half_input = vec![half_zero; len * 2];
+        H::narrow_complex(&signal, &mut half_input);
+
+        let mut expected = vec![Complex::zero(); len * 2];
+        H::widen_complex(&half_input, &mut expected);
+        let dft = Dft::new(len, direction);
+        for chunk in expected.chunks_mut(len) {
+            dft.process(chunk);
+        }
+
+        let check = |output: &[Complex<H>], method: &str| {
+            for (actual, expected) in output.iter().zip(expected.iter()) {
+                let actual = Complex::new(actual.re.to_f32(), actual.im.to_f32());
+                assert!(
+                    (actual - expected).norm() <= tolerance * expected.norm().max(1.0),
+                    "{} failed, length = {}, direction = {}, expected = {}, actual = {}",
+                    method,
+                    len,
+                    direction,
+                    expected,
+                    actual
+                );
+            }
+        };
+
+        let mut buffer = half_input.clone();
+        fft.process(&mut buffer);
+        check(&buffer, "process()");
+
+        let mut output = vec![half_zero; len * 2];
+        let mut scratch = vec![Complex::zero(); fft.get_outofplace_scratch_len()];
+        fft.process_outofplace_with_scratch(&half_input, &mut output, &mut scratch);
+        check(&output, "process_outofplace_with_scratch()");
+    }
+
+    #[test]
+    fn test_half_precision_fft_f16() {
+        for len in 1..40 {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                check_half_precision_fft::<f16>(len, direction, 2e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_half_precision_fft_f16_fused() {
+        // Sizes where the AVX planner's first step is a mixed radix step, including ones whose rows don't fill a whole number of vectors
+        for &len in &[96, 250, 1200, 7 * 43] {
+            #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+            {
+                if crate::avx::avx_half_precision::has_f16c() {
+                    let fft = HalfPrecisionFft::<f16>::new(FftPlanner::new().plan_fft_forward(len));
+                    assert!(fft.fused_fft().is_some(), "len = {}", len);
+                }
+            }
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                check_half_precision_fft::<f16>(len, direction, 2e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_half_precision_fft_bf16() {
+        for len in 1..40 {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                check_half_precision_fft::<bf16>(len, direction, 1e-2);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f16_conversion_roundtrip() {
+        // Cover full vectors and remainders of every size, to exercise both the F16C path and the scalar tail
+        for len in 0..20 {
+            let signal: Vec<Complex<f32>> = random_signal(len);
+            let mut narrowed = vec![Complex::new(f16::from_f32(0.0), f16::from_f32(0.0)); len];
+            f16::narrow_complex(&signal, &mut narrowed);
+
+            let mut widened = vec![Complex::zero(); len];
+            f16::widen_complex(&narrowed, &mut widened);
+
+            for ((original, narrowed), widened) in
+                signal.iter().zip(narrowed.iter()).zip(widened.iter())
+            {
+                assert_eq!(narrowed.re, f16::from_f32(original.re));
+                assert_eq!(narrowed.im, f16::from_f32(original.im));
+                assert_eq!(widened.re, narrowed.re.to_f32());
+                assert_eq!(widened.im, narrowed.im.to_f32());
+            }
+        }
+    }
+}
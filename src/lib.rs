@@ -1,4 +1,5 @@
 #![cfg_attr(all(feature = "bench", test), feature(test))]
+#![cfg_attr(all(not(feature = "std"), not(test)), no_std)]
 
 //! RustFFT is a high-performance FFT library written in pure Rust.
 //!
@@ -46,18 +47,45 @@
 //!
 //! ### Feature Flags
 //!
+//! * `std` (Enabled by default)
+//!
+//!     Links the standard library. Without it, RustFFT is a `#![no_std]` crate that only requires `alloc`, and [`libm`](https://crates.io/crates/libm) computes the trigonometry for twiddle factors. The `avx` feature requires `std`.
+//!
 //! * `avx` (Enabled by default)
 //!
 //!     On x86_64, the `avx` feature enables compilation of AVX-accelerated code. Enabling it greatly improves performance if the
 //!     client CPU supports AVX, while disabling it reduces compile time and binary size.
 //!     On every other platform, this feature does nothing, and RustFFT will behave like it's not set.
 //!
+//! * `half` (Disabled by default)
+//!
+//!     Enables the [`half_precision`](crate::half_precision) module, which computes FFTs of `Complex<f16>` and `Complex<bf16>` data from the [`half`](https://crates.io/crates/half) crate, converting it to f32 for the computation.
+//!
+//! * `const-generics` (Disabled by default)
+//!
+//!     Enables [`ConstSizeFft`](crate::algorithm::ConstSizeFft), a power-of-two FFT whose size is a const generic parameter. It stores its twiddle factors inline, so it never allocates. Requires Rust 1.51 or newer.
+//!
 //! ### Normalization
 //!
-//! RustFFT does not normalize outputs. Callers must manually normalize the results by scaling each element by
+//! By default, RustFFT does not normalize outputs. Callers can manually normalize the results by scaling each element by
 //! `1/len().sqrt()`. Multiple normalization steps can be merged into one via pairwise multiplication, so when
 //! doing a forward FFT followed by an inverse callers can normalize once by scaling each element by `1/len()`
 //!
+//! Alternatively, the normalization can be chosen when planning a FFT, by passing a [`FftNormalization`](crate::FftNormalization)
+//! to [`plan_fft_normalized`](crate::FftPlanner::plan_fft_normalized). The returned FFT instance folds the scale factor into
+//! work it already does, so it's cheaper than a separate scaling pass.
+//!
+//! ```
+//! // Perform a forward FFT of size 1234, scaled by 1/sqrt(1234)
+//! use rustfft::{FftPlanner, FftDirection, FftNormalization, num_complex::Complex};
+//!
+//! let mut planner = FftPlanner::new();
+//! let fft = planner.plan_fft_normalized(1234, FftDirection::Forward, FftNormalization::Unitary);
+//!
+//! let mut buffer = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 1234];
+//! fft.process(&mut buffer);
+//! ```
+//!
 //! ### Output Order
 //!
 //! Elements in the output are ordered by ascending frequency, with the first element corresponding to frequency 0.
@@ -87,7 +115,10 @@
 //! If that's too slow, see if you can find a nearby size whose prime factors are all 11 or smaller, and you can expect a 2x-5x speedup.
 //! If that's still too slow, find a nearby size whose prime factors are all 2 or 3, and you can expect a 1.1x-1.5x speedup.
 
-use std::fmt::Display;
+#[macro_use]
+extern crate alloc;
+
+use core::fmt::Display;
 
 pub use num_complex;
 pub use num_traits;
@@ -98,16 +129,29 @@ mod common;
 /// Individual FFT algorithms
 pub mod algorithm;
 mod array_utils;
+/// An extended-precision `DoubleDouble` float type, with twiddle factors accurate to its full precision
+pub mod double_double;
 mod fft_cache;
+/// Fixed-point (Q15 and Q31) FFTs, with saturating arithmetic and per-stage scaling
+pub mod fixed_point;
+/// FFTs of half-precision (f16 and bf16) data, computed internally in f32
+#[cfg(feature = "half")]
+pub mod half_precision;
 mod math_utils;
 mod plan;
+/// FFT-based and polyphase signal resampling
+pub mod resample;
+/// Spectral analysis helpers: cepstra, FFT shifts and frequency axes
+pub mod spectral;
 mod twiddles;
 
 use num_complex::Complex;
 use num_traits::Zero;
 
 pub use crate::common::FftNum;
-pub use crate::plan::{FftPlanner, FftPlannerScalar};
+pub use crate::fft_cache::TwiddleStats;
+pub use crate::plan::{FftPlanner, FftPlannerFixed, FftPlannerScalar};
+pub use crate::twiddles::TwiddlePrecision;
 
 /// A trait that allows FFT algorithms to report their expected input/output size
 pub trait Length {
@@ -134,7 +178,7 @@ impl FftDirection {
     }
 }
 impl Display for FftDirection {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> Result<(), ::std::fmt::Error> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> Result<(), ::core::fmt::Error> {
         match self {
             Self::Forward => f.write_str("Forward"),
             Self::Inverse => f.write_str("Inverse"),
@@ -142,6 +186,155 @@ impl Display for FftDirection {
     }
 }
 
+/// Represents a normalization mode for FFT outputs, chosen when a FFT is planned.
+///
+/// Normalized FFT instances fold the scale factor into precomputed data or into an existing pass over the data, so normalization
+/// doesn't require a separate pass over the buffer.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FftNormalization {
+    /// Don't scale the output. This is what FFT instances do if no normalization is requested.
+    None,
+    /// Scale the output of both forward and inverse FFTs by `1/len().sqrt()`, making the FFT unitary.
+    Unitary,
+    /// Scale the output of forward FFTs by `1/len()`, and don't scale the output of inverse FFTs.
+    ForwardByN,
+    /// Scale the output of inverse FFTs by `1/len()`, and don't scale the output of forward FFTs.
+    InverseByN,
+}
+impl FftNormalization {
+    /// Returns the factor that the output of a FFT of size `len` in direction `direction` will be multiplied by.
+    pub fn scale_factor(&self, len: usize, direction: FftDirection) -> f64 {
+        if len == 0 {
+            return 1.0;
+        }
+        match (self, direction) {
+            (Self::None, _) => 1.0,
+            (Self::Unitary, _) => 1.0 / num_traits::Float::sqrt(len as f64),
+            (Self::ForwardByN, FftDirection::Forward) => 1.0 / len as f64,
+            (Self::InverseByN, FftDirection::Inverse) => 1.0 / len as f64,
+            (Self::ForwardByN, FftDirection::Inverse) => 1.0,
+            (Self::InverseByN, FftDirection::Forward) => 1.0,
+        }
+    }
+}
+
+/// An error returned by the fallible `try_*` methods of RustFFT, when a FFT can't be computed or an algorithm can't be constructed
+/// with the provided arguments.
+///
+/// The infallible versions of these methods panic in the same situations, with the same message that this type's `Display`
+/// implementation produces. With the `std` feature enabled, this type implements `std::error::Error`.
+///
+/// More variants may be added in future versions, so matches on this type need a wildcard arm.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[non_exhaustive]
+pub enum FftError {
+    /// The provided buffer was shorter than the FFT length.
+    BufferTooSmall {
+        expected_len: usize,
+        actual_len: usize,
+    },
+    /// The provided buffer's length was not a multiple of the FFT length.
+    BufferNotMultiple {
+        expected_multiple: usize,
+        actual_len: usize,
+    },
+    /// The provided scratch buffer was shorter than the algorithm requires.
+    ScratchTooSmall {
+        expected_len: usize,
+        actual_len: usize,
+    },
+    /// The provided input and output buffers had different lengths.
+    MismatchedBuffers { input_len: usize, output_len: usize },
+    /// The algorithm requires a power-of-two length.
+    NotPowerOfTwo { len: usize },
+    /// The algorithm requires a length whose only prime factors are 2 and 3.
+    UnsupportedFactors { len: usize },
+    /// The algorithm requires a prime length.
+    NotPrime { len: usize },
+    /// The algorithm requires its width and height to be coprime.
+    NotCoprime { width: usize, height: usize },
+    /// The provided inner FFT was too short for the requested length.
+    InnerFftTooSmall {
+        expected_len: usize,
+        actual_len: usize,
+    },
+    /// The provided inner FFTs compute FFTs in different directions.
+    MismatchedDirections {
+        width_direction: FftDirection,
+        height_direction: FftDirection,
+    },
+    /// The provided inner FFT requires more scratch space than the algorithm can give it. The `*Small` algorithms require inner FFTs with
+    /// no out-of-place scratch, and at most `len` in-place scratch.
+    InnerFftScratchTooLarge {
+        len: usize,
+        inplace_scratch_len: usize,
+        outofplace_scratch_len: usize,
+    },
+}
+impl Display for FftError {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> Result<(), ::core::fmt::Error> {
+        match *self {
+            Self::BufferTooSmall { expected_len, actual_len } => write!(
+                f,
+                "Provided FFT buffer was too small. Expected len = {}, got len = {}",
+                expected_len, actual_len
+            ),
+            Self::BufferNotMultiple { expected_multiple, actual_len } => write!(
+                f,
+                "Input FFT buffer must be a multiple of FFT length. Expected multiple of {}, got len = {}",
+                expected_multiple, actual_len
+            ),
+            Self::ScratchTooSmall { expected_len, actual_len } => write!(
+                f,
+                "Not enough scratch space was provided. Expected scratch len >= {}, got scratch len = {}",
+                expected_len, actual_len
+            ),
+            Self::MismatchedBuffers { input_len, output_len } => write!(
+                f,
+                "Provided FFT input buffer and output buffer must have the same length. Got input.len() = {}, output.len() = {}",
+                input_len, output_len
+            ),
+            Self::NotPowerOfTwo { len } => write!(
+                f,
+                "This FFT algorithm requires a power-of-two input size. Got {}",
+                len
+            ),
+            Self::UnsupportedFactors { len } => write!(
+                f,
+                "This FFT algorithm requires an input size whose only prime factors are 2 and 3. Got {}",
+                len
+            ),
+            Self::NotPrime { len } => write!(
+                f,
+                "This FFT algorithm requires a prime input size. Got {}",
+                len
+            ),
+            Self::NotCoprime { width, height } => write!(
+                f,
+                "Invalid width and height for Good-Thomas Algorithm (width={}, height={}): Inputs must be coprime",
+                width, height
+            ),
+            Self::InnerFftTooSmall { expected_len, actual_len } => write!(
+                f,
+                "The provided inner FFT is too small for this FFT algorithm and input size. Expected inner_fft.len() >= {}, got {}",
+                expected_len, actual_len
+            ),
+            Self::MismatchedDirections { width_direction, height_direction } => write!(
+                f,
+                "width_fft and height_fft must have the same direction. got width direction={}, height direction={}",
+                width_direction, height_direction
+            ),
+            Self::InnerFftScratchTooLarge { len, inplace_scratch_len, outofplace_scratch_len } => write!(
+                f,
+                "Inner FFTs of small algorithms must require 0 out-of-place scratch and at most their own length of inplace scratch. Inner FFT (len={}) requires {} inplace and {} out-of-place",
+                len, inplace_scratch_len, outofplace_scratch_len
+            ),
+        }
+    }
+}
+#[cfg(feature = "std")]
+impl std::error::Error for FftError {}
+
 /// A trait that allows FFT algorithms to report whether they compute forward FFTs or inverse FFTs
 pub trait Direction {
     /// Returns FftDirection::Forward if this instance computes forward FFTs, or FftDirection::Inverse for inverse FFTs
@@ -207,6 +400,166 @@ pub trait Fft<T: FftNum>: Length + Direction + Sync + Send {
         scratch: &mut [Complex<T>],
     );
 
+    /// Divides `input` and `output` into chunks of size `self.len()`, and computes a FFT on each chunk, without modifying `input`.
+    ///
+    /// Unlike `process_outofplace_with_scratch`, this method never uses `input` as scratch space, so it can compute FFTs directly from
+    /// read-only buffers, like an `Arc<[Complex<T>]>` shared between several consumers, or a memory-mapped file.
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage after calling.
+    ///
+    /// Algorithms without a specialized implementation copy `input` to `output`, then compute the FFT in-place.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// - `output.len() != input.len()`
+    /// - `input.len() % self.len() > 0`
+    /// - `input.len() < self.len()`
+    /// - `scratch.len() < self.get_immutable_scratch_len()`
+    fn process_immutable(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        if output.len() != input.len() {
+            common::fft_error_outofplace(
+                self.len(),
+                input.len(),
+                output.len(),
+                self.get_immutable_scratch_len(),
+                scratch.len(),
+            );
+            return; // Unreachable, because fft_error_outofplace asserts, but it helps codegen to put it here
+        }
+        output.copy_from_slice(input);
+        self.process_with_scratch(output, scratch);
+    }
+
+    /// Divides `re` and `im` into chunks of size `self.len()`, and computes a FFT on each chunk of split-complex data, where `re` holds the
+    /// real parts and `im` holds the imaginary parts.
+    ///
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage after calling.
+    ///
+    /// By default, each chunk is interleaved into `scratch`, transformed in place, and split back out before moving on to the next chunk.
+    /// The AVX mixed radix steps and the AVX implementation of Bluestein's algorithm instead read the split-complex input in their first pass
+    /// and write the split-complex output in their last pass, so they don't need separate conversion passes.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if:
+    /// - `im.len() != re.len()`
+    /// - `re.len() % self.len() > 0`
+    /// - `re.len() < self.len()`
+    /// - `scratch.len() < self.get_split_scratch_len()`
+    fn process_split(&self, re: &mut [T], im: &mut [T], scratch: &mut [Complex<T>]) {
+        if self.len() == 0 {
+            return;
+        }
+
+        let required_scratch = self.get_split_scratch_len();
+        if scratch.len() < required_scratch || re.len() < self.len() || im.len() != re.len() {
+            common::fft_error_split(
+                self.len(),
+                re.len(),
+                im.len(),
+                required_scratch,
+                scratch.len(),
+            );
+            return; // Unreachable, because fft_error_split asserts, but it helps codegen to put it here
+        }
+
+        let (chunk_buffer, inner_scratch) = scratch[..required_scratch].split_at_mut(self.len());
+        let result = array_utils::iter_chunks_zipped(re, im, self.len(), |re_chunk, im_chunk| {
+            array_utils::interleave_split(re_chunk, im_chunk, chunk_buffer);
+            self.process_with_scratch(chunk_buffer, inner_scratch);
+            array_utils::deinterleave_split(chunk_buffer, re_chunk, im_chunk);
+        });
+
+        if result.is_err() {
+            common::fft_error_split(
+                self.len(),
+                re.len(),
+                im.len(),
+                required_scratch,
+                scratch.len(),
+            );
+        }
+    }
+
+    /// Computes a FFT in-place, returning an error instead of panicking if `buffer` has an invalid length.
+    ///
+    /// Convenience method that allocates a `Vec` with the required scratch space and calls `self.try_process_with_scratch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the situations where [`process`](crate::Fft::process) would panic.
+    fn try_process(&self, buffer: &mut [Complex<T>]) -> Result<(), FftError> {
+        let mut scratch = vec![Complex::zero(); self.get_inplace_scratch_len()];
+        self.try_process_with_scratch(buffer, &mut scratch)
+    }
+
+    /// Divides `buffer` into chunks of size `self.len()`, and computes a FFT on each chunk, returning an error instead of panicking
+    /// if `buffer` or `scratch` have invalid lengths.
+    ///
+    /// Uses the `scratch` buffer as scratch space, so the contents of `scratch` should be considered garbage
+    /// after calling. If an error is returned, neither buffer is modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the situations where [`process_with_scratch`](crate::Fft::process_with_scratch) would panic.
+    fn try_process_with_scratch(
+        &self,
+        buffer: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) -> Result<(), FftError> {
+        if self.len() > 0 {
+            common::validate_inplace(
+                self.len(),
+                buffer.len(),
+                self.get_inplace_scratch_len(),
+                scratch.len(),
+            )?;
+        }
+        self.process_with_scratch(buffer, scratch);
+        Ok(())
+    }
+
+    /// Divides `input` and `output` into chunks of size `self.len()`, and computes a FFT on each chunk, returning an error instead
+    /// of panicking if any of the buffers have invalid lengths.
+    ///
+    /// This method uses both the `input` buffer and `scratch` buffer as scratch space, so the contents of both should be
+    /// considered garbage after calling. If an error is returned, none of the buffers are modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error in the situations where [`process_outofplace_with_scratch`](crate::Fft::process_outofplace_with_scratch)
+    /// would panic.
+    fn try_process_outofplace_with_scratch(
+        &self,
+        input: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) -> Result<(), FftError> {
+        if self.len() > 0 {
+            common::validate_outofplace(
+                self.len(),
+                input.len(),
+                output.len(),
+                self.get_outofplace_scratch_len(),
+                scratch.len(),
+            )?;
+        }
+        self.process_outofplace_with_scratch(input, output, scratch);
+        Ok(())
+    }
+
+    /// Returns this FFT's fused half-precision implementation, if it can convert `f16` data in its first and last passes.
+    /// Used by [`HalfPrecisionFft`](crate::half_precision::HalfPrecisionFft). Returns `None` by default.
+    #[cfg(feature = "half")]
+    fn fused_f16(&self) -> Option<&dyn half_precision::FusedHalfFft<half::f16>> {
+        None
+    }
+
     /// Returns the size of the scratch buffer required by `process_with_scratch`
     ///
     /// For most FFT sizes, this method will return `self.len()`. For a few small sizes it will return 0, and for some special FFT sizes
@@ -220,6 +573,23 @@ pub trait Fft<T: FftNum>: Length + Direction + Sync + Send {
     /// (Sizes that require the use of Bluestein's Algorithm), this may return a scratch size larger than `self.len()`.
     /// The returned value may change from one version of RustFFT to the next.
     fn get_outofplace_scratch_len(&self) -> usize;
+
+    /// Returns the size of the scratch buffer required by `process_immutable`
+    ///
+    /// For algorithms without a specialized `process_immutable` implementation, this is the same as `self.get_inplace_scratch_len()`.
+    /// The returned value may change from one version of RustFFT to the next.
+    fn get_immutable_scratch_len(&self) -> usize {
+        self.get_inplace_scratch_len()
+    }
+
+    /// Returns the size of the scratch buffer required by `process_split`
+    ///
+    /// For algorithms without a specialized `process_split` implementation, this is `self.len()` for the interleaved copy of each chunk,
+    /// plus `self.get_inplace_scratch_len()`.
+    /// The returned value may change from one version of RustFFT to the next.
+    fn get_split_scratch_len(&self) -> usize {
+        self.len() + self.get_inplace_scratch_len()
+    }
 }
 
 // Algorithms implemented to use AVX instructions. Only compiled on x86_64, and only compiled if the "avx" feature flag is set.
@@ -231,8 +601,8 @@ mod avx;
 #[cfg(not(all(target_arch = "x86_64", feature = "avx")))]
 mod avx {
     pub mod avx_planner {
-        use crate::{Fft, FftDirection, FftNum};
-        use std::sync::Arc;
+        use crate::{Fft, FftDirection, FftNormalization, FftNum, TwiddleStats};
+        use alloc::sync::Arc;
 
         /// The AVX FFT planner creates new FFT algorithm instances which take advantage of the AVX instruction set.
         ///
@@ -268,7 +638,7 @@ mod avx {
         /// Each FFT instance owns [`Arc`s](std::sync::Arc) to its internal data, rather than borrowing it from the planner, so it's perfectly
         /// safe to drop the planner after creating Fft instances.
         pub struct FftPlannerAvx<T: FftNum> {
-            _phantom: std::marker::PhantomData<T>,
+            _phantom: core::marker::PhantomData<T>,
         }
         impl<T: FftNum> FftPlannerAvx<T> {
             /// Constructs a new `FftPlannerAvx` instance.
@@ -298,6 +668,58 @@ mod avx {
             pub fn plan_fft_inverse(&mut self, _len: usize) -> Arc<dyn Fft<T>> {
                 unreachable!()
             }
+            /// Returns a pruned `Fft` instance which computes FFTs of size `len`, where only some of the inputs are nonzero, and only some of the outputs are needed.
+            ///
+            /// The returned instance expects every input element at index `nonzero_inputs` or above to be zero, and may skip reading them. Only the first
+            /// `needed_outputs` output elements are guaranteed to be correct, and the remaining output elements will contain garbage.
+            pub fn plan_fft_pruned(
+                &mut self,
+                _len: usize,
+                _direction: FftDirection,
+                _nonzero_inputs: usize,
+                _needed_outputs: usize,
+            ) -> Arc<dyn Fft<T>> {
+                unreachable!()
+            }
+            /// Returns a `Fft` instance which uses AVX instructions to compute FFTs of size `len`, and scales its output according to `normalization`.
+            ///
+            /// The output is multiplied by `normalization.scale_factor(len, direction)`.
+            pub fn plan_fft_normalized(
+                &mut self,
+                _len: usize,
+                _direction: FftDirection,
+                _normalization: FftNormalization,
+            ) -> Arc<dyn Fft<T>> {
+                unreachable!()
+            }
+            /// Sets the maximum number of FFT instances this planner keeps in its cache.
+            pub fn set_max_cached_ffts(&mut self, _count: Option<usize>) {
+                unreachable!()
+            }
+            /// Sets the maximum memory in bytes that this planner's caches may use.
+            pub fn set_max_cache_memory(&mut self, _bytes: Option<usize>) {
+                unreachable!()
+            }
+            /// Removes every FFT instance and all shared precomputed data from this planner's caches.
+            pub fn clear(&mut self) {
+                unreachable!()
+            }
+            /// Removes the FFT instances of size `len` from this planner's cache, in both directions.
+            pub fn evict(&mut self, _len: usize) {
+                unreachable!()
+            }
+            /// Returns the number of FFT instances in this planner's cache. Each direction counts separately.
+            pub fn cached_fft_count(&self) -> usize {
+                unreachable!()
+            }
+            /// Returns the estimated memory in bytes used by this planner's caches.
+            pub fn cache_memory(&self) -> usize {
+                unreachable!()
+            }
+            /// Returns statistics about the twiddle factor tables this planner has shared between the FFT instances it created.
+            pub fn twiddle_stats(&self) -> TwiddleStats {
+                unreachable!()
+            }
         }
     }
 }
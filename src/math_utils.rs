@@ -1,4 +1,7 @@
-use num_traits::{One, PrimInt, Zero};
+use alloc::vec::Vec;
+use core::cmp::max;
+use num_integer::div_ceil;
+use num_traits::{Float, One, PrimInt, Zero};
 
 pub fn primitive_root(prime: u64) -> Option<u64> {
     let test_exponents: Vec<u64> = distinct_prime_factors(prime - 1)
@@ -36,6 +39,54 @@ pub fn modular_exponent<T: PrimInt>(mut base: T, mut exponent: T, modulo: T) ->
     result
 }
 
+/// Returns true if `n` is prime
+pub fn is_prime(n: u64) -> bool {
+    #[cfg(feature = "std")]
+    {
+        primal_check::miller_rabin(n)
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        deterministic_miller_rabin(n)
+    }
+}
+
+/// Without the standard library we can't use primal_check, so is_prime() falls back to this Miller-Rabin test.
+/// Testing with the first 12 primes as witnesses gives the correct answer for every u64.
+#[cfg(any(not(feature = "std"), test))]
+fn deterministic_miller_rabin(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    for &witness in &WITNESSES {
+        if n % witness == 0 {
+            return n == witness;
+        }
+    }
+
+    // write n - 1 as d * 2^s, with d odd
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    // do the arithmetic in u128, so that the products in modular_exponent can't overflow
+    let n_wide = n as u128;
+    'next_witness: for &witness in &WITNESSES {
+        let mut x = modular_exponent(witness as u128, d as u128, n_wide);
+        if x == 1 || x == n_wide - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = x * x % n_wide;
+            if x == n_wide - 1 {
+                continue 'next_witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
 /// return all of the prime factors of n, but omit duplicate prime factors
 pub fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
     let mut result = Vec::new();
@@ -49,7 +100,7 @@ pub fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
     }
     if n > 1 {
         let mut divisor = 3;
-        let mut limit = (n as f32).sqrt() as u64 + 1;
+        let mut limit = Float::sqrt(n as f32) as u64 + 1;
         while divisor < limit {
             if n % divisor == 0 {
                 // remove as many factors as possible from n
@@ -59,7 +110,7 @@ pub fn distinct_prime_factors(mut n: u64) -> Vec<u64> {
                 result.push(divisor);
 
                 // recalculate the limit to reduce the amount of work we need to do
-                limit = (n as f32).sqrt() as u64 + 1;
+                limit = Float::sqrt(n as f32) as u64 + 1;
             }
 
             divisor += 2;
@@ -121,7 +172,7 @@ impl PrimeFactors {
         if n > 1 {
             let mut divisor = 5;
             // compute divisor limit. if our divisor goes above this limit, we know we won't find any more factors. we'll revise it downwards as we discover factors.
-            let mut limit = (n as f32).sqrt() as usize + 1;
+            let mut limit = Float::sqrt(n as f32) as usize + 1;
             while divisor < limit {
                 // Count how many times this divisor divesthe remaining input
                 let mut count = 0;
@@ -140,7 +191,7 @@ impl PrimeFactors {
                     result.distinct_factor_count += 1;
 
                     // recalculate the limit to reduce the amount of other factors we need to check
-                    limit = (n as f32).sqrt() as usize + 1;
+                    limit = Float::sqrt(n as f32) as usize + 1;
                 }
 
                 divisor += 2;
@@ -332,6 +383,40 @@ impl PrimeFactors {
             (Self::compute(left_product), Self::compute(right_product))
         }
     }
+
+    // Splits this set of prime factors into two coprime sets, by putting every power of each prime entirely into one set or the other,
+    // so that the products of the two sets are as close as possible. Returns None if there's only one distinct prime factor
+    pub fn partition_coprime_factors(&self) -> Option<(Self, Self)> {
+        if self.distinct_factor_count < 2 {
+            return None;
+        }
+
+        let mut prime_powers: Vec<usize> = self
+            .other_factors
+            .iter()
+            .map(|factor| factor.value.pow(factor.count))
+            .collect();
+        if self.power_two > 0 {
+            prime_powers.push(1 << self.power_two);
+        }
+        if self.power_three > 0 {
+            prime_powers.push(3usize.pow(self.power_three));
+        }
+
+        // greedily put each prime power into whichever half is smaller. Starting with the largest powers keeps the halves more balanced
+        prime_powers.sort_unstable_by(|a, b| b.cmp(a));
+        let mut left_product = 1;
+        let mut right_product = 1;
+        for prime_power in prime_powers {
+            if left_product <= right_product {
+                left_product *= prime_power;
+            } else {
+                right_product *= prime_power;
+            }
+        }
+
+        Some((Self::compute(left_product), Self::compute(right_product)))
+    }
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -453,10 +538,134 @@ impl PartialFactors {
     }
 }
 
+/// Estimates the relative cost of FFTs whose lengths only have the prime factors 2, 3, 5, 7 and 11, to choose the inner FFT length of convolution-based algorithms like Bluestein's.
+///
+/// The cost of a length is the length times the sum of a per-pass cost for each of its prime factors. The per-pass costs were fit to benchmarks of every such length up to about a million, separately for each planner.
+#[derive(Copy, Clone, Debug)]
+pub struct SmoothCostModel {
+    // The relative cost of one pass over the data for a factor of 2, 3, 5, 7 and 11, respectively
+    pass_costs: [usize; 5],
+    // Chosen lengths are always a multiple of 2^min_power2. The AVX implementation of Bluestein's algorithm needs its inner FFT length to be a multiple of its vector width
+    min_power2: u32,
+}
+impl SmoothCostModel {
+    pub const SCALAR: Self = Self {
+        pass_costs: [10, 26, 44, 46, 52],
+        min_power2: 0,
+    };
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
+    pub const AVX_F32: Self = Self {
+        pass_costs: [10, 24, 53, 58, 71],
+        min_power2: 2,
+    };
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
+    pub const AVX_F64: Self = Self {
+        pass_costs: [10, 20, 39, 43, 53],
+        min_power2: 1,
+    };
+
+    /// Returns the estimated cost of a FFT of the given length. The length may not have any prime factors larger than 11.
+    pub fn cost(&self, len: usize) -> usize {
+        let factors = PartialFactors::compute(len);
+        assert_eq!(
+            factors.get_other_factors(),
+            1,
+            "len {} has prime factors larger than 11",
+            len
+        );
+        let passes = [
+            factors.get_power2(),
+            factors.get_power3(),
+            factors.get_power5(),
+            factors.get_power7(),
+            factors.get_power11(),
+        ];
+        let pass_cost: usize = passes
+            .iter()
+            .zip(self.pass_costs.iter())
+            .map(|(passes, cost)| *passes as usize * cost)
+            .sum();
+        len * pass_cost
+    }
+
+    /// Returns the cheapest length that's at least `min_len`, only has the prime factors 2, 3, 5, 7 and 11, and is a multiple of this model's minimum power of two.
+    /// The result is never larger than the next power of two (or the minimum power of two, if that's larger), and ties go to the shorter length.
+    pub fn choose_len(&self, min_len: usize) -> usize {
+        let max_len = max(
+            min_len.checked_next_power_of_two().unwrap(),
+            1 << self.min_power2,
+        );
+
+        // For each product of odd factors, the best candidate is the smallest power of two times it that's at least min_len
+        let mut best_len = max_len;
+        let mut best_cost = self.cost(max_len);
+        let mut product11 = 1;
+        while product11 <= max_len {
+            let mut product7 = product11;
+            while product7 <= max_len {
+                let mut product5 = product7;
+                while product5 <= max_len {
+                    let mut product3 = product5;
+                    while product3 <= max_len {
+                        let power2 = div_ceil(min_len, product3)
+                            .next_power_of_two()
+                            .trailing_zeros();
+                        let candidate = product3 << max(power2, self.min_power2);
+                        if candidate <= max_len {
+                            let cost = self.cost(candidate);
+                            if cost < best_cost || (cost == best_cost && candidate < best_len) {
+                                best_len = candidate;
+                                best_cost = cost;
+                            }
+                        }
+                        product3 *= 3;
+                    }
+                    product5 *= 5;
+                }
+                product7 *= 7;
+            }
+            product11 *= 11;
+        }
+        best_len
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_deterministic_miller_rabin() {
+        for n in 0..10000 {
+            assert_eq!(
+                deterministic_miller_rabin(n),
+                primal_check::miller_rabin(n),
+                "n = {}",
+                n
+            );
+        }
+
+        // large primes, and large strong pseudoprimes for several of the witnesses
+        for &n in &[
+            4294967291,
+            18446744073709551557,
+            3215031751,
+            2152302898747,
+            3474749660383,
+            341550071728321,
+            3825123056546413051,
+            18446744073709551615,
+        ] {
+            assert_eq!(
+                deterministic_miller_rabin(n),
+                primal_check::miller_rabin(n),
+                "n = {}",
+                n
+            );
+        }
+    }
+
     #[test]
     fn test_modular_exponent() {
         // make sure to test something that would overflow under ordinary circumstances
@@ -665,6 +874,38 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_partition_coprime_factors() {
+        for n in 2..200 {
+            let factors = PrimeFactors::compute(n);
+            if let Some((left_factors, right_factors)) = factors.partition_coprime_factors() {
+                assert!(left_factors.get_product() > 1);
+                assert!(right_factors.get_product() > 1);
+
+                assert_eq!(left_factors.get_product() * right_factors.get_product(), n);
+                assert_eq!(
+                    num_integer::gcd(left_factors.get_product(), right_factors.get_product()),
+                    1
+                );
+
+                assert_internally_consistent(&left_factors);
+                assert_internally_consistent(&right_factors);
+            } else {
+                // If the method returned None, n must be a power of a single prime
+                assert_eq!(factors.get_distinct_factor_count(), 1);
+            }
+        }
+
+        // 13^2 * 17^2 is a perfect square, so partition_factors can't split it into coprime halves, but partition_coprime_factors can
+        let (left_factors, right_factors) = PrimeFactors::compute(169 * 289)
+            .partition_coprime_factors()
+            .unwrap();
+        assert_eq!(
+            (left_factors.get_product(), right_factors.get_product()),
+            (289, 169)
+        );
+    }
+
     #[test]
     fn test_remove_factors() {
         // For every possible factor of a bunch of factors, they removing each and making sure the result is internally consistent
@@ -911,4 +1152,46 @@ mod unit_tests {
             }
         }
     }
+
+    #[test]
+    fn test_smooth_cost_model_choose_len() {
+        for model in &[
+            SmoothCostModel::SCALAR,
+            SmoothCostModel::AVX_F32,
+            SmoothCostModel::AVX_F64,
+        ] {
+            for min_len in 1..3000 {
+                let chosen_len = model.choose_len(min_len);
+                let max_len = max(min_len.next_power_of_two(), 1 << model.min_power2);
+                assert!(
+                    chosen_len.trailing_zeros() >= model.min_power2,
+                    "min_len = {}, chosen_len = {}",
+                    min_len,
+                    chosen_len
+                );
+                assert!(
+                    chosen_len >= min_len && chosen_len <= max_len,
+                    "min_len = {}, chosen_len = {}",
+                    min_len,
+                    chosen_len
+                );
+
+                // Compare against a brute force search of every candidate
+                let expected_len = (min_len..=max_len)
+                    .filter(|len| PartialFactors::compute(*len).get_other_factors() == 1)
+                    .filter(|len| len.trailing_zeros() >= model.min_power2)
+                    .min_by_key(|len| model.cost(*len))
+                    .unwrap();
+                assert_eq!(
+                    model.cost(chosen_len),
+                    model.cost(expected_len),
+                    "min_len = {}, chosen_len = {}, expected_len = {}",
+                    min_len,
+                    chosen_len,
+                    expected_len
+                );
+                assert!(chosen_len <= expected_len);
+            }
+        }
+    }
 }
@@ -1,8 +1,11 @@
-use num_integer::gcd;
-use std::collections::HashMap;
-use std::sync::Arc;
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::cmp::min;
+use num_integer::{div_ceil, gcd};
 
-use crate::{common::FftNum, fft_cache::FftCache, FftDirection};
+use crate::fft_cache::{exceeds, ChirpSpectrumCache, FftCache, LruMap, TwiddleStats, TwiddleStore};
+use crate::fixed_point::{FixedFft, FixedPoint, FixedScaling, RoundingMode};
+use crate::{common::FftNum, twiddles, FftDirection, FftNormalization};
 
 use crate::algorithm::butterflies::*;
 use crate::algorithm::*;
@@ -10,10 +13,11 @@ use crate::Fft;
 
 use crate::FftPlannerAvx;
 
-use crate::math_utils::{PrimeFactor, PrimeFactors};
+use crate::math_utils::{PrimeFactor, PrimeFactors, SmoothCostModel};
 
 enum ChosenFftPlanner<T: FftNum> {
-    Scalar(FftPlannerScalar<T>),
+    // The scalar planner holds all of its caches inline, so box it to keep this enum small
+    Scalar(Box<FftPlannerScalar<T>>),
     Avx(FftPlannerAvx<T>),
     // todo: If we add NEON, SSE, avx-512 etc support, add more enum variants for them here
 }
@@ -65,7 +69,7 @@ impl<T: FftNum> FftPlanner<T> {
             }
         } else {
             Self {
-                chosen_planner: ChosenFftPlanner::Scalar(FftPlannerScalar::new()),
+                chosen_planner: ChosenFftPlanner::Scalar(Box::new(FftPlannerScalar::new())),
             }
         }
     }
@@ -95,12 +99,134 @@ impl<T: FftNum> FftPlanner<T> {
     pub fn plan_fft_inverse(&mut self, len: usize) -> Arc<dyn Fft<T>> {
         self.plan_fft(len, FftDirection::Inverse)
     }
+
+    /// Returns a pruned `Fft` instance which computes FFTs of size `len`, where only some of the inputs are nonzero, and only some of the outputs are needed.
+    ///
+    /// The returned instance expects every input element at index `nonzero_inputs` or above to be zero, and may skip reading them. Only the first
+    /// `needed_outputs` output elements are guaranteed to be correct, and the remaining output elements will contain garbage.
+    /// Where possible, the returned instance skips computing sub-FFTs that only process zeroes or only produce unneeded outputs.
+    ///
+    /// This is useful for zero-padded FFTs, and for FFTs where only the lowest frequency bins are needed.
+    /// Values of `nonzero_inputs` and `needed_outputs` larger than `len` are treated as `len`.
+    ///
+    /// Pruned FFT instances are not cached, but their inner FFTs are.
+    pub fn plan_fft_pruned(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Arc<dyn Fft<T>> {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => {
+                scalar_planner.plan_fft_pruned(len, direction, nonzero_inputs, needed_outputs)
+            }
+            ChosenFftPlanner::Avx(avx_planner) => {
+                avx_planner.plan_fft_pruned(len, direction, nonzero_inputs, needed_outputs)
+            }
+        }
+    }
+
+    /// Returns a `Fft` instance which computes FFTs of size `len`, and scales its output according to `normalization`.
+    ///
+    /// The output is multiplied by `normalization.scale_factor(len, direction)`. Wherever possible, the scale is folded into
+    /// data the FFT algorithms precompute anyways, so normalized FFTs are usually just as fast as unnormalized ones.
+    ///
+    /// If the scale factor is 1, this is equivalent to `plan_fft`. Otherwise, the outermost step of the FFT is not cached, but its inner FFTs are.
+    pub fn plan_fft_normalized(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Arc<dyn Fft<T>> {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => {
+                scalar_planner.plan_fft_normalized(len, direction, normalization)
+            }
+            ChosenFftPlanner::Avx(avx_planner) => {
+                avx_planner.plan_fft_normalized(len, direction, normalization)
+            }
+        }
+    }
+
+    /// Sets the maximum number of FFT instances this planner keeps in its cache.
+    ///
+    /// The planner caches every FFT instance it creates, including the inner FFTs of larger FFTs, and each direction counts separately.
+    /// When the cache holds more instances than this, the least recently used ones are evicted. `None` removes the limit, which is the default.
+    ///
+    /// Evicted instances keep working if they were already returned by the planner, or if another FFT instance uses them.
+    pub fn set_max_cached_ffts(&mut self, count: Option<usize>) {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.set_max_cached_ffts(count),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.set_max_cached_ffts(count),
+        }
+    }
+
+    /// Sets the maximum memory in bytes that this planner's caches may use.
+    ///
+    /// The memory of each cached FFT instance is estimated as `len * size_of::<Complex<T>>()`, which is roughly the size of the data most FFT algorithms precompute.
+    /// The twiddle factor tables and chirp spectra that the planner shares between FFT instances count with their actual size, for as long as any FFT instance uses them.
+    /// When the caches hold more memory than this, the least recently used instances are evicted. `None` removes the limit, which is the default.
+    pub fn set_max_cache_memory(&mut self, bytes: Option<usize>) {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.set_max_cache_memory(bytes),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.set_max_cache_memory(bytes),
+        }
+    }
+
+    /// Removes every FFT instance and all shared precomputed data from this planner's caches.
+    ///
+    /// FFT instances that were already returned by the planner keep working.
+    pub fn clear(&mut self) {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.clear(),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.clear(),
+        }
+    }
+
+    /// Removes the FFT instances of size `len` from this planner's cache, in both directions.
+    ///
+    /// Their inner FFTs stay in the cache. FFT instances that were already returned by the planner keep working.
+    pub fn evict(&mut self, len: usize) {
+        match &mut self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.evict(len),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.evict(len),
+        }
+    }
+
+    /// Returns the number of FFT instances in this planner's cache. Each direction counts separately.
+    pub fn cached_fft_count(&self) -> usize {
+        match &self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.cached_fft_count(),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.cached_fft_count(),
+        }
+    }
+
+    /// Returns the estimated memory in bytes used by this planner's caches, as described in [`set_max_cache_memory`](Self::set_max_cache_memory).
+    pub fn cache_memory(&self) -> usize {
+        match &self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.cache_memory(),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.cache_memory(),
+        }
+    }
+
+    /// Returns statistics about the twiddle factor tables this planner has shared between the FFT instances it created.
+    pub fn twiddle_stats(&self) -> TwiddleStats {
+        match &self.chosen_planner {
+            ChosenFftPlanner::Scalar(scalar_planner) => scalar_planner.twiddle_stats(),
+            ChosenFftPlanner::Avx(avx_planner) => avx_planner.twiddle_stats(),
+        }
+    }
 }
 
 const MIN_RADIX4_BITS: u32 = 5; // smallest size to consider radix 4 an option is 2^5 = 32
 const MAX_RADIX4_BITS: u32 = 16; // largest size to consider radix 4 an option is 2^16 = 65536
-const MAX_RADER_PRIME_FACTOR: usize = 23; // don't use Raders if the inner fft length has prime factor larger than this
-const MIN_BLUESTEIN_MIXED_RADIX_LEN: usize = 90; // only use mixed radix for the inner fft of Bluestein if length is larger than this
+const MIN_RADIX8_BITS: u32 = 10; // smallest power of two where radix 8 measured faster than radix 4 is 2^10 = 1024
+const MIN_STOCKHAM_LEN: usize = 1536; // smallest 3-smooth size where Stockham consistently beats mixed radix
+const MIN_STOCKHAM_POWER_OF_THREE: u32 = 3; // Stockham's radix-3 passes only beat the mixed radix butterflies when there are at least 3 of them
+const DEFAULT_MIN_SIX_STEP_LEN: usize = 1 << 21; // smallest size where six-step measured faster than mixed radix
+const MAX_GOOD_THOMAS_LEN: usize = 2500; // largest size where good-thomas with a balanced coprime split measured faster than mixed radix on average. above this, they measured about even
+const MAX_RADER_PRIME_FACTOR: usize = 47; // don't use Raders if the inner fft length has prime factor larger than this
 
 /// A Recipe is a structure that describes the design of a FFT, without actually creating it.
 /// It is used as a middle step in the planning process.
@@ -111,7 +237,10 @@ pub enum Recipe {
         left_fft: Arc<Recipe>,
         right_fft: Arc<Recipe>,
     },
-    #[allow(dead_code)]
+    SixStep {
+        left_fft: Arc<Recipe>,
+        right_fft: Arc<Recipe>,
+    },
     GoodThomasAlgorithm {
         left_fft: Arc<Recipe>,
         right_fft: Arc<Recipe>,
@@ -131,7 +260,13 @@ pub enum Recipe {
         len: usize,
         inner_fft: Arc<Recipe>,
     },
+    RadersAlgorithmPadded {
+        len: usize,
+        inner_fft: Arc<Recipe>,
+    },
     Radix4(usize),
+    Radix8(usize),
+    Stockham(usize),
     Butterfly2,
     Butterfly3,
     Butterfly4,
@@ -139,6 +274,7 @@ pub enum Recipe {
     Butterfly6,
     Butterfly7,
     Butterfly8,
+    Butterfly9Winograd,
     Butterfly11,
     Butterfly13,
     Butterfly16,
@@ -148,6 +284,13 @@ pub enum Recipe {
     Butterfly29,
     Butterfly31,
     Butterfly32,
+    Butterfly37,
+    Butterfly41,
+    Butterfly43,
+    Butterfly47,
+    Butterfly53,
+    Butterfly59,
+    Butterfly61,
 }
 
 impl Recipe {
@@ -155,6 +298,8 @@ impl Recipe {
         match self {
             Recipe::Dft(length) => *length,
             Recipe::Radix4(length) => *length,
+            Recipe::Radix8(length) => *length,
+            Recipe::Stockham(length) => *length,
             Recipe::Butterfly2 => 2,
             Recipe::Butterfly3 => 3,
             Recipe::Butterfly4 => 4,
@@ -162,6 +307,7 @@ impl Recipe {
             Recipe::Butterfly6 => 6,
             Recipe::Butterfly7 => 7,
             Recipe::Butterfly8 => 8,
+            Recipe::Butterfly9Winograd => 9,
             Recipe::Butterfly11 => 11,
             Recipe::Butterfly13 => 13,
             Recipe::Butterfly16 => 16,
@@ -171,10 +317,21 @@ impl Recipe {
             Recipe::Butterfly29 => 29,
             Recipe::Butterfly31 => 31,
             Recipe::Butterfly32 => 32,
+            Recipe::Butterfly37 => 37,
+            Recipe::Butterfly41 => 41,
+            Recipe::Butterfly43 => 43,
+            Recipe::Butterfly47 => 47,
+            Recipe::Butterfly53 => 53,
+            Recipe::Butterfly59 => 59,
+            Recipe::Butterfly61 => 61,
             Recipe::MixedRadix {
                 left_fft,
                 right_fft,
             } => left_fft.len() * right_fft.len(),
+            Recipe::SixStep {
+                left_fft,
+                right_fft,
+            } => left_fft.len() * right_fft.len(),
             Recipe::GoodThomasAlgorithm {
                 left_fft,
                 right_fft,
@@ -189,6 +346,7 @@ impl Recipe {
             } => left_fft.len() * right_fft.len(),
             Recipe::RadersAlgorithm { inner_fft } => inner_fft.len() + 1,
             Recipe::BluesteinsAlgorithm { len, .. } => *len,
+            Recipe::RadersAlgorithmPadded { len, .. } => *len,
         }
     }
 }
@@ -225,7 +383,11 @@ impl Recipe {
 /// safe to drop the planner after creating Fft instances.
 pub struct FftPlannerScalar<T: FftNum> {
     algorithm_cache: FftCache<T>,
-    recipe_cache: HashMap<usize, Arc<Recipe>>,
+    chirp_cache: ChirpSpectrumCache<T>,
+    twiddle_store: TwiddleStore<T>,
+    recipe_cache: LruMap<usize, Arc<Recipe>>,
+    max_cached_recipes: Option<usize>,
+    min_six_step_len: usize,
 }
 
 impl<T: FftNum> FftPlannerScalar<T> {
@@ -233,10 +395,81 @@ impl<T: FftNum> FftPlannerScalar<T> {
     pub fn new() -> Self {
         Self {
             algorithm_cache: FftCache::new(),
-            recipe_cache: HashMap::new(),
+            chirp_cache: ChirpSpectrumCache::new(),
+            twiddle_store: TwiddleStore::new(),
+            recipe_cache: LruMap::new(),
+            max_cached_recipes: None,
+            min_six_step_len: DEFAULT_MIN_SIX_STEP_LEN,
         }
     }
 
+    /// Sets the smallest FFT size that this planner will compute with the [`SixStep`](crate::algorithm::SixStep) algorithm.
+    ///
+    /// At or above this size, every composite FFT size is split into two inner FFTs of roughly `sqrt(len)`, so that the inner FFTs fit in cache,
+    /// instead of being computed with a single power-of-two or `Stockham` step, or a chain of `MixedRadix` steps. The default is 2^21.
+    /// The best value depends on the cache sizes of the machine running the FFT.
+    ///
+    /// Changing the threshold clears this planner's caches, so that FFTs planned afterwards use the new threshold.
+    pub fn set_min_six_step_len(&mut self, len: usize) {
+        self.min_six_step_len = len;
+        self.algorithm_cache.clear();
+        self.recipe_cache.clear();
+    }
+
+    /// Sets the maximum number of FFT instances this planner keeps in its cache.
+    ///
+    /// The planner caches every FFT instance it creates, including the inner FFTs of larger FFTs, and each direction counts separately.
+    /// When the cache holds more instances than this, the least recently used ones are evicted. The planner's cache of FFT recipes is
+    /// limited to the same number of entries. `None` removes the limit, which is the default.
+    ///
+    /// Evicted instances keep working if they were already returned by the planner, or if another FFT instance uses them. Once a capacity is set,
+    /// the planner also releases the precomputed data it shares between FFT instances as soon as no FFT instance uses it anymore.
+    pub fn set_max_cached_ffts(&mut self, count: Option<usize>) {
+        self.algorithm_cache.set_max_entries(count);
+        self.max_cached_recipes = count;
+        self.shrink_recipe_cache();
+        self.release_unused_data();
+    }
+
+    /// Sets the maximum memory in bytes that this planner's caches may use.
+    ///
+    /// The memory of each cached FFT instance is estimated as `len * size_of::<Complex<T>>()`, which is roughly the size of the data most FFT algorithms precompute.
+    /// The twiddle factor tables and chirp spectra that the planner shares between FFT instances count with their actual size, for as long as any FFT instance uses them.
+    /// When the caches hold more memory than this, the least recently used instances are evicted. `None` removes the limit, which is the default.
+    pub fn set_max_cache_memory(&mut self, bytes: Option<usize>) {
+        self.algorithm_cache.set_max_memory(bytes);
+        self.release_unused_data();
+    }
+
+    /// Removes every FFT instance and all shared precomputed data from this planner's caches.
+    ///
+    /// FFT instances that were already returned by the planner keep working. The twiddle statistics are not reset.
+    pub fn clear(&mut self) {
+        self.algorithm_cache.clear();
+        self.recipe_cache.clear();
+        self.chirp_cache.clear();
+        self.twiddle_store.clear();
+    }
+
+    /// Removes the FFT instances of size `len` from this planner's cache, in both directions.
+    ///
+    /// Their inner FFTs stay in the cache. FFT instances that were already returned by the planner keep working.
+    pub fn evict(&mut self, len: usize) {
+        self.algorithm_cache.evict(len);
+        self.recipe_cache.remove(&len);
+        self.release_unused_data();
+    }
+
+    /// Returns the number of FFT instances in this planner's cache. Each direction counts separately.
+    pub fn cached_fft_count(&self) -> usize {
+        self.algorithm_cache.len()
+    }
+
+    /// Returns the estimated memory in bytes used by this planner's caches, as described in [`set_max_cache_memory`](Self::set_max_cache_memory).
+    pub fn cache_memory(&self) -> usize {
+        self.algorithm_cache.memory() + self.chirp_cache.memory() + self.twiddle_store.memory()
+    }
+
     /// Returns a `Fft` instance which computes FFTs of size `len`.
     ///
     /// If the provided `direction` is `FftDirection::Forward`, the returned instance will compute forward FFTs. If it's `FftDirection::Inverse`, it will compute inverse FFTs.
@@ -247,7 +480,12 @@ impl<T: FftNum> FftPlannerScalar<T> {
         let recipe = self.design_fft_for_len(len);
 
         // Step 2: Use our recipe to construct a Fft trait object
-        self.build_fft(&recipe, direction)
+        let fft = self.build_fft(&recipe, direction);
+
+        if self.algorithm_cache.is_bounded() {
+            self.release_unused_data();
+        }
+        fft
     }
 
     /// Returns a `Fft` instance which computes forward FFTs of size `len`
@@ -264,8 +502,70 @@ impl<T: FftNum> FftPlannerScalar<T> {
         self.plan_fft(len, FftDirection::Inverse)
     }
 
+    /// Returns a pruned `Fft` instance which computes FFTs of size `len`, where only some of the inputs are nonzero, and only some of the outputs are needed.
+    ///
+    /// The returned instance expects every input element at index `nonzero_inputs` or above to be zero, and may skip reading them. Only the first
+    /// `needed_outputs` output elements are guaranteed to be correct, and the remaining output elements will contain garbage.
+    /// `MixedRadix` and `Radix4` steps of the plan skip computing sub-FFTs that only process zeroes or only produce unneeded outputs.
+    ///
+    /// Values of `nonzero_inputs` and `needed_outputs` larger than `len` are treated as `len`.
+    ///
+    /// Pruned FFT instances are not cached, but their inner FFTs are.
+    pub fn plan_fft_pruned(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Arc<dyn Fft<T>> {
+        let recipe = self.design_fft_for_len(len);
+        let fft = self.build_pruned_fft(
+            &recipe,
+            direction,
+            min(nonzero_inputs, len),
+            min(needed_outputs, len),
+        );
+
+        if self.algorithm_cache.is_bounded() {
+            self.release_unused_data();
+        }
+        fft
+    }
+
+    /// Returns a `Fft` instance which computes FFTs of size `len`, and scales its output according to `normalization`.
+    ///
+    /// The output is multiplied by `normalization.scale_factor(len, direction)`. Wherever possible, the scale is folded into
+    /// data the FFT algorithms precompute anyways, so normalized FFTs are usually just as fast as unnormalized ones.
+    ///
+    /// If the scale factor is 1, this is equivalent to `plan_fft`. Otherwise, the outermost step of the FFT is not cached, but its inner FFTs are.
+    pub fn plan_fft_normalized(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        normalization: FftNormalization,
+    ) -> Arc<dyn Fft<T>> {
+        let scale = normalization.scale_factor(len, direction);
+        if scale == 1.0 {
+            return self.plan_fft(len, direction);
+        }
+
+        let recipe = self.design_fft_for_len(len);
+        let scale = twiddles::compute_scale_factor(normalization, len, direction);
+        let fft = self.build_scaled_fft(&recipe, direction, scale);
+
+        if self.algorithm_cache.is_bounded() {
+            self.release_unused_data();
+        }
+        fft
+    }
+
+    /// Returns statistics about the twiddle factor tables this planner has shared between the FFT instances it created.
+    pub fn twiddle_stats(&self) -> TwiddleStats {
+        self.twiddle_store.stats()
+    }
+
     // Make a recipe for a length
-    fn design_fft_for_len(&mut self, len: usize) -> Arc<Recipe> {
+    pub(crate) fn design_fft_for_len(&mut self, len: usize) -> Arc<Recipe> {
         if len < 2 {
             Arc::new(Recipe::Dft(len))
         } else if let Some(recipe) = self.recipe_cache.get(&len) {
@@ -274,10 +574,30 @@ impl<T: FftNum> FftPlannerScalar<T> {
             let factors = PrimeFactors::compute(len);
             let recipe = self.design_fft_with_factors(len, factors);
             self.recipe_cache.insert(len, Arc::clone(&recipe));
+            self.shrink_recipe_cache();
             recipe
         }
     }
 
+    fn shrink_recipe_cache(&mut self) {
+        while exceeds(self.recipe_cache.len(), self.max_cached_recipes) {
+            self.recipe_cache.remove_least_recently_used();
+        }
+    }
+
+    // Drops the shared precomputed data that no FFT instance uses anymore, then evicts the least recently used FFT instances
+    // until the instances and the shared data they use fit in the memory limit
+    fn release_unused_data(&mut self) {
+        self.chirp_cache.release_unused();
+        self.twiddle_store.release_unused();
+        while exceeds(self.cache_memory(), self.algorithm_cache.max_memory())
+            && self.algorithm_cache.evict_least_recently_used()
+        {
+            self.chirp_cache.release_unused();
+            self.twiddle_store.release_unused();
+        }
+    }
+
     // Create the fft from a recipe, take from cache if possible
     fn build_fft(&mut self, recipe: &Recipe, direction: FftDirection) -> Arc<dyn Fft<T>> {
         let len = recipe.len();
@@ -294,7 +614,15 @@ impl<T: FftNum> FftPlannerScalar<T> {
     fn build_new_fft(&mut self, recipe: &Recipe, direction: FftDirection) -> Arc<dyn Fft<T>> {
         match recipe {
             Recipe::Dft(len) => Arc::new(Dft::new(*len, direction)) as Arc<dyn Fft<T>>,
-            Recipe::Radix4(len) => Arc::new(Radix4::new(*len, direction)) as Arc<dyn Fft<T>>,
+            Recipe::Radix4(len) => {
+                let twiddles = self.twiddle_store.radix4_twiddles(*len, direction);
+                Arc::new(Radix4::new_with_twiddles(*len, direction, twiddles)) as Arc<dyn Fft<T>>
+            }
+            Recipe::Radix8(len) => {
+                let twiddles = self.twiddle_store.radix8_twiddles(*len, direction);
+                Arc::new(Radix8::new_with_twiddles(*len, direction, twiddles)) as Arc<dyn Fft<T>>
+            }
+            Recipe::Stockham(len) => Arc::new(Stockham::new(*len, direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly2 => Arc::new(Butterfly2::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly3 => Arc::new(Butterfly3::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly4 => Arc::new(Butterfly4::new(direction)) as Arc<dyn Fft<T>>,
@@ -302,6 +630,9 @@ impl<T: FftNum> FftPlannerScalar<T> {
             Recipe::Butterfly6 => Arc::new(Butterfly6::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly7 => Arc::new(Butterfly7::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly8 => Arc::new(Butterfly8::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly9Winograd => {
+                Arc::new(Butterfly9Winograd::new(direction)) as Arc<dyn Fft<T>>
+            }
             Recipe::Butterfly11 => Arc::new(Butterfly11::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly13 => Arc::new(Butterfly13::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly16 => Arc::new(Butterfly16::new(direction)) as Arc<dyn Fft<T>>,
@@ -311,13 +642,34 @@ impl<T: FftNum> FftPlannerScalar<T> {
             Recipe::Butterfly29 => Arc::new(Butterfly29::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly31 => Arc::new(Butterfly31::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::Butterfly32 => Arc::new(Butterfly32::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly37 => Arc::new(Butterfly37::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly41 => Arc::new(Butterfly41::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly43 => Arc::new(Butterfly43::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly47 => Arc::new(Butterfly47::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly53 => Arc::new(Butterfly53::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly59 => Arc::new(Butterfly59::new(direction)) as Arc<dyn Fft<T>>,
+            Recipe::Butterfly61 => Arc::new(Butterfly61::new(direction)) as Arc<dyn Fft<T>>,
             Recipe::MixedRadix {
                 left_fft,
                 right_fft,
             } => {
                 let left_fft = self.build_fft(&left_fft, direction);
                 let right_fft = self.build_fft(&right_fft, direction);
-                Arc::new(MixedRadix::new(left_fft, right_fft)) as Arc<dyn Fft<T>>
+                let twiddles = self.twiddle_store.mixed_radix_twiddles(
+                    left_fft.len(),
+                    right_fft.len(),
+                    direction,
+                );
+                Arc::new(MixedRadix::new_with_twiddles(left_fft, right_fft, twiddles))
+                    as Arc<dyn Fft<T>>
+            }
+            Recipe::SixStep {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                Arc::new(SixStep::new(left_fft, right_fft)) as Arc<dyn Fft<T>>
             }
             Recipe::GoodThomasAlgorithm {
                 left_fft,
@@ -333,7 +685,14 @@ impl<T: FftNum> FftPlannerScalar<T> {
             } => {
                 let left_fft = self.build_fft(&left_fft, direction);
                 let right_fft = self.build_fft(&right_fft, direction);
-                Arc::new(MixedRadixSmall::new(left_fft, right_fft)) as Arc<dyn Fft<T>>
+                let twiddles = self.twiddle_store.mixed_radix_twiddles(
+                    left_fft.len(),
+                    right_fft.len(),
+                    direction,
+                );
+                Arc::new(MixedRadixSmall::new_with_twiddles(
+                    left_fft, right_fft, twiddles,
+                )) as Arc<dyn Fft<T>>
             }
             Recipe::GoodThomasAlgorithmSmall {
                 left_fft,
@@ -349,7 +708,189 @@ impl<T: FftNum> FftPlannerScalar<T> {
             }
             Recipe::BluesteinsAlgorithm { len, inner_fft } => {
                 let inner_fft = self.build_fft(&inner_fft, direction);
-                Arc::new(BluesteinsAlgorithm::new(*len, inner_fft)) as Arc<dyn Fft<T>>
+                let chirp_spectrum = self.chirp_cache.get_or_compute(*len, &*inner_fft);
+                Arc::new(BluesteinsAlgorithm::new_with_chirp_spectrum(
+                    *len,
+                    inner_fft,
+                    chirp_spectrum,
+                )) as Arc<dyn Fft<T>>
+            }
+            Recipe::RadersAlgorithmPadded { len, inner_fft } => {
+                let inner_fft = self.build_fft(inner_fft, direction);
+                Arc::new(RadersAlgorithmPadded::new(*len, inner_fft)) as Arc<dyn Fft<T>>
+            }
+        }
+    }
+
+    // Create a pruned fft from a recipe. Only the unpruned parts of the plan are taken from the cache
+    fn build_pruned_fft(
+        &mut self,
+        recipe: &Recipe,
+        direction: FftDirection,
+        nonzero_inputs: usize,
+        needed_outputs: usize,
+    ) -> Arc<dyn Fft<T>> {
+        let len = recipe.len();
+        if nonzero_inputs >= len && needed_outputs >= len {
+            return self.build_fft(recipe, direction);
+        }
+
+        match recipe {
+            // Radix8 doesn't support pruning, but skipping the pruned work is worth more than its speedup over Radix4
+            Recipe::Radix4(len) | Recipe::Radix8(len) => {
+                let twiddles = self.twiddle_store.radix4_twiddles(*len, direction);
+                let mut fft = Radix4::new_with_twiddles(*len, direction, twiddles);
+                fft.prune(nonzero_inputs, needed_outputs);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            // SixStep and GoodThomasAlgorithm don't support pruning, so use MixedRadix with the same inner FFTs instead
+            Recipe::MixedRadix {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::GoodThomasAlgorithm {
+                left_fft,
+                right_fft,
+            }
+            | Recipe::SixStep {
+                left_fft,
+                right_fft,
+            } => {
+                // The pruning propagates to the inner FFTs:
+                // The width FFTs only see nonzero data in their first `nonzero_inputs` columns, and only the first `ceil(needed_outputs / height)` of their outputs are used
+                // The height FFTs only see nonzero data in their first `ceil(nonzero_inputs / width)` rows, and only the first `needed_outputs` of their outputs are used
+                let width = left_fft.len();
+                let height = right_fft.len();
+                let left_fft = self.build_pruned_fft(
+                    left_fft,
+                    direction,
+                    nonzero_inputs,
+                    div_ceil(needed_outputs, height),
+                );
+                let right_fft = self.build_pruned_fft(
+                    right_fft,
+                    direction,
+                    div_ceil(nonzero_inputs, width),
+                    needed_outputs,
+                );
+                let twiddles = self
+                    .twiddle_store
+                    .mixed_radix_twiddles(width, height, direction);
+                let mut fft = MixedRadix::new_with_twiddles(left_fft, right_fft, twiddles);
+                fft.prune(nonzero_inputs, needed_outputs);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            // The other algorithms don't support pruning, so just compute the whole FFT
+            _ => self.build_fft(recipe, direction),
+        }
+    }
+
+    // Create a new fft from a recipe, whose output is multiplied by `scale`. The scaled parts of the plan aren't cached,
+    // so that they don't get handed out to callers expecting unscaled FFTs
+    fn build_scaled_fft(
+        &mut self,
+        recipe: &Recipe,
+        direction: FftDirection,
+        scale: T,
+    ) -> Arc<dyn Fft<T>> {
+        match recipe {
+            Recipe::Dft(len) => {
+                let mut fft = Dft::new(*len, direction);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            // Radix4 and Radix8 apply their scale separately from their twiddles, so they can share them
+            Recipe::Radix4(len) => {
+                let twiddles = self.twiddle_store.radix4_twiddles(*len, direction);
+                let mut fft = Radix4::new_with_twiddles(*len, direction, twiddles);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::Radix8(len) => {
+                let twiddles = self.twiddle_store.radix8_twiddles(*len, direction);
+                let mut fft = Radix8::new_with_twiddles(*len, direction, twiddles);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::Stockham(len) => {
+                let mut fft = Stockham::new(*len, direction);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::MixedRadix {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                let mut fft = MixedRadix::new(left_fft, right_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::SixStep {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                let mut fft = SixStep::new(left_fft, right_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::MixedRadixSmall {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                let mut fft = MixedRadixSmall::new(left_fft, right_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::GoodThomasAlgorithm {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                let mut fft = GoodThomasAlgorithm::new(left_fft, right_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::GoodThomasAlgorithmSmall {
+                left_fft,
+                right_fft,
+            } => {
+                let left_fft = self.build_fft(left_fft, direction);
+                let right_fft = self.build_fft(right_fft, direction);
+                let mut fft = GoodThomasAlgorithmSmall::new(left_fft, right_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::RadersAlgorithm { inner_fft } => {
+                let inner_fft = self.build_fft(inner_fft, direction);
+                let mut fft = RadersAlgorithm::new(inner_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::BluesteinsAlgorithm { len, inner_fft } => {
+                let inner_fft = self.build_fft(inner_fft, direction);
+                let chirp_spectrum = self.chirp_cache.get_or_compute(*len, &*inner_fft);
+                let mut fft =
+                    BluesteinsAlgorithm::new_with_chirp_spectrum(*len, inner_fft, chirp_spectrum);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            Recipe::RadersAlgorithmPadded { len, inner_fft } => {
+                let inner_fft = self.build_fft(inner_fft, direction);
+                let mut fft = RadersAlgorithmPadded::new(*len, inner_fft);
+                fft.scale_output(scale);
+                Arc::new(fft) as Arc<dyn Fft<T>>
+            }
+            // The butterflies are hardcoded, so they need an extra pass to apply the scale
+            _ => {
+                let inner_fft = self.build_fft(recipe, direction);
+                Arc::new(ScaledFft::new(inner_fft, scale)) as Arc<dyn Fft<T>>
             }
         }
     }
@@ -359,31 +900,69 @@ impl<T: FftNum> FftPlannerScalar<T> {
             fft_instance
         } else if factors.is_prime() {
             self.design_prime(len)
+        } else if len >= self.min_six_step_len {
+            // Split the FFT into two halves that are as close to sqrt(len) as possible. If they're still large, they'll use six-step too
+            let (left_factors, right_factors) = factors.partition_factors();
+            let left_fft = self.design_fft_with_factors(left_factors.get_product(), left_factors);
+            let right_fft =
+                self.design_fft_with_factors(right_factors.get_product(), right_factors);
+            Arc::new(Recipe::SixStep {
+                left_fft,
+                right_fft,
+            })
+        } else if factors.get_other_factors().is_empty()
+            && factors.get_power_of_three() >= MIN_STOCKHAM_POWER_OF_THREE
+            && len >= MIN_STOCKHAM_LEN
+        {
+            // Stockham doesn't need to transpose its data between passes, which beats mixed radix when there are enough radix-3 passes
+            Arc::new(Recipe::Stockham(len))
+        } else if len.is_power_of_two() && len.trailing_zeros() >= MIN_RADIX4_BITS {
+            self.design_power_of_two(len)
         } else if len.trailing_zeros() <= MAX_RADIX4_BITS && len.trailing_zeros() >= MIN_RADIX4_BITS
         {
-            if len.is_power_of_two() {
-                Arc::new(Recipe::Radix4(len))
-            } else {
-                let non_power_of_two = factors
-                    .remove_factors(PrimeFactor {
-                        value: 2,
-                        count: len.trailing_zeros(),
-                    })
-                    .unwrap();
-                let power_of_two = PrimeFactors::compute(1 << len.trailing_zeros());
-                self.design_mixed_radix(power_of_two, non_power_of_two)
-            }
+            let non_power_of_two = factors
+                .remove_factors(PrimeFactor {
+                    value: 2,
+                    count: len.trailing_zeros(),
+                })
+                .unwrap();
+            let power_of_two = PrimeFactors::compute(1 << len.trailing_zeros());
+            self.design_mixed_radix(power_of_two, non_power_of_two)
         } else {
             let (left_factors, right_factors) = factors.partition_factors();
             self.design_mixed_radix(left_factors, right_factors)
         }
     }
 
+    // Radix8 makes a third fewer passes over the data than Radix4, which measured 3-8% faster from 2^10 up, and faster than
+    // splitting sizes above 2^16 into MixedRadix. Below 2^10, they measured even. SplitRadix measured 2-20% slower than Radix4
+    // at every size from 2^5 to 2^20, so the planner doesn't choose it
+    fn design_power_of_two(&self, len: usize) -> Arc<Recipe> {
+        if len.trailing_zeros() >= MIN_RADIX8_BITS {
+            Arc::new(Recipe::Radix8(len))
+        } else {
+            Arc::new(Recipe::Radix4(len))
+        }
+    }
+
     fn design_mixed_radix(
         &mut self,
         left_factors: PrimeFactors,
         right_factors: PrimeFactors,
     ) -> Arc<Recipe> {
+        let len = left_factors.get_product() * right_factors.get_product();
+        let is_small = left_factors.get_product() < 31 && right_factors.get_product() < 31;
+
+        // Good-thomas is faster than mixed radix up to MAX_GOOD_THOMAS_LEN, but it needs coprime halves, and the split we were given may not be coprime.
+        // So for sizes in that range, search for the most balanced coprime split instead
+        let (left_factors, right_factors) = if !is_small && len <= MAX_GOOD_THOMAS_LEN {
+            let all_factors = PrimeFactors::compute(len);
+            all_factors
+                .partition_coprime_factors()
+                .unwrap_or((left_factors, right_factors))
+        } else {
+            (left_factors, right_factors)
+        };
         let left_len = left_factors.get_product();
         let right_len = right_factors.get_product();
 
@@ -405,6 +984,11 @@ impl<T: FftNum> FftPlannerScalar<T> {
                     right_fft,
                 })
             }
+        } else if gcd(left_len, right_len) == 1 && len <= MAX_GOOD_THOMAS_LEN {
+            Arc::new(Recipe::GoodThomasAlgorithm {
+                left_fft,
+                right_fft,
+            })
         } else {
             Arc::new(Recipe::MixedRadix {
                 left_fft,
@@ -423,6 +1007,8 @@ impl<T: FftNum> FftPlannerScalar<T> {
             6 => Some(Arc::new(Recipe::Butterfly6)),
             7 => Some(Arc::new(Recipe::Butterfly7)),
             8 => Some(Arc::new(Recipe::Butterfly8)),
+            // The other Winograd butterflies measured about even with the butterflies above, but this one is 3x faster than a 3x3 mixed radix
+            9 => Some(Arc::new(Recipe::Butterfly9Winograd)),
             11 => Some(Arc::new(Recipe::Butterfly11)),
             13 => Some(Arc::new(Recipe::Butterfly13)),
             16 => Some(Arc::new(Recipe::Butterfly16)),
@@ -432,6 +1018,13 @@ impl<T: FftNum> FftPlannerScalar<T> {
             29 => Some(Arc::new(Recipe::Butterfly29)),
             31 => Some(Arc::new(Recipe::Butterfly31)),
             32 => Some(Arc::new(Recipe::Butterfly32)),
+            37 => Some(Arc::new(Recipe::Butterfly37)),
+            41 => Some(Arc::new(Recipe::Butterfly41)),
+            43 => Some(Arc::new(Recipe::Butterfly43)),
+            47 => Some(Arc::new(Recipe::Butterfly47)),
+            53 => Some(Arc::new(Recipe::Butterfly53)),
+            59 => Some(Arc::new(Recipe::Butterfly59)),
+            61 => Some(Arc::new(Recipe::Butterfly61)),
             _ => None,
         }
     }
@@ -439,24 +1032,25 @@ impl<T: FftNum> FftPlannerScalar<T> {
     fn design_prime(&mut self, len: usize) -> Arc<Recipe> {
         let inner_fft_len_rader = len - 1;
         let raders_factors = PrimeFactors::compute(inner_fft_len_rader);
-        // If any of the prime factors is too large, Rader's gets slow and Bluestein's is the better choice
+        // If any of the prime factors is too large, Rader's gets slow, and padded Rader's or Bluestein's is the better choice
         if raders_factors
             .get_other_factors()
             .iter()
             .any(|val| val.value > MAX_RADER_PRIME_FACTOR)
         {
-            let inner_fft_len_pow2 = (2 * len - 1).checked_next_power_of_two().unwrap();
-            // for long ffts a mixed radix inner fft is faster than a longer radix4
-            let min_inner_len = 2 * len - 1;
-            let mixed_radix_len = 3 * inner_fft_len_pow2 / 4;
-            let inner_fft =
-                if mixed_radix_len >= min_inner_len && len >= MIN_BLUESTEIN_MIXED_RADIX_LEN {
-                    let mixed_radix_factors = PrimeFactors::compute(mixed_radix_len);
-                    self.design_fft_with_factors(mixed_radix_len, mixed_radix_factors)
-                } else {
-                    Arc::new(Recipe::Radix4(inner_fft_len_pow2))
-                };
-            Arc::new(Recipe::BluesteinsAlgorithm { len, inner_fft })
+            // Padded Rader's computes two inner FFTs of at least len - 2 each way, and Bluestein's one of at least 2 * len - 1,
+            // so rank them by the cost of their inner FFTs. The two have about the same overhead besides that
+            let bluesteins_inner_len = SmoothCostModel::SCALAR.choose_len(2 * len - 1);
+            let raders_padded_inner_len = SmoothCostModel::SCALAR.choose_len(len - 2);
+            if 2 * SmoothCostModel::SCALAR.cost(raders_padded_inner_len)
+                < SmoothCostModel::SCALAR.cost(bluesteins_inner_len)
+            {
+                let inner_fft = self.design_fft_for_len(raders_padded_inner_len);
+                Arc::new(Recipe::RadersAlgorithmPadded { len, inner_fft })
+            } else {
+                let inner_fft = self.design_fft_for_len(bluesteins_inner_len);
+                Arc::new(Recipe::BluesteinsAlgorithm { len, inner_fft })
+            }
         } else {
             let inner_fft = self.design_fft_with_factors(inner_fft_len_rader, raders_factors);
             Arc::new(Recipe::RadersAlgorithm { inner_fft })
@@ -464,6 +1058,66 @@ impl<T: FftNum> FftPlannerScalar<T> {
     }
 }
 
+/// The fixed-point FFT planner creates FFT instances for fixed-point data: `Complex<i16>` (Q15) and `Complex<i32>` (Q31).
+///
+/// Fixed-point FFTs are designed with the same recipes as [`FftPlannerScalar`](crate::FftPlannerScalar), so the stages of a fixed-point FFT
+/// follow the same factorization as the corresponding floating point FFT. See [`FixedFft`](crate::fixed_point::FixedFft) for details on the arithmetic.
+///
+/// ~~~
+/// // Perform a forward Q31 FFT of size 1200, with a fixed shift after every stage
+/// use rustfft::{FftPlannerFixed, FftDirection, num_complex::Complex};
+/// use rustfft::fixed_point::{FixedScaling, RoundingMode};
+///
+/// let mut planner = FftPlannerFixed::<i32>::new();
+/// let fft = planner.plan_fft(1200, FftDirection::Forward, FixedScaling::FixedShift, RoundingMode::RoundHalfUp);
+///
+/// let mut buffer = vec![Complex{ re: 0i32, im: 0i32 }; 1200];
+/// let exponent = fft.process(&mut buffer);
+/// ~~~
+///
+/// If you plan on creating multiple FFT instances, it is recommended to reuse the same planner for all of them, so that recipes are only designed once.
+pub struct FftPlannerFixed<T: FixedPoint> {
+    // Fixed-point FFTs use the same recipes as scalar FFTs, so borrow the scalar planner's recipe design and recipe cache
+    recipe_planner: FftPlannerScalar<f64>,
+    _phantom: core::marker::PhantomData<T>,
+}
+
+impl<T: FixedPoint> FftPlannerFixed<T> {
+    /// Creates a new `FftPlannerFixed` instance.
+    pub fn new() -> Self {
+        Self {
+            recipe_planner: FftPlannerScalar::new(),
+            _phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a `FixedFft` instance which computes FFTs of size `len`, scaling between stages according to `scaling`, and rounding according to `rounding`.
+    ///
+    /// If the provided `direction` is `FftDirection::Forward`, the returned instance will compute forward FFTs. If it's `FftDirection::Inverse`, it will compute inverse FFTs.
+    pub fn plan_fft(
+        &mut self,
+        len: usize,
+        direction: FftDirection,
+        scaling: FixedScaling,
+        rounding: RoundingMode,
+    ) -> Arc<FixedFft<T>> {
+        let recipe = self.recipe_planner.design_fft_for_len(len);
+        Arc::new(FixedFft::from_recipe(
+            &recipe,
+            direction,
+            scaling,
+            rounding,
+            &mut self.recipe_planner,
+        ))
+    }
+}
+
+impl<T: FixedPoint> Default for FftPlannerFixed<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
@@ -475,6 +1129,13 @@ mod unit_tests {
         }
     }
 
+    fn is_sixstep(plan: &Recipe) -> bool {
+        match plan {
+            &Recipe::SixStep { .. } => true,
+            _ => false,
+        }
+    }
+
     fn is_mixedradixsmall(plan: &Recipe) -> bool {
         match plan {
             &Recipe::MixedRadixSmall { .. } => true,
@@ -482,6 +1143,13 @@ mod unit_tests {
         }
     }
 
+    fn is_goodthomas(plan: &Recipe) -> bool {
+        match plan {
+            &Recipe::GoodThomasAlgorithm { .. } => true,
+            _ => false,
+        }
+    }
+
     fn is_goodthomassmall(plan: &Recipe) -> bool {
         match plan {
             &Recipe::GoodThomasAlgorithmSmall { .. } => true,
@@ -496,13 +1164,21 @@ mod unit_tests {
         }
     }
 
-    fn is_bluesteins(plan: &Recipe) -> bool {
+    fn is_raders_padded(plan: &Recipe) -> bool {
         match plan {
-            &Recipe::BluesteinsAlgorithm { .. } => true,
+            &Recipe::RadersAlgorithmPadded { .. } => true,
             _ => false,
         }
     }
 
+    // The planner prefers padded Rader's over Bluestein's for primes, so tests of Bluestein's chirp spectrum cache put a Bluestein's recipe in the recipe cache first
+    fn insert_bluesteins_recipe(planner: &mut FftPlannerScalar<f64>, len: usize) -> Arc<Recipe> {
+        let inner_fft = planner.design_fft_for_len(SmoothCostModel::SCALAR.choose_len(2 * len - 1));
+        let recipe = Arc::new(Recipe::BluesteinsAlgorithm { len, inner_fft });
+        planner.recipe_cache.insert(len, Arc::clone(&recipe));
+        recipe
+    }
+
     #[test]
     fn test_plan_scalar_trivial() {
         // Length 0 and 1 should use Dft
@@ -516,9 +1192,9 @@ mod unit_tests {
 
     #[test]
     fn test_plan_scalar_mediumpoweroftwo() {
-        // Powers of 2 between 64 and 32768 should use Radix4
+        // Powers of 2 between 64 and 512 should use Radix4
         let mut planner = FftPlannerScalar::<f64>::new();
-        for pow in 6..16 {
+        for pow in 6..10 {
             let len = 1 << pow;
             let plan = planner.design_fft_for_len(len);
             assert_eq!(*plan, Recipe::Radix4(len));
@@ -528,16 +1204,77 @@ mod unit_tests {
 
     #[test]
     fn test_plan_scalar_largepoweroftwo() {
-        // Powers of 2 from 65536 and up should use MixedRadix
+        // Powers of 2 from 1024 and up should use Radix8, or SixStep if they're large enough
         let mut planner = FftPlannerScalar::<f64>::new();
-        for pow in 17..32 {
+        for pow in 10..32 {
             let len = 1 << pow;
             let plan = planner.design_fft_for_len(len);
-            assert!(is_mixedradix(&plan), "Expected MixedRadix, got {:?}", plan);
+            if len >= DEFAULT_MIN_SIX_STEP_LEN {
+                assert!(is_sixstep(&plan), "Expected SixStep, got {:?}", plan);
+            } else {
+                assert_eq!(*plan, Recipe::Radix8(len));
+            }
             assert_eq!(plan.len(), len, "Recipe reports wrong length");
         }
     }
 
+    #[test]
+    fn test_plan_scalar_stockham() {
+        // Large sizes whose only prime factors are 2 and 3, with at least 27 as a factor, should use Stockham, until they're large enough for SixStep
+        let mut planner = FftPlannerScalar::<f64>::new();
+        for pow2 in 0..12 {
+            for pow3 in 3..8 {
+                let len = (1 << pow2) * 3usize.pow(pow3);
+                let plan = planner.design_fft_for_len(len);
+                if len >= MIN_STOCKHAM_LEN && len < DEFAULT_MIN_SIX_STEP_LEN {
+                    assert_eq!(*plan, Recipe::Stockham(len));
+                } else {
+                    assert_ne!(*plan, Recipe::Stockham(len));
+                }
+            }
+        }
+
+        // Sizes with fewer factors of 3, or other prime factors, shouldn't use Stockham
+        for &len in &[9 * 4096, 3 * 65536, 5 * 27 * 256, 7 * 81 * 64] {
+            let plan = planner.design_fft_for_len(len);
+            assert_ne!(*plan, Recipe::Stockham(len));
+        }
+    }
+
+    #[test]
+    fn test_plan_scalar_sixstep() {
+        let mut planner = FftPlannerScalar::<f64>::new();
+
+        // Large composite sizes should be split into two halves close to their square root
+        let plan = planner.design_fft_for_len(5000 * 5000);
+        match &*plan {
+            Recipe::SixStep {
+                left_fft,
+                right_fft,
+            } => {
+                assert_eq!(left_fft.len(), 5000);
+                assert_eq!(right_fft.len(), 5000);
+            }
+            _ => panic!("Expected SixStep, got {:?}", plan),
+        }
+
+        // Powers of two and Stockham sizes above the threshold should use SixStep too
+        for &len in &[1 << 22, 1 << 24, 4096 * 2187] {
+            let plan = planner.design_fft_for_len(len);
+            assert!(is_sixstep(&plan), "Expected SixStep, got {:?}", plan);
+            assert_eq!(plan.len(), len, "Recipe reports wrong length");
+        }
+
+        // Lowering the threshold should replan sizes that were already planned
+        let len = 60 * 70;
+        assert!(is_mixedradix(&planner.design_fft_for_len(len)));
+        planner.set_min_six_step_len(len);
+        assert!(is_sixstep(&planner.design_fft_for_len(len)));
+
+        let fft = planner.plan_fft_forward(len);
+        crate::test_utils::check_fft_algorithm(fft.as_ref(), len, FftDirection::Forward);
+    }
+
     #[test]
     fn test_plan_scalar_butterflies() {
         // Check that all butterflies are used
@@ -549,6 +1286,7 @@ mod unit_tests {
         assert_eq!(*planner.design_fft_for_len(6), Recipe::Butterfly6);
         assert_eq!(*planner.design_fft_for_len(7), Recipe::Butterfly7);
         assert_eq!(*planner.design_fft_for_len(8), Recipe::Butterfly8);
+        assert_eq!(*planner.design_fft_for_len(9), Recipe::Butterfly9Winograd);
         assert_eq!(*planner.design_fft_for_len(11), Recipe::Butterfly11);
         assert_eq!(*planner.design_fft_for_len(13), Recipe::Butterfly13);
         assert_eq!(*planner.design_fft_for_len(16), Recipe::Butterfly16);
@@ -558,11 +1296,18 @@ mod unit_tests {
         assert_eq!(*planner.design_fft_for_len(29), Recipe::Butterfly29);
         assert_eq!(*planner.design_fft_for_len(31), Recipe::Butterfly31);
         assert_eq!(*planner.design_fft_for_len(32), Recipe::Butterfly32);
+        assert_eq!(*planner.design_fft_for_len(37), Recipe::Butterfly37);
+        assert_eq!(*planner.design_fft_for_len(41), Recipe::Butterfly41);
+        assert_eq!(*planner.design_fft_for_len(43), Recipe::Butterfly43);
+        assert_eq!(*planner.design_fft_for_len(47), Recipe::Butterfly47);
+        assert_eq!(*planner.design_fft_for_len(53), Recipe::Butterfly53);
+        assert_eq!(*planner.design_fft_for_len(59), Recipe::Butterfly59);
+        assert_eq!(*planner.design_fft_for_len(61), Recipe::Butterfly61);
     }
 
     #[test]
     fn test_plan_scalar_mixedradix() {
-        // Products of several different primes should become MixedRadix
+        // Products of several different primes should become MixedRadix, or SixStep if they're large enough
         let mut planner = FftPlannerScalar::<f64>::new();
         for pow2 in 2..5 {
             for pow3 in 2..5 {
@@ -573,7 +1318,11 @@ mod unit_tests {
                             * 5usize.pow(pow5)
                             * 7usize.pow(pow7);
                         let plan = planner.design_fft_for_len(len);
-                        assert!(is_mixedradix(&plan), "Expected MixedRadix, got {:?}", plan);
+                        if len >= DEFAULT_MIN_SIX_STEP_LEN {
+                            assert!(is_sixstep(&plan), "Expected SixStep, got {:?}", plan);
+                        } else {
+                            assert!(is_mixedradix(&plan), "Expected MixedRadix, got {:?}", plan);
+                        }
                         assert_eq!(plan.len(), len, "Recipe reports wrong length");
                     }
                 }
@@ -610,20 +1359,58 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_plan_scalar_goodthomas() {
+        // Products of two coprime lengths, where one is 31 or larger, should be GoodThomasAlgorithm if they're small enough
+        let mut planner = FftPlannerScalar::<f64>::new();
+        for len in [32 * 45, 35 * 44, 32 * 77, 31 * 64].iter() {
+            let plan = planner.design_fft_for_len(*len);
+            assert!(
+                is_goodthomas(&plan),
+                "Expected GoodThomasAlgorithm, got {:?}",
+                plan
+            );
+            assert_eq!(plan.len(), *len, "Recipe reports wrong length");
+        }
+
+        // Sizes whose default split isn't coprime should search for a coprime split
+        for &(len, expected_halves) in &[
+            (36 * 36, (81, 16)),
+            (42 * 42, (49, 36)),
+            (60 * 40, (32, 75)),
+        ] {
+            let plan = planner.design_fft_for_len(len);
+            match &*plan {
+                Recipe::GoodThomasAlgorithm {
+                    left_fft,
+                    right_fft,
+                } => assert_eq!((left_fft.len(), right_fft.len()), expected_halves),
+                _ => panic!("Expected GoodThomasAlgorithm, got {:?}", plan),
+            }
+        }
+
+        // Larger sizes, and sizes with only one distinct prime factor, should still be MixedRadix
+        for len in [45 * 64, 128 * 35, 125 * 128, 49 * 49 * 7].iter() {
+            let plan = planner.design_fft_for_len(*len);
+            assert!(is_mixedradix(&plan), "Expected MixedRadix, got {:?}", plan);
+            assert_eq!(plan.len(), *len, "Recipe reports wrong length");
+        }
+    }
+
     #[test]
     fn test_plan_scalar_bluestein_vs_rader() {
-        let difficultprimes: [usize; 11] = [59, 83, 107, 149, 167, 173, 179, 359, 719, 1439, 2879];
-        let easyprimes: [usize; 24] = [
-            53, 61, 67, 71, 73, 79, 89, 97, 101, 103, 109, 113, 127, 131, 137, 139, 151, 157, 163,
-            181, 191, 193, 197, 199,
+        let difficultprimes: [usize; 10] = [107, 167, 179, 359, 367, 709, 719, 1439, 1697, 2879];
+        let easyprimes: [usize; 27] = [
+            67, 71, 73, 79, 83, 89, 97, 101, 103, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163,
+            173, 181, 191, 193, 197, 199, 283, 1033,
         ];
 
         let mut planner = FftPlannerScalar::<f64>::new();
         for len in difficultprimes.iter() {
             let plan = planner.design_fft_for_len(*len);
             assert!(
-                is_bluesteins(&plan),
-                "Expected BluesteinsAlgorithm, got {:?}",
+                is_raders_padded(&plan),
+                "Expected RadersAlgorithmPadded, got {:?}",
                 plan
             );
             assert_eq!(plan.len(), *len, "Recipe reports wrong length");
@@ -663,6 +1450,203 @@ mod unit_tests {
         }
     }
 
+    #[test]
+    fn test_scalar_chirp_cache() {
+        // Forward, inverse and scaled instances of Bluestein's algorithm should all share the same chirp spectrum
+        let len = 1439;
+        let mut planner = FftPlannerScalar::<f64>::new();
+        insert_bluesteins_recipe(&mut planner, len);
+        for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+            let fft = planner.plan_fft(len, direction);
+            crate::test_utils::check_fft_algorithm(&*fft, len, direction);
+
+            let normalization = FftNormalization::Unitary;
+            let fft = planner.plan_fft_normalized(len, direction, normalization);
+            crate::test_utils::check_normalized_fft_algorithm(&*fft, len, direction, normalization);
+        }
+        assert_eq!(planner.chirp_cache.spectrum_count(), 1);
+    }
+
+    #[test]
+    fn test_scalar_cache_capacity() {
+        let element_size = core::mem::size_of::<num_complex::Complex<f64>>();
+        let mut planner = FftPlannerScalar::<f64>::new();
+        let fft2 = planner.plan_fft_forward(2);
+        planner.plan_fft_forward(3);
+        planner.plan_fft_forward(5);
+        assert_eq!(planner.cached_fft_count(), 3);
+        assert_eq!(planner.cache_memory(), (2 + 3 + 5) * element_size);
+
+        // Shrinking the cache should evict the least recently used FFTs, but the evicted instances should keep working
+        planner.set_max_cached_ffts(Some(2));
+        assert!(!planner
+            .algorithm_cache
+            .contains_fft(2, FftDirection::Forward));
+        crate::test_utils::check_fft_algorithm(&*fft2, 2, FftDirection::Forward);
+
+        planner.plan_fft_forward(3);
+        planner.plan_fft_forward(7);
+        assert_eq!(planner.cached_fft_count(), 2);
+        assert!(planner
+            .algorithm_cache
+            .contains_fft(3, FftDirection::Forward));
+        assert!(!planner
+            .algorithm_cache
+            .contains_fft(5, FftDirection::Forward));
+        assert!(planner.recipe_cache.len() <= 2);
+
+        // The memory limit evicts FFTs until the rest fit
+        planner.set_max_cached_ffts(None);
+        planner.set_max_cache_memory(Some(10 * element_size));
+        planner.plan_fft_inverse(7);
+        assert_eq!(planner.cache_memory(), 7 * element_size);
+        assert!(planner
+            .algorithm_cache
+            .contains_fft(7, FftDirection::Inverse));
+
+        // An FFT larger than the whole cache shouldn't evict anything
+        planner.plan_fft_forward(11);
+        assert_eq!(planner.cached_fft_count(), 1);
+
+        planner.evict(7);
+        assert_eq!(planner.cached_fft_count(), 0);
+        assert_eq!(planner.cache_memory(), 0);
+    }
+
+    #[test]
+    fn test_scalar_cache_release_unused_data() {
+        let len = 1439;
+        let mut planner = FftPlannerScalar::<f64>::new();
+        insert_bluesteins_recipe(&mut planner, len);
+        planner.set_max_cached_ffts(Some(1));
+
+        // The chirp spectrum should stay alive as long as an FFT instance uses it
+        let fft = planner.plan_fft_forward(len);
+        planner.evict(len);
+        assert_eq!(planner.chirp_cache.spectrum_count(), 1);
+
+        drop(fft);
+        planner.plan_fft_forward(2);
+        assert_eq!(planner.chirp_cache.spectrum_count(), 0);
+
+        planner.plan_fft_forward(len);
+        planner.clear();
+        assert_eq!(planner.cached_fft_count(), 0);
+        assert_eq!(planner.chirp_cache.spectrum_count(), 0);
+    }
+
+    #[test]
+    fn test_scalar_cache_memory_shared_data() {
+        let element_size = core::mem::size_of::<num_complex::Complex<f64>>();
+        let len = 1439;
+        let mut planner = FftPlannerScalar::<f64>::new();
+        let recipe = insert_bluesteins_recipe(&mut planner, len);
+        let inner_len = match &*recipe {
+            Recipe::BluesteinsAlgorithm { inner_fft, .. } => inner_fft.len(),
+            _ => unreachable!(),
+        };
+
+        // The chirp spectrum and the twiddle tables should count towards the cache memory
+        let fft = planner.plan_fft_forward(len);
+        planner.plan_fft_forward(512);
+        assert_eq!(planner.chirp_cache.memory(), inner_len * element_size);
+        assert!(planner.twiddle_store.memory() >= (512 - 8) * element_size);
+        assert_eq!(
+            planner.cache_memory(),
+            planner.algorithm_cache.memory()
+                + planner.chirp_cache.memory()
+                + planner.twiddle_store.memory()
+        );
+
+        // and towards the memory limit, even though the FFT instances alone would fit
+        drop(fft);
+        let limit = planner.algorithm_cache.memory();
+        planner.set_max_cache_memory(Some(limit));
+        assert!(planner.cache_memory() <= limit);
+        assert!(planner.cached_fft_count() > 0);
+    }
+
+    #[test]
+    fn test_scalar_twiddle_sharing() {
+        let element_size = core::mem::size_of::<num_complex::Complex<f64>>();
+        let mut planner = FftPlannerScalar::<f64>::new();
+        assert_eq!(*planner.design_fft_for_len(512), Recipe::Radix4(512));
+        assert_eq!(*planner.design_fft_for_len(128), Recipe::Radix4(128));
+
+        // 512 and 128 both use a base length of 8, so the smaller FFT should use a prefix of the larger FFT's twiddles
+        for &len in &[512, 128] {
+            let fft = planner.plan_fft_forward(len);
+            crate::test_utils::check_fft_algorithm(&*fft, len, FftDirection::Forward);
+        }
+        let stats = planner.twiddle_stats();
+        assert_eq!(stats.requests(), 2);
+        assert_eq!(stats.shared_requests(), 1);
+        assert_eq!(stats.computed_tables(), 1);
+        assert_eq!(stats.computed_bytes(), (512 - 8) * element_size);
+        assert_eq!(stats.bytes_saved(), (128 - 8) * element_size);
+
+        // Inverse FFTs can't share the forward twiddles
+        planner.plan_fft_inverse(128);
+        let stats = planner.twiddle_stats();
+        assert_eq!(stats.computed_tables(), 2);
+        assert_eq!(stats.bytes_saved(), (128 - 8) * element_size);
+
+        // Pruned FFTs can't come from the FFT cache, but their twiddles should be shared with the previous pruned FFTs of the same size
+        let len = 1001;
+        let fft = planner.plan_fft_pruned(len, FftDirection::Forward, 10, 20);
+        crate::test_utils::check_pruned_fft_algorithm(
+            &*fft,
+            len,
+            FftDirection::Forward,
+            10,
+            20,
+            false,
+        );
+        let before = planner.twiddle_stats();
+        assert!(before.computed_tables() > 2);
+
+        planner.plan_fft_pruned(len, FftDirection::Forward, 10, 20);
+        let after = planner.twiddle_stats();
+        assert_eq!(after.computed_tables(), before.computed_tables());
+        assert_eq!(after.computed_bytes(), before.computed_bytes());
+        assert!(after.shared_requests() > before.shared_requests());
+        assert_eq!(
+            after.bytes_saved() - before.bytes_saved(),
+            after.requested_bytes() - before.requested_bytes()
+        );
+    }
+
+    #[test]
+    fn test_scalar_radix8_twiddle_sharing() {
+        let element_size = core::mem::size_of::<num_complex::Complex<f64>>();
+        let mut planner = FftPlannerScalar::<f64>::new();
+        assert_eq!(*planner.design_fft_for_len(8192), Recipe::Radix8(8192));
+        assert_eq!(*planner.design_fft_for_len(1024), Recipe::Radix8(1024));
+
+        // 8192 and 1024 both use a base length of 16, so the smaller FFT should use a prefix of the larger FFT's twiddles
+        for &len in &[8192, 1024] {
+            let fft = planner.plan_fft_forward(len);
+            crate::test_utils::check_fft_algorithm(&*fft, len, FftDirection::Forward);
+        }
+        let stats = planner.twiddle_stats();
+        assert_eq!(stats.requests(), 2);
+        assert_eq!(stats.shared_requests(), 1);
+        assert_eq!(stats.computed_tables(), 1);
+        assert_eq!(stats.computed_bytes(), (8192 - 16) * element_size);
+        assert_eq!(stats.bytes_saved(), (1024 - 16) * element_size);
+
+        // Normalized FFTs scale their output separately, so they share the same table
+        let fft =
+            planner.plan_fft_normalized(1024, FftDirection::Forward, FftNormalization::Unitary);
+        crate::test_utils::check_normalized_fft_algorithm(
+            &*fft,
+            1024,
+            FftDirection::Forward,
+            FftNormalization::Unitary,
+        );
+        assert_eq!(planner.twiddle_stats().computed_tables(), 1);
+    }
+
     #[test]
     fn test_scalar_recipe_cache() {
         // Check that all butterflies are used
@@ -696,4 +1680,84 @@ mod unit_tests {
         is_send::<FftPlannerScalar<T>>();
         is_send::<FftPlannerAvx<T>>();
     }
+
+    #[test]
+    fn test_plan_scalar_pruned() {
+        let mut planner = FftPlannerScalar::<f32>::new();
+        for &len in &[64, 96, 120, 256, 1000, 1024, 1031] {
+            for &nonzero_inputs in &[1, 7, len / 8, len / 3, len] {
+                for &needed_outputs in &[1, 9, len / 8, len / 2, len] {
+                    for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                        let fft =
+                            planner.plan_fft_pruned(len, direction, nonzero_inputs, needed_outputs);
+                        crate::test_utils::check_pruned_fft_algorithm(
+                            &*fft,
+                            len,
+                            direction,
+                            nonzero_inputs,
+                            needed_outputs,
+                            false,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_scalar_normalized() {
+        let mut planner = FftPlannerScalar::<f32>::new();
+        for &len in &[1, 5, 30, 53, 59, 64, 120, 1000, 1024] {
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                for &normalization in &[
+                    FftNormalization::None,
+                    FftNormalization::Unitary,
+                    FftNormalization::ForwardByN,
+                    FftNormalization::InverseByN,
+                ] {
+                    let fft = planner.plan_fft_normalized(len, direction, normalization);
+                    crate::test_utils::check_normalized_fft_algorithm(
+                        &*fft,
+                        len,
+                        direction,
+                        normalization,
+                    );
+                }
+
+                // Make sure the scaled FFTs didn't leak into the cache
+                let fft = planner.plan_fft(len, direction);
+                crate::test_utils::check_fft_algorithm(&*fft, len, direction);
+            }
+        }
+    }
+
+    #[test]
+    fn test_plan_scalar_raders_padded() {
+        let mut planner = FftPlannerScalar::<f32>::new();
+        for &len in &[107, 167, 359] {
+            let recipe = planner.design_fft_for_len(len);
+            match *recipe {
+                Recipe::RadersAlgorithmPadded { ref inner_fft, .. } => {
+                    assert_eq!(inner_fft.len(), SmoothCostModel::SCALAR.choose_len(len - 2))
+                }
+                _ => panic!("Expected RadersAlgorithmPadded for len = {}", len),
+            }
+            assert_eq!(recipe.len(), len, "Recipe reports wrong length");
+
+            for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+                let fft = planner.build_fft(&recipe, direction);
+                crate::test_utils::check_fft_algorithm(&*fft, len, direction);
+
+                let normalization = FftNormalization::Unitary;
+                let scale = twiddles::compute_scale_factor(normalization, len, direction);
+                let fft = planner.build_scaled_fft(&recipe, direction, scale);
+                crate::test_utils::check_normalized_fft_algorithm(
+                    &*fft,
+                    len,
+                    direction,
+                    normalization,
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,596 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+use num_integer::gcd;
+use num_traits::{Float, Zero};
+
+use crate::{Fft, FftDirection, FftNum};
+
+/// Resamples signals from one length to another, by zero-padding or truncating their spectrum.
+///
+/// The input is transformed with a forward FFT of size `input_len`. The lowest `min(input_len, output_len)` frequency bins are copied
+/// into a spectrum of size `output_len`, which is transformed back with an inverse FFT. The output is normalized, so a signal resampled to
+/// the same length is returned unchanged.
+///
+/// If the shorter of the two lengths is even, its Nyquist bin needs special handling:
+///
+/// - When downsampling, the output's Nyquist bin is the sum of the two input bins that alias onto it.
+/// - When upsampling, the input's Nyquist bin is split evenly between the positive and negative Nyquist frequencies of the output.
+///
+/// This way, real inputs always produce real outputs, and resampling a band-limited signal gives the same result as evaluating
+/// that signal at the new sample positions.
+///
+/// ~~~
+/// // Upsample a signal of length 1200 to length 1600
+/// use rustfft::{FftPlanner, num_complex::Complex, resample::FftResampler};
+///
+/// let mut planner = FftPlanner::new();
+/// let resampler = FftResampler::new(planner.plan_fft_forward(1200), planner.plan_fft_inverse(1600));
+///
+/// let input = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 1200];
+/// let mut output = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 1600];
+/// resampler.process(&input, &mut output);
+///
+/// // Real signals can be resampled directly
+/// let real_input = vec![0.0f32; 1200];
+/// let mut real_output = vec![0.0f32; 1600];
+/// resampler.process_real(&real_input, &mut real_output);
+/// ~~~
+pub struct FftResampler<T> {
+    forward_fft: Arc<dyn Fft<T>>,
+    inverse_fft: Arc<dyn Fft<T>>,
+
+    scale: T,
+    fft_scratch_len: usize,
+}
+
+impl<T: FftNum> FftResampler<T> {
+    /// Creates a resampler which converts signals of length `forward_fft.len()` to signals of length `inverse_fft.len()`.
+    ///
+    /// # Panics
+    /// Panics if `forward_fft` isn't a forward FFT, if `inverse_fft` isn't an inverse FFT, or if either of them has length 0.
+    pub fn new(forward_fft: Arc<dyn Fft<T>>, inverse_fft: Arc<dyn Fft<T>>) -> Self {
+        assert_eq!(
+            forward_fft.fft_direction(),
+            FftDirection::Forward,
+            "forward_fft must be a forward FFT"
+        );
+        assert_eq!(
+            inverse_fft.fft_direction(),
+            FftDirection::Inverse,
+            "inverse_fft must be an inverse FFT"
+        );
+        assert!(
+            forward_fft.len() > 0 && inverse_fft.len() > 0,
+            "Resampler lengths must be nonzero. Got input_len = {}, output_len = {}",
+            forward_fft.len(),
+            inverse_fft.len()
+        );
+
+        let scale = T::from_f64(1.0 / forward_fft.len() as f64).unwrap();
+        let fft_scratch_len = core::cmp::max(
+            forward_fft.get_inplace_scratch_len(),
+            inverse_fft.get_inplace_scratch_len(),
+        );
+
+        Self {
+            forward_fft,
+            inverse_fft,
+            scale,
+            fft_scratch_len,
+        }
+    }
+
+    /// The length of the signals passed to `process` and `process_real`
+    pub fn input_len(&self) -> usize {
+        self.forward_fft.len()
+    }
+
+    /// The length of the signals produced by `process` and `process_real`
+    pub fn output_len(&self) -> usize {
+        self.inverse_fft.len()
+    }
+
+    /// Returns the size of the scratch buffer required by `process_with_scratch` and `process_real_with_scratch`
+    pub fn get_scratch_len(&self) -> usize {
+        self.input_len() + self.output_len() + self.fft_scratch_len
+    }
+
+    /// Resamples `input` to the length of `output`.
+    ///
+    /// Convenience method that allocates a scratch buffer with the required length, and then calls `process_with_scratch`.
+    /// If you want to avoid this allocation, call `process_with_scratch` instead.
+    ///
+    /// # Panics
+    /// Panics if `input.len() != self.input_len()` or `output.len() != self.output_len()`
+    pub fn process(&self, input: &[Complex<T>], output: &mut [Complex<T>]) {
+        let mut scratch = vec![Complex::zero(); self.get_scratch_len()];
+        self.process_with_scratch(input, output, &mut scratch);
+    }
+
+    /// Resamples `input` to the length of `output`, using the provided scratch buffer.
+    ///
+    /// # Panics
+    /// Panics if `input.len() != self.input_len()`, `output.len() != self.output_len()`, or `scratch.len() < self.get_scratch_len()`
+    pub fn process_with_scratch(
+        &self,
+        input: &[Complex<T>],
+        output: &mut [Complex<T>],
+        scratch: &mut [Complex<T>],
+    ) {
+        self.check_buffers(input.len(), output.len(), scratch.len());
+
+        let (spectrum, fft_scratch) = scratch.split_at_mut(self.input_len());
+        spectrum.copy_from_slice(input);
+        self.resample_spectrum(spectrum, output, fft_scratch);
+    }
+
+    /// Resamples the real signal `input` to the length of `output`.
+    ///
+    /// Convenience method that allocates a scratch buffer with the required length, and then calls `process_real_with_scratch`.
+    /// If you want to avoid this allocation, call `process_real_with_scratch` instead.
+    ///
+    /// # Panics
+    /// Panics if `input.len() != self.input_len()` or `output.len() != self.output_len()`
+    pub fn process_real(&self, input: &[T], output: &mut [T]) {
+        let mut scratch = vec![Complex::zero(); self.get_scratch_len()];
+        self.process_real_with_scratch(input, output, &mut scratch);
+    }
+
+    /// Resamples the real signal `input` to the length of `output`, using the provided scratch buffer.
+    ///
+    /// # Panics
+    /// Panics if `input.len() != self.input_len()`, `output.len() != self.output_len()`, or `scratch.len() < self.get_scratch_len()`
+    pub fn process_real_with_scratch(
+        &self,
+        input: &[T],
+        output: &mut [T],
+        scratch: &mut [Complex<T>],
+    ) {
+        self.check_buffers(input.len(), output.len(), scratch.len());
+
+        let (spectrum, scratch) = scratch.split_at_mut(self.input_len());
+        let (complex_output, fft_scratch) = scratch.split_at_mut(self.output_len());
+        for (complex, &real) in spectrum.iter_mut().zip(input.iter()) {
+            *complex = Complex::new(real, T::zero());
+        }
+        self.resample_spectrum(spectrum, complex_output, fft_scratch);
+
+        // The spectrum is conjugate-symmetric, so the imaginary part of the output is zero, up to rounding error
+        for (real, complex) in output.iter_mut().zip(complex_output.iter()) {
+            *real = complex.re;
+        }
+    }
+
+    fn check_buffers(&self, input_len: usize, output_len: usize, scratch_len: usize) {
+        assert_eq!(
+            input_len,
+            self.input_len(),
+            "Provided input buffer has the wrong length. Expected {}, got {}",
+            self.input_len(),
+            input_len
+        );
+        assert_eq!(
+            output_len,
+            self.output_len(),
+            "Provided output buffer has the wrong length. Expected {}, got {}",
+            self.output_len(),
+            output_len
+        );
+        assert!(
+            scratch_len >= self.get_scratch_len(),
+            "Not enough scratch space was provided. Expected scratch len >= {}, got scratch len = {}",
+            self.get_scratch_len(),
+            scratch_len
+        );
+    }
+
+    // Transforms `spectrum`, copies the retained frequency bins into `output`, and transforms `output` back
+    fn resample_spectrum(
+        &self,
+        spectrum: &mut [Complex<T>],
+        output: &mut [Complex<T>],
+        fft_scratch: &mut [Complex<T>],
+    ) {
+        let fft_scratch = &mut fft_scratch[..self.fft_scratch_len];
+        self.forward_fft.process_with_scratch(spectrum, fft_scratch);
+
+        let input_len = spectrum.len();
+        let output_len = output.len();
+        let shared_len = core::cmp::min(input_len, output_len);
+
+        for element in output.iter_mut() {
+            *element = Complex::zero();
+        }
+
+        // Copy the nonnegative frequencies, up to and including the Nyquist bin if `shared_len` is even
+        let positive_len = shared_len / 2 + 1;
+        for (output_bin, input_bin) in output[..positive_len]
+            .iter_mut()
+            .zip(spectrum[..positive_len].iter())
+        {
+            *output_bin = *input_bin * self.scale;
+        }
+
+        // Copy the negative frequencies, excluding the Nyquist bin
+        let negative_len = shared_len - positive_len;
+        for (output_bin, input_bin) in output[output_len - negative_len..]
+            .iter_mut()
+            .zip(spectrum[input_len - negative_len..].iter())
+        {
+            *output_bin = *input_bin * self.scale;
+        }
+
+        let nyquist = shared_len / 2;
+        if nyquist * 2 == shared_len {
+            if output_len < input_len {
+                // Both the positive and negative input frequencies at the output's Nyquist rate alias onto the output's Nyquist bin
+                output[nyquist] = output[nyquist] + spectrum[input_len - nyquist] * self.scale;
+            } else if input_len < output_len {
+                // Split the input's Nyquist bin evenly between the positive and negative output frequencies
+                let half = output[nyquist] * T::from_f64(0.5).unwrap();
+                output[nyquist] = half;
+                output[output_len - nyquist] = half;
+            }
+        }
+
+        self.inverse_fft.process_with_scratch(output, fft_scratch);
+    }
+}
+
+/// Resamples streams of samples by a rational factor, using a polyphase windowed-sinc filter.
+///
+/// Unlike [`FftResampler`](crate::resample::FftResampler), which resamples a whole signal at once, `PolyphaseResampler` processes its input
+/// in blocks of any size, carrying its filter state from one block to the next. This makes it suitable for streaming use, at the cost of
+/// a less sharp filter, and a delay of `(taps_per_phase * up - 1) / (2 * up)` input samples.
+///
+/// The stream is conceptually upsampled by `up`, low-pass filtered, and downsampled by `down`, where `up / down` is `output_rate / input_rate` in lowest terms.
+/// Only the filter phases that contribute to an output sample are computed.
+///
+/// ~~~
+/// // Convert a stream from 44100 Hz to 48000 Hz
+/// use rustfft::{num_complex::Complex, resample::PolyphaseResampler};
+///
+/// let mut resampler = PolyphaseResampler::<f32>::new(44100, 48000, 32);
+///
+/// let mut output = Vec::new();
+/// for _ in 0..10 {
+///     let block = vec![Complex{ re: 0.0f32, im: 0.0f32 }; 441];
+///     resampler.process(&block, &mut output);
+/// }
+/// assert_eq!(output.len(), 4800);
+/// ~~~
+pub struct PolyphaseResampler<T> {
+    // Filter coefficients, grouped by phase. Phase `p` occupies `coefficients[p * taps_per_phase..(p + 1) * taps_per_phase]`
+    coefficients: Box<[T]>,
+    taps_per_phase: usize,
+
+    up: usize,
+    down: usize,
+
+    // The last `taps_per_phase - 1` input samples from the previous block
+    history: Vec<Complex<T>>,
+    // Position of the next output sample at the upsampled rate, relative to the start of the next block
+    next_output_time: usize,
+}
+
+impl<T: FftNum> PolyphaseResampler<T> {
+    /// Creates a resampler which converts streams sampled at `input_rate` to streams sampled at `output_rate`.
+    ///
+    /// Each output sample is computed from `taps_per_phase` input samples. Higher values give a sharper anti-aliasing filter, at the cost of speed and delay.
+    ///
+    /// # Panics
+    /// Panics if `input_rate`, `output_rate` or `taps_per_phase` is 0.
+    pub fn new(input_rate: usize, output_rate: usize, taps_per_phase: usize) -> Self {
+        assert!(
+            input_rate > 0 && output_rate > 0,
+            "Resampler rates must be nonzero. Got input_rate = {}, output_rate = {}",
+            input_rate,
+            output_rate
+        );
+        assert!(taps_per_phase > 0, "taps_per_phase must be nonzero");
+
+        let divisor = gcd(input_rate, output_rate);
+        let up = output_rate / divisor;
+        let down = input_rate / divisor;
+
+        // Design a windowed-sinc low-pass filter at the upsampled rate, with its cutoff at the lower of the two Nyquist frequencies
+        let filter_len = up * taps_per_phase;
+        let cutoff = 0.5 / core::cmp::max(up, down) as f64;
+        let center = (filter_len - 1) as f64 * 0.5;
+        let prototype: Vec<f64> = (0..filter_len)
+            .map(|i| {
+                let x = i as f64 - center;
+                let sinc = if x == 0.0 {
+                    2.0 * cutoff
+                } else {
+                    Float::sin(2.0 * core::f64::consts::PI * cutoff * x)
+                        / (core::f64::consts::PI * x)
+                };
+                sinc * blackman_window(i, filter_len)
+            })
+            .collect();
+
+        // Normalize so that each output sample has unity gain at DC
+        let gain = up as f64 / prototype.iter().sum::<f64>();
+
+        let mut coefficients = Vec::with_capacity(filter_len);
+        for phase in 0..up {
+            for tap in 0..taps_per_phase {
+                coefficients.push(T::from_f64(prototype[phase + tap * up] * gain).unwrap());
+            }
+        }
+
+        Self {
+            coefficients: coefficients.into_boxed_slice(),
+            taps_per_phase,
+            up,
+            down,
+            history: vec![Complex::zero(); taps_per_phase - 1],
+            next_output_time: 0,
+        }
+    }
+
+    /// The upsampling and downsampling factors of this resampler, in lowest terms.
+    pub fn ratio(&self) -> (usize, usize) {
+        (self.up, self.down)
+    }
+
+    /// Resamples the next block of the input stream, appending the resulting output samples to `output`.
+    ///
+    /// Over the lifetime of the resampler, the total number of output samples is `ceil(total_input * up / down)`.
+    pub fn process(&mut self, input: &[Complex<T>], output: &mut Vec<Complex<T>>) {
+        let history_len = self.taps_per_phase - 1;
+
+        // Append the new block to the history, so that every filter window is contiguous
+        self.history.extend_from_slice(input);
+        let buffer = &self.history;
+
+        let mut time = self.next_output_time;
+        let mut input_index = time / self.up;
+        while input_index < input.len() {
+            let phase = time % self.up;
+            let phase_coefficients =
+                &self.coefficients[phase * self.taps_per_phase..(phase + 1) * self.taps_per_phase];
+
+            // The newest sample contributing to this output is input[input_index], which is at buffer[input_index + history_len]
+            let window = &buffer[input_index..input_index + self.taps_per_phase];
+            let mut sum = Complex::zero();
+            for (&coefficient, &sample) in phase_coefficients.iter().zip(window.iter().rev()) {
+                sum = sum + sample * coefficient;
+            }
+            output.push(sum);
+
+            time += self.down;
+            input_index = time / self.up;
+        }
+        self.next_output_time = time - input.len() * self.up;
+
+        let consumed_len = self.history.len() - history_len;
+        self.history.drain(..consumed_len);
+    }
+
+    /// Clears the filter state, so that the next call to `process` starts a new stream.
+    pub fn reset(&mut self) {
+        for element in self.history.iter_mut() {
+            *element = Complex::zero();
+        }
+        self.next_output_time = 0;
+    }
+}
+
+fn blackman_window(index: usize, len: usize) -> f64 {
+    if len == 1 {
+        return 1.0;
+    }
+    let angle = 2.0 * core::f64::consts::PI * index as f64 / (len - 1) as f64;
+    0.42 - 0.5 * Float::cos(angle) + 0.08 * Float::cos(2.0 * angle)
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::Dft;
+    use crate::test_utils::compare_vectors;
+
+    // Evaluates a band-limited signal made of the given (frequency, amplitude) pairs, sampled `len` times per period
+    fn band_limited_signal(components: &[(f64, Complex<f64>)], len: usize) -> Vec<Complex<f64>> {
+        (0..len)
+            .map(|i| {
+                components
+                    .iter()
+                    .map(|&(frequency, amplitude)| {
+                        let angle = 2.0 * core::f64::consts::PI * frequency * i as f64 / len as f64;
+                        amplitude * Complex::from_polar(1.0, angle)
+                    })
+                    .fold(Complex::zero(), |a, b| a + b)
+            })
+            .collect()
+    }
+
+    fn make_resampler(input_len: usize, output_len: usize) -> FftResampler<f64> {
+        FftResampler::new(
+            Arc::new(Dft::new(input_len, FftDirection::Forward)),
+            Arc::new(Dft::new(output_len, FftDirection::Inverse)),
+        )
+    }
+
+    #[test]
+    fn test_fft_resampler_band_limited() {
+        for input_len in 1..20 {
+            for output_len in 1..20 {
+                // Only frequencies strictly below both Nyquist rates survive the resampling unchanged
+                let shared_len = core::cmp::min(input_len, output_len);
+                let max_frequency = (shared_len as f64 - 1.0) / 2.0;
+                let components: Vec<_> = (0..=max_frequency as i64)
+                    .flat_map(|f| {
+                        let amplitude = Complex::new(1.0 + f as f64, 0.5 - f as f64);
+                        vec![(f as f64, amplitude), (-f as f64, amplitude.conj() * 0.5)]
+                    })
+                    .collect();
+
+                let input = band_limited_signal(&components, input_len);
+                let expected = band_limited_signal(&components, output_len);
+
+                let resampler = make_resampler(input_len, output_len);
+                let mut output = vec![Complex::zero(); output_len];
+                resampler.process(&input, &mut output);
+
+                assert!(
+                    compare_vectors(&expected, &output),
+                    "input_len = {}, output_len = {}",
+                    input_len,
+                    output_len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_resampler_nyquist() {
+        // Upsampling a signal at its Nyquist frequency must produce a real cosine at the same frequency
+        for &(input_len, output_len) in &[(4, 8), (6, 9), (10, 11), (2, 7)] {
+            let input: Vec<f64> = (0..input_len)
+                .map(|i| if i % 2 == 0 { 1.0 } else { -1.0 })
+                .collect();
+            let expected: Vec<Complex<f64>> = (0..output_len)
+                .map(|i| {
+                    let angle = core::f64::consts::PI * (i * input_len) as f64 / output_len as f64;
+                    Complex::new(angle.cos(), 0.0)
+                })
+                .collect();
+
+            let resampler = make_resampler(input_len, output_len);
+            let mut output = vec![0.0; output_len];
+            resampler.process_real(&input, &mut output);
+
+            let output: Vec<Complex<f64>> =
+                output.iter().map(|&re| Complex::new(re, 0.0)).collect();
+            assert!(
+                compare_vectors(&expected, &output),
+                "input_len = {}, output_len = {}",
+                input_len,
+                output_len
+            );
+        }
+
+        // Downsampling a cosine at the output's Nyquist frequency must keep its full amplitude
+        for &(input_len, output_len) in &[(8, 4), (9, 6), (11, 10), (7, 2)] {
+            let components = [
+                (output_len as f64 / 2.0, Complex::new(0.5, 0.0)),
+                (-(output_len as f64) / 2.0, Complex::new(0.5, 0.0)),
+            ];
+            let input: Vec<f64> = band_limited_signal(&components, input_len)
+                .iter()
+                .map(|c| c.re)
+                .collect();
+            let expected: Vec<Complex<f64>> = (0..output_len)
+                .map(|i| Complex::new(if i % 2 == 0 { 1.0 } else { -1.0 }, 0.0))
+                .collect();
+
+            let resampler = make_resampler(input_len, output_len);
+            let mut output = vec![0.0; output_len];
+            resampler.process_real(&input, &mut output);
+
+            let output: Vec<Complex<f64>> =
+                output.iter().map(|&re| Complex::new(re, 0.0)).collect();
+            assert!(
+                compare_vectors(&expected, &output),
+                "input_len = {}, output_len = {}",
+                input_len,
+                output_len
+            );
+        }
+    }
+
+    #[test]
+    fn test_fft_resampler_real_output_is_real() {
+        for &(input_len, output_len) in &[(16, 10), (10, 16), (15, 8), (8, 15), (12, 12)] {
+            let input: Vec<f64> = (0..input_len).map(|i| ((i * 7) % 5) as f64 - 2.0).collect();
+            let complex_input: Vec<Complex<f64>> =
+                input.iter().map(|&re| Complex::new(re, 0.0)).collect();
+
+            let resampler = make_resampler(input_len, output_len);
+            let mut complex_output = vec![Complex::zero(); output_len];
+            resampler.process(&complex_input, &mut complex_output);
+            for element in &complex_output {
+                assert!(
+                    element.im.abs() < 1e-10,
+                    "input_len = {}, output_len = {}",
+                    input_len,
+                    output_len
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_polyphase_resampler_lengths() {
+        for &(input_rate, output_rate) in &[(1, 1), (2, 3), (3, 2), (44100, 48000), (5, 1)] {
+            let mut resampler = PolyphaseResampler::<f64>::new(input_rate, output_rate, 8);
+            let (up, down) = resampler.ratio();
+
+            let mut output = Vec::new();
+            let mut total_input = 0;
+            for block_len in 0..30 {
+                resampler.process(&vec![Complex::zero(); block_len], &mut output);
+                total_input += block_len;
+                assert_eq!(output.len(), (total_input * up + down - 1) / down);
+            }
+        }
+    }
+
+    #[test]
+    fn test_polyphase_resampler_block_independence() {
+        // Processing a stream in one block or in many blocks must give identical results
+        let input: Vec<Complex<f64>> = (0..200)
+            .map(|i| Complex::new((i as f64 * 0.1).sin(), (i as f64 * 0.07).cos()))
+            .collect();
+
+        let mut resampler = PolyphaseResampler::new(3, 5, 12);
+        let mut expected = Vec::new();
+        resampler.process(&input, &mut expected);
+
+        resampler.reset();
+        let mut output = Vec::new();
+        for block in input.chunks(7) {
+            resampler.process(block, &mut output);
+        }
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn test_polyphase_resampler_sine() {
+        // A low frequency sine wave should come out as the same sine wave at the new rate, delayed by the filter
+        let (input_rate, output_rate, taps_per_phase) = (4, 5, 32);
+        let mut resampler = PolyphaseResampler::new(input_rate, output_rate, taps_per_phase);
+        let (up, _) = resampler.ratio();
+        let delay = (taps_per_phase * up - 1) as f64 / (2 * up) as f64;
+
+        let frequency = 0.03;
+        let input: Vec<Complex<f64>> = (0..400)
+            .map(|i| Complex::from_polar(1.0, 2.0 * core::f64::consts::PI * frequency * i as f64))
+            .collect();
+        let mut output = Vec::new();
+        resampler.process(&input, &mut output);
+
+        // Skip the start-up transient
+        let start = 2 * taps_per_phase * output_rate / input_rate;
+        let expected: Vec<Complex<f64>> = (start..output.len())
+            .map(|i| {
+                let time = i as f64 * input_rate as f64 / output_rate as f64 - delay;
+                Complex::from_polar(1.0, 2.0 * core::f64::consts::PI * frequency * time)
+            })
+            .collect();
+        for (actual, expected) in output[start..].iter().zip(expected.iter()) {
+            assert!(
+                (actual - expected).norm() < 1e-2,
+                "{} != {}",
+                actual,
+                expected
+            );
+        }
+    }
+}
@@ -0,0 +1,617 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use num_complex::Complex;
+use num_integer::div_ceil;
+use num_traits::{Float, Zero};
+
+use crate::{Fft, FftDirection, FftNum};
+
+/// Rearranges the output of a FFT so that the zero-frequency element is in the center.
+///
+/// Element `i` of the result is element `(i + len - len / 2) % len` of the input. For even lengths, the Nyquist element ends up first.
+/// For odd lengths, `fftshift` and [`ifftshift`](crate::spectral::ifftshift) are different operations, and each undoes the other.
+///
+/// ~~~
+/// use rustfft::spectral::fftshift;
+///
+/// let mut buffer = [0, 1, 2, -2, -1];
+/// fftshift(&mut buffer);
+/// assert_eq!(buffer, [-2, -1, 0, 1, 2]);
+/// ~~~
+pub fn fftshift<T>(buffer: &mut [T]) {
+    let len = buffer.len();
+    buffer.rotate_right(len / 2);
+}
+
+/// Undoes [`fftshift`](crate::spectral::fftshift), moving the zero-frequency element from the center back to the start of the buffer.
+///
+/// ~~~
+/// use rustfft::spectral::ifftshift;
+///
+/// let mut buffer = [-2, -1, 0, 1, 2];
+/// ifftshift(&mut buffer);
+/// assert_eq!(buffer, [0, 1, 2, -2, -1]);
+/// ~~~
+pub fn ifftshift<T>(buffer: &mut [T]) {
+    let len = buffer.len();
+    buffer.rotate_left(len / 2);
+}
+
+/// Applies [`fftshift`](crate::spectral::fftshift) along every axis of a N-dimensional buffer.
+///
+/// The buffer is stored in row-major order: The last entry of `shape` is the length of the contiguous axis.
+///
+/// # Panics
+/// Panics if the product of `shape` is not equal to `buffer.len()`
+pub fn fftshift_nd<T>(buffer: &mut [T], shape: &[usize]) {
+    shift_nd(buffer, shape, |len| len / 2);
+}
+
+/// Applies [`ifftshift`](crate::spectral::ifftshift) along every axis of a N-dimensional buffer.
+///
+/// The buffer is stored in row-major order: The last entry of `shape` is the length of the contiguous axis.
+///
+/// # Panics
+/// Panics if the product of `shape` is not equal to `buffer.len()`
+pub fn ifftshift_nd<T>(buffer: &mut [T], shape: &[usize]) {
+    shift_nd(buffer, shape, |len| len - len / 2);
+}
+
+// Rotates every axis of `buffer` right by `right_shift(axis_len)` elements
+fn shift_nd<T>(buffer: &mut [T], shape: &[usize], right_shift: impl Fn(usize) -> usize) {
+    assert_eq!(
+        shape.iter().product::<usize>(),
+        buffer.len(),
+        "Buffer length doesn't match its shape. Expected len = {:?}, got len = {}",
+        shape,
+        buffer.len()
+    );
+    if buffer.is_empty() {
+        return;
+    }
+
+    let mut stride = buffer.len();
+    for &axis_len in shape {
+        stride /= axis_len;
+
+        // Each contiguous block of `axis_len * stride` elements holds one full run of this axis.
+        // Rotating the whole block by a multiple of `stride` rotates the axis, and leaves the faster axes alone
+        let shift = right_shift(axis_len) % axis_len;
+        for block in buffer.chunks_exact_mut(axis_len * stride) {
+            block.rotate_right(shift * stride);
+        }
+    }
+}
+
+/// Returns the frequency of each element of a FFT's output, in cycles per unit of `sample_spacing`.
+///
+/// The result is `[0, 1, ..., ceil(len / 2) - 1, -floor(len / 2), ..., -1] / (len * sample_spacing)`.
+///
+/// ~~~
+/// use rustfft::spectral::fftfreq;
+///
+/// assert_eq!(fftfreq(4, 0.5f64), vec![0.0, 0.5, -1.0, -0.5]);
+/// ~~~
+pub fn fftfreq<T: FftNum>(len: usize, sample_spacing: T) -> Vec<T> {
+    let scale = T::one() / (T::from_usize(len).unwrap() * sample_spacing);
+    let positive_len = div_ceil(len, 2);
+    (0..len)
+        .map(|i| {
+            if i < positive_len {
+                T::from_usize(i).unwrap() * scale
+            } else {
+                -T::from_usize(len - i).unwrap() * scale
+            }
+        })
+        .collect()
+}
+
+/// Returns the nonnegative frequencies of a FFT of size `len`, in cycles per unit of `sample_spacing`.
+///
+/// The result has `len / 2 + 1` elements: `[0, 1, ..., len / 2] / (len * sample_spacing)`. These are the frequencies present in the spectrum of a real signal,
+/// which is conjugate-symmetric.
+///
+/// ~~~
+/// use rustfft::spectral::rfftfreq;
+///
+/// assert_eq!(rfftfreq(5, 0.1f64), vec![0.0, 2.0, 4.0]);
+/// ~~~
+pub fn rfftfreq<T: FftNum>(len: usize, sample_spacing: T) -> Vec<T> {
+    let scale = T::one() / (T::from_usize(len).unwrap() * sample_spacing);
+    (0..len / 2 + 1)
+        .map(|i| T::from_usize(i).unwrap() * scale)
+        .collect()
+}
+
+/// Unwraps a sequence of phase angles in radians, by adding multiples of 2π wherever consecutive angles differ by more than π.
+///
+/// ~~~
+/// use rustfft::spectral::unwrap_phase;
+///
+/// let mut phase = [3.0f64, -3.0, -0.5];
+/// unwrap_phase(&mut phase);
+/// assert!((phase[1] - (2.0 * std::f64::consts::PI - 3.0)).abs() < 1e-12);
+/// ~~~
+pub fn unwrap_phase<T: FftNum + Float>(phase: &mut [T]) {
+    let pi = T::from_f64(core::f64::consts::PI).unwrap();
+    let two_pi = pi + pi;
+
+    let mut correction = T::zero();
+    let mut previous = match phase.first() {
+        Some(&first) => first,
+        None => return,
+    };
+    for element in phase.iter_mut().skip(1) {
+        let current = *element;
+        let difference = current - previous;
+        if difference > pi || difference < -pi {
+            // Round the jump to the nearest multiple of 2π
+            correction = correction - ((difference + pi) / two_pi).floor() * two_pi;
+        }
+        previous = current;
+        *element = current + correction;
+    }
+}
+
+/// Computes cepstra of real signals, and reconstructs minimum-phase signals from them.
+///
+/// All methods work on signals of the same length as the provided FFTs. Since the cepstrum is computed with a finite FFT, it is
+/// time-aliased. Zero-padding the signal reduces the aliasing.
+///
+/// The methods take the logarithm of the signal's magnitude spectrum, so the spectrum must not contain any zeroes.
+///
+/// ~~~
+/// // Compute the real cepstrum of a signal of size 1024
+/// use rustfft::{FftPlanner, spectral::Cepstrum};
+///
+/// let mut planner = FftPlanner::new();
+/// let cepstrum = Cepstrum::new(planner.plan_fft_forward(1024), planner.plan_fft_inverse(1024));
+///
+/// let mut signal = vec![0.0f32; 1024];
+/// signal[0] = 1.0;
+/// signal[1] = 0.5;
+///
+/// let mut output = vec![0.0f32; 1024];
+/// cepstrum.real_cepstrum(&signal, &mut output);
+/// ~~~
+pub struct Cepstrum<T> {
+    forward_fft: Arc<dyn Fft<T>>,
+    inverse_fft: Arc<dyn Fft<T>>,
+
+    fft_scratch_len: usize,
+}
+
+impl<T: FftNum + Float> Cepstrum<T> {
+    /// Creates a `Cepstrum` instance which computes cepstra of signals of length `forward_fft.len()`
+    ///
+    /// # Panics
+    /// Panics if `forward_fft` isn't a forward FFT, if `inverse_fft` isn't an inverse FFT, or if their lengths differ.
+    pub fn new(forward_fft: Arc<dyn Fft<T>>, inverse_fft: Arc<dyn Fft<T>>) -> Self {
+        assert_eq!(
+            forward_fft.fft_direction(),
+            FftDirection::Forward,
+            "forward_fft must be a forward FFT"
+        );
+        assert_eq!(
+            inverse_fft.fft_direction(),
+            FftDirection::Inverse,
+            "inverse_fft must be an inverse FFT"
+        );
+        assert_eq!(
+            forward_fft.len(),
+            inverse_fft.len(),
+            "forward_fft and inverse_fft must have the same length. Got {} and {}",
+            forward_fft.len(),
+            inverse_fft.len()
+        );
+
+        let fft_scratch_len = core::cmp::max(
+            forward_fft.get_inplace_scratch_len(),
+            inverse_fft.get_inplace_scratch_len(),
+        );
+
+        Self {
+            forward_fft,
+            inverse_fft,
+            fft_scratch_len,
+        }
+    }
+
+    /// The length of the signals this instance processes
+    pub fn len(&self) -> usize {
+        self.forward_fft.len()
+    }
+
+    /// Returns true if this instance processes empty signals
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size of the scratch buffer required by the `_with_scratch` methods
+    pub fn get_scratch_len(&self) -> usize {
+        self.len() + self.fft_scratch_len
+    }
+
+    /// Computes the real cepstrum of `input`, `IFFT(ln|FFT(input)|)`, and stores it in `output`.
+    ///
+    /// Convenience method that allocates a scratch buffer with the required length, and then calls `real_cepstrum_with_scratch`.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`
+    pub fn real_cepstrum(&self, input: &[T], output: &mut [T]) {
+        let mut scratch = vec![Complex::zero(); self.get_scratch_len()];
+        self.real_cepstrum_with_scratch(input, output, &mut scratch);
+    }
+
+    /// Computes the real cepstrum of `input`, `IFFT(ln|FFT(input)|)`, and stores it in `output`, using the provided scratch buffer.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`, or if `scratch.len() < self.get_scratch_len()`
+    pub fn real_cepstrum_with_scratch(
+        &self,
+        input: &[T],
+        output: &mut [T],
+        scratch: &mut [Complex<T>],
+    ) {
+        self.check_buffers(input.len(), output.len(), scratch.len());
+        let (buffer, fft_scratch) = scratch.split_at_mut(self.len());
+        let fft_scratch = &mut fft_scratch[..self.fft_scratch_len];
+
+        self.log_magnitude_spectrum(input, buffer, fft_scratch);
+        self.inverse_fft.process_with_scratch(buffer, fft_scratch);
+        self.store_real_part(buffer, output);
+    }
+
+    /// Computes the complex cepstrum of `input`, `IFFT(ln|FFT(input)| + i * unwrap(arg(FFT(input))))`, and stores it in `output`.
+    ///
+    /// Before the inverse FFT, the linear phase component of the unwrapped phase is removed, so that the cepstrum isn't dominated by it.
+    /// Returns the removed delay, in samples. This delay is needed to invert the complex cepstrum.
+    ///
+    /// Convenience method that allocates a scratch buffer with the required length, and then calls `complex_cepstrum_with_scratch`.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`
+    pub fn complex_cepstrum(&self, input: &[T], output: &mut [T]) -> isize {
+        let mut scratch = vec![Complex::zero(); self.get_scratch_len()];
+        self.complex_cepstrum_with_scratch(input, output, &mut scratch)
+    }
+
+    /// Computes the complex cepstrum of `input`, `IFFT(ln|FFT(input)| + i * unwrap(arg(FFT(input))))`, and stores it in `output`, using the provided scratch buffer.
+    ///
+    /// Before the inverse FFT, the linear phase component of the unwrapped phase is removed, so that the cepstrum isn't dominated by it.
+    /// Returns the removed delay, in samples. This delay is needed to invert the complex cepstrum.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`, or if `scratch.len() < self.get_scratch_len()`
+    pub fn complex_cepstrum_with_scratch(
+        &self,
+        input: &[T],
+        output: &mut [T],
+        scratch: &mut [Complex<T>],
+    ) -> isize {
+        self.check_buffers(input.len(), output.len(), scratch.len());
+        let len = self.len();
+        if len == 0 {
+            return 0;
+        }
+        let (buffer, fft_scratch) = scratch.split_at_mut(len);
+        let fft_scratch = &mut fft_scratch[..self.fft_scratch_len];
+
+        for (complex, &real) in buffer.iter_mut().zip(input.iter()) {
+            *complex = Complex::new(real, T::zero());
+        }
+        self.forward_fft.process_with_scratch(buffer, fft_scratch);
+
+        // Temporarily store the phase in `output`, so that we can unwrap it
+        for (phase, element) in output.iter_mut().zip(buffer.iter()) {
+            *phase = element.arg();
+        }
+        unwrap_phase(output);
+
+        // Remove the linear phase. The phase at the Nyquist frequency is a multiple of π for real signals, and that multiple is the negated delay
+        let pi = T::from_f64(core::f64::consts::PI).unwrap();
+        let center = div_ceil(len, 2);
+        let delay = -(output[center % len] / pi).round();
+        let center_t = T::from_usize(center).unwrap();
+        for (i, (element, &phase)) in buffer.iter_mut().zip(output.iter()).enumerate() {
+            let linear_phase = -pi * delay * T::from_usize(i).unwrap() / center_t;
+            *element = Complex::new(element.norm().ln(), phase - linear_phase);
+        }
+
+        self.inverse_fft.process_with_scratch(buffer, fft_scratch);
+        self.store_real_part(buffer, output);
+
+        delay.to_isize().unwrap()
+    }
+
+    /// Computes the minimum-phase signal with the same magnitude spectrum as `input`, and stores it in `output`.
+    ///
+    /// The minimum-phase signal is reconstructed from the real cepstrum, by folding its anticausal part onto its causal part.
+    ///
+    /// Convenience method that allocates a scratch buffer with the required length, and then calls `minimum_phase_with_scratch`.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`
+    pub fn minimum_phase(&self, input: &[T], output: &mut [T]) {
+        let mut scratch = vec![Complex::zero(); self.get_scratch_len()];
+        self.minimum_phase_with_scratch(input, output, &mut scratch);
+    }
+
+    /// Computes the minimum-phase signal with the same magnitude spectrum as `input`, and stores it in `output`, using the provided scratch buffer.
+    ///
+    /// The minimum-phase signal is reconstructed from the real cepstrum, by folding its anticausal part onto its causal part.
+    ///
+    /// # Panics
+    /// Panics if `input.len()` or `output.len()` is not equal to `self.len()`, or if `scratch.len() < self.get_scratch_len()`
+    pub fn minimum_phase_with_scratch(
+        &self,
+        input: &[T],
+        output: &mut [T],
+        scratch: &mut [Complex<T>],
+    ) {
+        self.check_buffers(input.len(), output.len(), scratch.len());
+        let len = self.len();
+        if len == 0 {
+            return;
+        }
+        let (buffer, fft_scratch) = scratch.split_at_mut(len);
+        let fft_scratch = &mut fft_scratch[..self.fft_scratch_len];
+
+        // Compute the real cepstrum, normalized
+        self.log_magnitude_spectrum(input, buffer, fft_scratch);
+        self.inverse_fft.process_with_scratch(buffer, fft_scratch);
+        let scale = T::one() / T::from_usize(len).unwrap();
+
+        // Fold the cepstrum: Keep quefrency 0 (and the Nyquist quefrency if the length is even), double the positive quefrencies, and zero the negative ones
+        let half_len = len / 2;
+        let two = T::from_f64(2.0).unwrap();
+        for (i, element) in buffer.iter_mut().enumerate() {
+            let weight = if i == 0 || i * 2 == len {
+                T::one()
+            } else if i <= half_len {
+                two
+            } else {
+                T::zero()
+            };
+            *element = Complex::new(element.re * scale * weight, T::zero());
+        }
+
+        // Exponentiate the folded cepstrum's spectrum to get the minimum-phase spectrum
+        self.forward_fft.process_with_scratch(buffer, fft_scratch);
+        for element in buffer.iter_mut() {
+            *element = element.exp();
+        }
+        self.inverse_fft.process_with_scratch(buffer, fft_scratch);
+        self.store_real_part(buffer, output);
+    }
+
+    fn check_buffers(&self, input_len: usize, output_len: usize, scratch_len: usize) {
+        assert_eq!(
+            input_len,
+            self.len(),
+            "Provided input buffer has the wrong length. Expected {}, got {}",
+            self.len(),
+            input_len
+        );
+        assert_eq!(
+            output_len,
+            self.len(),
+            "Provided output buffer has the wrong length. Expected {}, got {}",
+            self.len(),
+            output_len
+        );
+        assert!(
+            scratch_len >= self.get_scratch_len(),
+            "Not enough scratch space was provided. Expected scratch len >= {}, got scratch len = {}",
+            self.get_scratch_len(),
+            scratch_len
+        );
+    }
+
+    // Stores ln|FFT(input)| in `buffer`
+    fn log_magnitude_spectrum(
+        &self,
+        input: &[T],
+        buffer: &mut [Complex<T>],
+        fft_scratch: &mut [Complex<T>],
+    ) {
+        for (complex, &real) in buffer.iter_mut().zip(input.iter()) {
+            *complex = Complex::new(real, T::zero());
+        }
+        self.forward_fft.process_with_scratch(buffer, fft_scratch);
+        for element in buffer.iter_mut() {
+            *element = Complex::new(element.norm().ln(), T::zero());
+        }
+    }
+
+    // Stores the real part of `buffer` in `output`, normalized by the FFT length
+    fn store_real_part(&self, buffer: &[Complex<T>], output: &mut [T]) {
+        let scale = T::one() / T::from_usize(self.len()).unwrap();
+        for (real, complex) in output.iter_mut().zip(buffer.iter()) {
+            *real = complex.re * scale;
+        }
+    }
+}
+
+#[cfg(test)]
+mod unit_tests {
+    use super::*;
+    use crate::algorithm::Dft;
+
+    fn make_cepstrum(len: usize) -> Cepstrum<f64> {
+        Cepstrum::new(
+            Arc::new(Dft::new(len, FftDirection::Forward)),
+            Arc::new(Dft::new(len, FftDirection::Inverse)),
+        )
+    }
+
+    fn assert_close(expected: &[f64], actual: &[f64]) {
+        assert_eq!(expected.len(), actual.len());
+        for (i, (e, a)) in expected.iter().zip(actual.iter()).enumerate() {
+            assert!(
+                (e - a).abs() < 1e-6,
+                "index {}: expected {}, got {}",
+                i,
+                e,
+                a
+            );
+        }
+    }
+
+    #[test]
+    fn test_fftshift() {
+        let mut even = [0, 1, 2, 3, 4, 5];
+        fftshift(&mut even);
+        assert_eq!(even, [3, 4, 5, 0, 1, 2]);
+        ifftshift(&mut even);
+        assert_eq!(even, [0, 1, 2, 3, 4, 5]);
+
+        let mut odd = [0, 1, 2, 3, 4];
+        fftshift(&mut odd);
+        assert_eq!(odd, [3, 4, 0, 1, 2]);
+        ifftshift(&mut odd);
+        assert_eq!(odd, [0, 1, 2, 3, 4]);
+
+        let mut odd = [0, 1, 2, 3, 4];
+        ifftshift(&mut odd);
+        assert_eq!(odd, [2, 3, 4, 0, 1]);
+
+        let mut empty: [i32; 0] = [];
+        fftshift(&mut empty);
+        ifftshift(&mut empty);
+    }
+
+    #[test]
+    fn test_fftshift_nd() {
+        // shape [2, 3]
+        let mut buffer = [0, 1, 2, 10, 11, 12];
+        fftshift_nd(&mut buffer, &[2, 3]);
+        assert_eq!(buffer, [12, 10, 11, 2, 0, 1]);
+        ifftshift_nd(&mut buffer, &[2, 3]);
+        assert_eq!(buffer, [0, 1, 2, 10, 11, 12]);
+
+        // shape [3, 1, 3]: each axis must behave like the 1D shift
+        let original: Vec<usize> = (0..9).collect();
+        let mut buffer = original.clone();
+        fftshift_nd(&mut buffer, &[3, 1, 3]);
+        assert_eq!(buffer, [8, 6, 7, 2, 0, 1, 5, 3, 4]);
+        ifftshift_nd(&mut buffer, &[3, 1, 3]);
+        assert_eq!(buffer, original);
+
+        // A 1D shape must match the 1D functions
+        for len in 0..10 {
+            let mut expected: Vec<usize> = (0..len).collect();
+            let mut actual = expected.clone();
+            fftshift(&mut expected);
+            fftshift_nd(&mut actual, &[len]);
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_fftfreq() {
+        assert_eq!(fftfreq(1, 1.0f64), vec![0.0]);
+        assert_eq!(fftfreq(4, 1.0f64), vec![0.0, 0.25, -0.5, -0.25]);
+        assert_close(&fftfreq(5, 1.0f64), &[0.0, 0.2, 0.4, -0.4, -0.2]);
+        assert_eq!(rfftfreq(1, 1.0f64), vec![0.0]);
+        assert_eq!(rfftfreq(4, 1.0f64), vec![0.0, 0.25, 0.5]);
+        assert_close(&rfftfreq(5, 1.0f64), &[0.0, 0.2, 0.4]);
+        assert_close(&fftfreq(4, 0.1f64), &[0.0, 2.5, -5.0, -2.5]);
+    }
+
+    #[test]
+    fn test_unwrap_phase() {
+        let pi = core::f64::consts::PI;
+        let expected: Vec<f64> = (0..20).map(|i| i as f64 * 0.9 - 5.0).collect();
+        let mut wrapped: Vec<f64> = expected
+            .iter()
+            .map(|&phase| phase.sin().atan2(phase.cos()))
+            .collect();
+        unwrap_phase(&mut wrapped);
+
+        // The unwrapped phase matches up to a constant multiple of 2π
+        let offset = ((expected[0] - wrapped[0]) / (2.0 * pi)).round() * 2.0 * pi;
+        let shifted: Vec<f64> = wrapped.iter().map(|&phase| phase + offset).collect();
+        assert_close(&expected, &shifted);
+    }
+
+    // x = [1, a, 0, 0, ...] with |a| < 1 is minimum phase, and ln(1 + a z^-1) = sum (-1)^(k+1) a^k / k z^-k
+    fn minimum_phase_cepstrum(a: f64, len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|k| {
+                if k == 0 {
+                    0.0
+                } else {
+                    -(-a).powi(k as i32) / k as f64
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_complex_cepstrum() {
+        let len = 64;
+        let a = 0.5;
+        let cepstrum = make_cepstrum(len);
+        let expected = minimum_phase_cepstrum(a, len);
+
+        let mut signal = vec![0.0; len];
+        signal[0] = 1.0;
+        signal[1] = a;
+        let mut output = vec![0.0; len];
+        let delay = cepstrum.complex_cepstrum(&signal, &mut output);
+        assert_eq!(delay, 0);
+        assert_close(&expected, &output);
+
+        // Delaying the signal only changes the returned delay
+        signal.rotate_right(3);
+        let delay = cepstrum.complex_cepstrum(&signal, &mut output);
+        assert_eq!(delay, 3);
+        assert_close(&expected, &output);
+    }
+
+    #[test]
+    fn test_real_cepstrum() {
+        for &len in &[64, 65] {
+            let a = -0.4;
+            let cepstrum = make_cepstrum(len);
+
+            // The real cepstrum is the even part of the complex cepstrum
+            let complex_cepstrum = minimum_phase_cepstrum(a, len);
+            let expected: Vec<f64> = (0..len)
+                .map(|k| (complex_cepstrum[k] + complex_cepstrum[(len - k) % len]) * 0.5)
+                .collect();
+
+            let mut signal = vec![0.0; len];
+            signal[0] = 1.0;
+            signal[1] = a;
+            let mut output = vec![0.0; len];
+            cepstrum.real_cepstrum(&signal, &mut output);
+            assert_close(&expected, &output);
+        }
+    }
+
+    #[test]
+    fn test_minimum_phase() {
+        for &len in &[64, 65] {
+            let cepstrum = make_cepstrum(len);
+
+            // [0.5, 1] is maximum phase, and its minimum-phase counterpart is [1, 0.5]
+            let mut signal = vec![0.0; len];
+            signal[0] = 0.5;
+            signal[1] = 1.0;
+            let mut expected = vec![0.0; len];
+            expected[0] = 1.0;
+            expected[1] = 0.5;
+
+            let mut output = vec![0.0; len];
+            cepstrum.minimum_phase(&signal, &mut output);
+            assert_close(&expected, &output);
+
+            // Minimum phase signals are unchanged
+            cepstrum.minimum_phase(&expected, &mut output);
+            assert_close(&expected, &output);
+        }
+    }
+}
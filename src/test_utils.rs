@@ -5,7 +5,7 @@ use rand::distributions::{uniform::SampleUniform, Distribution, Uniform};
 use rand::{rngs::StdRng, SeedableRng};
 
 use crate::{algorithm::Dft, Direction, FftNum, Length};
-use crate::{Fft, FftDirection};
+use crate::{Fft, FftDirection, FftError, FftNormalization};
 
 /// The seed for the random number generator used to generate
 /// random signals. It's defined here so that we have deterministic
@@ -156,6 +156,308 @@ pub fn check_fft_algorithm<T: FftNum + Float + SampleUniform>(
             );
         }
     }
+
+    // test process_immutable()
+    {
+        let input = reference_input.clone();
+        let mut scratch = vec![Zero::zero(); fft.get_immutable_scratch_len()];
+        let mut output = vec![dirty_scratch_value; len * n];
+
+        fft.process_immutable(&input, &mut output, &mut scratch);
+
+        assert!(
+            compare_vectors(&expected_output, &output),
+            "process_immutable() failed, length = {}, direction = {}",
+            len,
+            direction
+        );
+        assert_eq!(
+            input, reference_input,
+            "process_immutable() modified its input, length = {}, direction = {}",
+            len, direction
+        );
+
+        // make sure this algorithm works correctly with dirty scratch
+        if scratch.len() > 0 {
+            for item in scratch.iter_mut() {
+                *item = dirty_scratch_value;
+            }
+
+            fft.process_immutable(&input, &mut output, &mut scratch);
+
+            assert!(
+                compare_vectors(&expected_output, &output),
+                "process_immutable() failed the 'dirty scratch' test, length = {}, direction = {}",
+                len,
+                direction
+            );
+        }
+    }
+
+    // test process_split()
+    {
+        let mut re: Vec<T> = reference_input.iter().map(|c| c.re).collect();
+        let mut im: Vec<T> = reference_input.iter().map(|c| c.im).collect();
+        let mut scratch = vec![dirty_scratch_value; fft.get_split_scratch_len()];
+
+        fft.process_split(&mut re, &mut im, &mut scratch);
+
+        let output: Vec<Complex<T>> = re
+            .iter()
+            .zip(im.iter())
+            .map(|(re, im)| Complex::new(*re, *im))
+            .collect();
+        assert!(
+            compare_vectors(&expected_output, &output),
+            "process_split() failed, length = {}, direction = {}",
+            len,
+            direction
+        );
+    }
+
+    // test try_process(), try_process_with_scratch() and try_process_outofplace_with_scratch()
+    {
+        let mut buffer = reference_input.clone();
+        assert_eq!(fft.try_process(&mut buffer), Ok(()));
+        assert!(
+            compare_vectors(&expected_output, &buffer),
+            "try_process() failed, length = {}, direction = {}",
+            len,
+            direction
+        );
+
+        let mut input = reference_input.clone();
+        let mut output = vec![Zero::zero(); len * n];
+        let mut scratch = vec![Zero::zero(); fft.get_outofplace_scratch_len()];
+        assert_eq!(
+            fft.try_process_outofplace_with_scratch(&mut input, &mut output, &mut scratch),
+            Ok(())
+        );
+        assert!(
+            compare_vectors(&expected_output, &output),
+            "try_process_outofplace_with_scratch() failed, length = {}, direction = {}",
+            len,
+            direction
+        );
+
+        // invalid buffer lengths should return errors, and leave the buffers untouched
+        let mut scratch = vec![Zero::zero(); fft.get_inplace_scratch_len()];
+        if len > 1 {
+            let mut buffer = random_signal(len - 1);
+            let original = buffer.clone();
+            assert_eq!(
+                fft.try_process_with_scratch(&mut buffer, &mut scratch),
+                Err(FftError::BufferTooSmall {
+                    expected_len: len,
+                    actual_len: len - 1
+                })
+            );
+            assert_eq!(buffer, original);
+
+            let mut buffer = random_signal(len + 1);
+            assert_eq!(
+                fft.try_process_with_scratch(&mut buffer, &mut scratch),
+                Err(FftError::BufferNotMultiple {
+                    expected_multiple: len,
+                    actual_len: len + 1
+                })
+            );
+        }
+        if scratch.len() > 0 {
+            let mut buffer = reference_input.clone();
+            assert_eq!(
+                fft.try_process_with_scratch(&mut buffer, &mut scratch[1..]),
+                Err(FftError::ScratchTooSmall {
+                    expected_len: scratch.len(),
+                    actual_len: scratch.len() - 1
+                })
+            );
+            assert_eq!(buffer, reference_input);
+        }
+        if len > 0 {
+            let mut input = reference_input.clone();
+            let mut output = vec![Zero::zero(); len * n + 1];
+            let mut scratch = vec![Zero::zero(); fft.get_outofplace_scratch_len()];
+            assert_eq!(
+                fft.try_process_outofplace_with_scratch(&mut input, &mut output, &mut scratch),
+                Err(FftError::MismatchedBuffers {
+                    input_len: len * n,
+                    output_len: len * n + 1
+                })
+            );
+        }
+    }
+}
+
+// Checks that a normalized FFT algorithm produces the output of a DFT, scaled according to `normalization`
+pub fn check_normalized_fft_algorithm<T: FftNum + Float + SampleUniform>(
+    fft: &dyn Fft<T>,
+    len: usize,
+    direction: FftDirection,
+    normalization: FftNormalization,
+) {
+    assert_eq!(
+        fft.len(),
+        len,
+        "Algorithm reported incorrect size. Expected {}, got {}",
+        len,
+        fft.len()
+    );
+    assert_eq!(
+        fft.fft_direction(),
+        direction,
+        "Algorithm reported incorrect FFT direction"
+    );
+
+    let dft = Dft::new(len, direction);
+    let scale = T::from_f64(normalization.scale_factor(len, direction)).unwrap();
+
+    // set up buffers. Use a large signal, so that a missing scale would be noticeable
+    let reference_input: Vec<Complex<T>> = random_signal(len)
+        .iter()
+        .map(|element| element * T::from_f32(10.0).unwrap())
+        .collect();
+    let mut expected_output = reference_input.clone();
+    let mut dft_scratch = vec![Zero::zero(); dft.get_inplace_scratch_len()];
+    dft.process_with_scratch(&mut expected_output, &mut dft_scratch);
+    for element in expected_output.iter_mut() {
+        *element = *element * scale;
+    }
+
+    // test process_with_scratch()
+    {
+        let mut buffer = reference_input.clone();
+        let mut scratch = vec![Zero::zero(); fft.get_inplace_scratch_len()];
+
+        fft.process_with_scratch(&mut buffer, &mut scratch);
+
+        assert!(
+            compare_vectors(&expected_output, &buffer),
+            "normalized process_with_scratch() failed, length = {}, direction = {}, normalization = {:?}",
+            len,
+            direction,
+            normalization
+        );
+    }
+
+    // test process_outofplace_with_scratch()
+    {
+        let mut input = reference_input.clone();
+        let mut output = vec![Zero::zero(); len];
+        let mut scratch = vec![Zero::zero(); fft.get_outofplace_scratch_len()];
+
+        fft.process_outofplace_with_scratch(&mut input, &mut output, &mut scratch);
+
+        assert!(
+            compare_vectors(&expected_output, &output),
+            "normalized process_outofplace_with_scratch() failed, length = {}, direction = {}, normalization = {:?}",
+            len,
+            direction,
+            normalization
+        );
+    }
+}
+
+// Checks a pruned FFT algorithm. Only the first `needed_outputs` outputs are compared against a DFT of the zero-padded input.
+// If `garbage_inputs` is true, inputs at index `nonzero_inputs` and above are filled with garbage, which the algorithm must ignore
+pub fn check_pruned_fft_algorithm<T: FftNum + Float + SampleUniform>(
+    fft: &dyn Fft<T>,
+    len: usize,
+    direction: FftDirection,
+    nonzero_inputs: usize,
+    needed_outputs: usize,
+    garbage_inputs: bool,
+) {
+    assert_eq!(
+        fft.len(),
+        len,
+        "Algorithm reported incorrect size. Expected {}, got {}",
+        len,
+        fft.len()
+    );
+    assert_eq!(
+        fft.fft_direction(),
+        direction,
+        "Algorithm reported incorrect FFT direction"
+    );
+
+    let dft = Dft::new(len, direction);
+    let garbage_value = Complex::one() * T::from_i32(100).unwrap();
+
+    // set up buffers
+    let mut reference_input = random_signal(len);
+    for item in reference_input[nonzero_inputs..].iter_mut() {
+        *item = if garbage_inputs {
+            garbage_value
+        } else {
+            Zero::zero()
+        };
+    }
+    let mut expected_output = reference_input.clone();
+    for item in expected_output[nonzero_inputs..].iter_mut() {
+        *item = Zero::zero();
+    }
+    let mut dft_scratch = vec![Zero::zero(); dft.get_inplace_scratch_len()];
+    dft.process_with_scratch(&mut expected_output, &mut dft_scratch);
+    let expected_output = &expected_output[..needed_outputs];
+
+    // test process_with_scratch()
+    {
+        let mut buffer = reference_input.clone();
+        let mut scratch = vec![garbage_value; fft.get_inplace_scratch_len()];
+
+        fft.process_with_scratch(&mut buffer, &mut scratch);
+
+        assert!(
+            needed_outputs == 0 || compare_vectors(expected_output, &buffer[..needed_outputs]),
+            "pruned process_with_scratch() failed, length = {}, nonzero_inputs = {}, needed_outputs = {}, direction = {}",
+            len,
+            nonzero_inputs,
+            needed_outputs,
+            direction
+        );
+    }
+
+    // test process_outofplace_with_scratch()
+    {
+        let mut input = reference_input.clone();
+        let mut output = vec![garbage_value; len];
+        let mut scratch = vec![garbage_value; fft.get_outofplace_scratch_len()];
+
+        fft.process_outofplace_with_scratch(&mut input, &mut output, &mut scratch);
+
+        assert!(
+            needed_outputs == 0 || compare_vectors(expected_output, &output[..needed_outputs]),
+            "pruned process_outofplace_with_scratch() failed, length = {}, nonzero_inputs = {}, needed_outputs = {}, direction = {}",
+            len,
+            nonzero_inputs,
+            needed_outputs,
+            direction
+        );
+    }
+
+    // test process_immutable()
+    {
+        let input = reference_input.clone();
+        let mut output = vec![garbage_value; len];
+        let mut scratch = vec![garbage_value; fft.get_immutable_scratch_len()];
+
+        fft.process_immutable(&input, &mut output, &mut scratch);
+
+        assert!(
+            needed_outputs == 0 || compare_vectors(expected_output, &output[..needed_outputs]),
+            "pruned process_immutable() failed, length = {}, nonzero_inputs = {}, needed_outputs = {}, direction = {}",
+            len,
+            nonzero_inputs,
+            needed_outputs,
+            direction
+        );
+        assert_eq!(
+            input, reference_input,
+            "pruned process_immutable() modified its input, length = {}, nonzero_inputs = {}, needed_outputs = {}, direction = {}",
+            len, nonzero_inputs, needed_outputs, direction
+        );
+    }
 }
 
 // A fake FFT algorithm that requests much more scratch than it needs. You can use this as an inner FFT to other algorithms to test their scratch-supplying logic
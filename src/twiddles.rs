@@ -1,37 +1,67 @@
-use crate::{common::FftNum, FftDirection};
+use crate::double_double::DoubleDouble;
+use crate::{common::FftNum, FftDirection, FftNormalization};
+use alloc::sync::Arc;
+use core::any::{Any, TypeId};
 use num_complex::Complex;
+use num_traits::{Float, FromPrimitive};
 
-pub fn compute_twiddle<T: FftNum>(
-    index: usize,
-    fft_len: usize,
-    direction: FftDirection,
-) -> Complex<T> {
-    let constant = -2f64 * std::f64::consts::PI / fft_len as f64;
-    let angle = constant * index as f64;
+/// A number type whose twiddle factors and normalization scale factors RustFFT can compute. It's implemented for every type
+/// that implements `FromPrimitive`, so every [`FftNum`] implements it.
+///
+/// Values are computed in f64, then converted, which is accurate enough for f32 and f64. The exception is
+/// [`DoubleDouble`](crate::double_double::DoubleDouble), whose values are computed in double-double arithmetic, so they're
+/// accurate to its full precision.
+pub trait TwiddlePrecision: FromPrimitive + 'static {
+    /// Returns e^(-2 * pi * i * index / fft_len)
+    fn forward_twiddle(index: usize, fft_len: usize) -> Complex<Self>;
 
-    let result = Complex {
-        re: T::from_f64(angle.cos()).unwrap(),
-        im: T::from_f64(angle.sin()).unwrap(),
-    };
+    /// Returns the same value as `normalization.scale_factor(len, direction)`, computed in this type's precision
+    fn scale_factor(normalization: FftNormalization, len: usize, direction: FftDirection) -> Self;
+}
 
-    match direction {
-        FftDirection::Forward => result,
-        FftDirection::Inverse => result.conj(),
+impl<T: FromPrimitive + 'static> TwiddlePrecision for T {
+    fn forward_twiddle(index: usize, fft_len: usize) -> Complex<Self> {
+        if TypeId::of::<T>() == TypeId::of::<DoubleDouble>() {
+            return cast_same_type(DoubleDouble::precise_forward_twiddle(index, fft_len));
+        }
+
+        let constant = -2f64 * core::f64::consts::PI / fft_len as f64;
+        let angle = constant * index as f64;
+
+        Complex {
+            re: Self::from_f64(Float::cos(angle)).unwrap(),
+            im: Self::from_f64(Float::sin(angle)).unwrap(),
+        }
     }
+
+    fn scale_factor(normalization: FftNormalization, len: usize, direction: FftDirection) -> Self {
+        if TypeId::of::<T>() == TypeId::of::<DoubleDouble>() {
+            return cast_same_type(DoubleDouble::precise_scale_factor(
+                normalization,
+                len,
+                direction,
+            ));
+        }
+
+        Self::from_f64(normalization.scale_factor(len, direction)).unwrap()
+    }
+}
+
+// Returns `value` as a `U`. `T` and `U` must be the same type
+fn cast_same_type<T: 'static, U: 'static>(value: T) -> U {
+    let mut value = Some(value);
+    (&mut value as &mut dyn Any)
+        .downcast_mut::<Option<U>>()
+        .and_then(Option::take)
+        .unwrap()
 }
 
-pub fn compute_twiddle_floatindex<T: FftNum>(
-    index: f64,
+pub fn compute_twiddle<T: FftNum>(
+    index: usize,
     fft_len: usize,
     direction: FftDirection,
 ) -> Complex<T> {
-    let constant = -2f64 * std::f64::consts::PI / fft_len as f64;
-    let angle = constant * index;
-
-    let result = Complex {
-        re: T::from_f64(angle.cos()).unwrap(),
-        im: T::from_f64(angle.sin()).unwrap(),
-    };
+    let result = T::forward_twiddle(index, fft_len);
 
     match direction {
         FftDirection::Forward => result,
@@ -39,6 +69,15 @@ pub fn compute_twiddle_floatindex<T: FftNum>(
     }
 }
 
+// Returns the same value as `normalization.scale_factor(len, direction)`, computed in the precision that `compute_twiddle` uses for `T`
+pub fn compute_scale_factor<T: FftNum>(
+    normalization: FftNormalization,
+    len: usize,
+    direction: FftDirection,
+) -> T {
+    T::scale_factor(normalization, len, direction)
+}
+
 pub fn rotate_90<T: FftNum>(value: Complex<T>, direction: FftDirection) -> Complex<T> {
     match direction {
         FftDirection::Forward => Complex {
@@ -52,6 +91,107 @@ pub fn rotate_90<T: FftNum>(value: Complex<T>, direction: FftDirection) -> Compl
     }
 }
 
+// The twiddle factors of a mixed radix FFT of size `width * height`, where element `(x, y)` is `compute_twiddle(x * y, width * height, direction)`.
+// The table may be shared with other FFT instances. Element `(x, y)` is stored at `x * row_stride + y * column_stride`,
+// so a view can pick the twiddles of a smaller FFT out of the table of a larger one: If the larger table is `(width * a) x (height * b)`,
+// element `(x, y)` of the smaller table equals element `(x * a, y * b)` of the larger one
+#[derive(Clone)]
+pub(crate) struct TwiddleView<T> {
+    table: Arc<[Complex<T>]>,
+    width: usize,
+    height: usize,
+    row_stride: usize,
+    column_stride: usize,
+}
+impl<T: FftNum> TwiddleView<T> {
+    // Views a table that's exactly `width x height`
+    pub fn new(table: Arc<[Complex<T>]>, width: usize, height: usize) -> Self {
+        Self::strided(table, width, height, width, height)
+    }
+
+    // Views the `width x height` twiddles inside `table`, which is a `table_width x table_height` table.
+    // The table's width and height must be multiples of our width and height
+    pub fn strided(
+        table: Arc<[Complex<T>]>,
+        table_width: usize,
+        table_height: usize,
+        width: usize,
+        height: usize,
+    ) -> Self {
+        assert_eq!(table.len(), table_width * table_height);
+        assert_eq!((table_width % width, table_height % height), (0, 0));
+        Self {
+            table,
+            width,
+            height,
+            row_stride: table_width / width * table_height,
+            column_stride: table_height / height,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    // Returns the distance between rows `x` and `x + 1` in `table()`. Only the AVX algorithms read the table directly
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
+    pub fn row_stride(&self) -> usize {
+        self.row_stride
+    }
+    // Returns the distance between the elements of a row in `table()`
+    #[cfg(any(test, all(target_arch = "x86_64", feature = "avx")))]
+    pub fn column_stride(&self) -> usize {
+        self.column_stride
+    }
+    #[cfg(all(target_arch = "x86_64", feature = "avx"))]
+    pub fn table(&self) -> &[Complex<T>] {
+        &self.table
+    }
+
+    // Returns the whole `width x height` table as a slice, if it's stored contiguously
+    pub fn as_slice(&self) -> Option<&[Complex<T>]> {
+        if self.column_stride == 1 && self.row_stride == self.height {
+            Some(&self.table[..self.width * self.height])
+        } else {
+            None
+        }
+    }
+
+    // Returns the twiddles `(x, 0..height)`
+    pub fn row(&self, x: usize) -> impl Iterator<Item = &Complex<T>> {
+        self.table[x * self.row_stride..]
+            .iter()
+            .step_by(self.column_stride)
+            .take(self.height)
+    }
+
+    // Multiplies each element of `data`, a `width x height` array, by the corresponding twiddle factor
+    pub fn apply(&self, data: &mut [Complex<T>]) {
+        if let Some(twiddles) = self.as_slice() {
+            for (element, twiddle) in data.iter_mut().zip(twiddles) {
+                *element = *element * twiddle;
+            }
+        } else {
+            for (x, row) in data.chunks_exact_mut(self.height).enumerate() {
+                for (element, twiddle) in row.iter_mut().zip(self.row(x)) {
+                    *element = *element * twiddle;
+                }
+            }
+        }
+    }
+
+    // Returns a contiguous copy of this view, with every twiddle multiplied by `scale`. The copy isn't shared with any other instance
+    pub fn scaled(&self, scale: T) -> Self {
+        let table = (0..self.width)
+            .flat_map(|x| self.row(x))
+            .map(|twiddle| *twiddle * scale)
+            .collect();
+        Self::new(table, self.width, self.height)
+    }
+}
+
 #[cfg(test)]
 mod unit_tests {
     use super::*;
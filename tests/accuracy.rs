@@ -8,11 +8,15 @@ use std::sync::Arc;
 
 use num_traits::Float;
 use rustfft::{
-    algorithm::{BluesteinsAlgorithm, Radix4},
+    algorithm::{BluesteinsAlgorithm, Dft, Radix4},
+    double_double::DoubleDouble,
     num_complex::Complex,
     Fft, FftNum, FftPlanner,
 };
-use rustfft::{num_traits::Zero, FftDirection};
+use rustfft::{
+    num_traits::{Signed, Zero},
+    FftDirection, FftNormalization,
+};
 
 use rand::distributions::{uniform::SampleUniform, Distribution, Uniform};
 use rand::{rngs::StdRng, SeedableRng};
@@ -167,3 +171,182 @@ fn test_planned_fft_inverse_f64() {
         assert!(fft_matches_control(control, &signal), "length = {}", len);
     }
 }
+
+/// Integration test that verifies pruned FFTs match the direct Dft calculation of a zero-padded signal,
+/// in the outputs that were requested
+#[test]
+fn test_planned_fft_pruned_f32() {
+    let direction = FftDirection::Forward;
+    let cache: ControlCache<f32> = ControlCache::new(TEST_MAX, direction);
+    let mut planner = FftPlanner::new();
+
+    for &len in &[64, 100, 256, 360, 512, 1000] {
+        let control = cache.plan_fft(len);
+        for &nonzero_inputs in &[1, 10, len / 4, len] {
+            for &needed_outputs in &[1, 10, len / 4, len] {
+                let fft = planner.plan_fft_pruned(len, direction, nonzero_inputs, needed_outputs);
+                assert_eq!(fft.len(), len);
+                assert_eq!(fft.fft_direction(), direction);
+
+                let mut control_buffer = random_signal(len);
+                for element in control_buffer[nonzero_inputs..].iter_mut() {
+                    *element = Zero::zero();
+                }
+                let mut test_buffer = control_buffer.clone();
+
+                control.process(&mut control_buffer);
+                fft.process(&mut test_buffer);
+
+                assert!(
+                    compare_vectors(
+                        &test_buffer[..needed_outputs],
+                        &control_buffer[..needed_outputs]
+                    ),
+                    "length = {}, nonzero_inputs = {}, needed_outputs = {}",
+                    len,
+                    nonzero_inputs,
+                    needed_outputs
+                );
+            }
+        }
+    }
+}
+
+/// Integration test that verifies a unitary forward FFT followed by a unitary inverse FFT reproduces the original signal,
+/// with no manual scaling required
+#[test]
+fn test_planned_fft_normalized_roundtrip() {
+    let mut planner = FftPlanner::<f64>::new();
+
+    for &len in &[1, 2, 7, 64, 100, 360, 1031, 1201, 4096] {
+        let forward =
+            planner.plan_fft_normalized(len, FftDirection::Forward, FftNormalization::Unitary);
+        let inverse =
+            planner.plan_fft_normalized(len, FftDirection::Inverse, FftNormalization::Unitary);
+
+        let signal = random_signal(len);
+        let mut buffer = signal.clone();
+        forward.process(&mut buffer);
+        inverse.process(&mut buffer);
+
+        assert!(compare_vectors(&signal, &buffer), "length = {}", len);
+
+        // The unitary FFT preserves energy
+        let mut spectrum = signal.clone();
+        forward.process(&mut spectrum);
+        let signal_energy: f64 = signal.iter().map(|c| c.norm_sqr()).sum();
+        let spectrum_energy: f64 = spectrum.iter().map(|c| c.norm_sqr()).sum();
+        assert!(
+            (signal_energy - spectrum_energy).abs() < 1e-9 * signal_energy,
+            "length = {}, signal energy = {}, spectrum energy = {}",
+            len,
+            signal_energy,
+            spectrum_energy
+        );
+    }
+}
+
+/// Integration test that verifies process_immutable matches the in-place FFT, and leaves its input untouched
+#[test]
+fn test_planned_fft_immutable_f32() {
+    let direction = FftDirection::Forward;
+    let cache: ControlCache<f32> = ControlCache::new(TEST_MAX, direction);
+    let mut planner = FftPlanner::new();
+
+    for &len in &[1, 7, 64, 100, 256, 360, 512, 997, 1000] {
+        let control = cache.plan_fft(len);
+        let fft = planner.plan_fft(len, direction);
+
+        // process two FFTs at once, to make sure multi-chunk buffers work too
+        let input = random_signal(len * 2);
+        let mut control_buffer = input.clone();
+        control.process(&mut control_buffer);
+
+        let mut output = vec![Zero::zero(); len * 2];
+        let mut scratch = vec![Zero::zero(); fft.get_immutable_scratch_len()];
+        fft.process_immutable(&input, &mut output, &mut scratch);
+
+        assert!(
+            compare_vectors(&output, &control_buffer),
+            "length = {}",
+            len
+        );
+        assert_eq!(input, random_signal(len * 2), "length = {}", len);
+    }
+}
+
+/// Integration test that verifies double-double FFTs are accurate to the full precision of the type, rather than to the
+/// precision of the f64 twiddle factors
+#[test]
+fn test_planned_fft_double_double() {
+    let mut planner = FftPlanner::<DoubleDouble>::new();
+
+    for &len in &[1, 2, 8, 12, 64, 97, 100, 360, 1031, 1201] {
+        for &direction in &[FftDirection::Forward, FftDirection::Inverse] {
+            let control = Dft::new(len, direction);
+            let fft = planner.plan_fft(len, direction);
+
+            let signal: Vec<Complex<DoubleDouble>> = random_signal::<f64>(len)
+                .iter()
+                .map(|c| Complex::new(DoubleDouble::from(c.re), DoubleDouble::from(c.im)))
+                .collect();
+            let mut control_buffer = signal.clone();
+            control.process(&mut control_buffer);
+            let mut buffer = signal;
+            fft.process(&mut buffer);
+
+            let mut error = DoubleDouble::zero();
+            let mut magnitude = DoubleDouble::zero();
+            for (actual, expected) in buffer.iter().zip(control_buffer.iter()) {
+                let difference = actual - expected;
+                error = error + difference.re.abs() + difference.im.abs();
+                magnitude = magnitude + expected.re.abs() + expected.im.abs();
+            }
+            assert!(
+                error.hi() <= 1e-28 * magnitude.hi(),
+                "length = {}, direction = {}, relative error = {}",
+                len,
+                direction,
+                error.hi() / magnitude.hi()
+            );
+        }
+    }
+}
+
+/// Integration test that verifies process_split matches the in-place FFT on the equivalent interleaved data
+#[test]
+fn test_planned_fft_split() {
+    fn check_split<T: FftNum + Float + SampleUniform>() {
+        let direction = FftDirection::Inverse;
+        let cache: ControlCache<T> = ControlCache::new(TEST_MAX, direction);
+        let mut planner = FftPlanner::new();
+
+        for &len in &[1, 7, 64, 100, 256, 360, 997, 1000] {
+            let control = cache.plan_fft(len);
+            let fft = planner.plan_fft(len, direction);
+
+            // process two FFTs at once, to make sure multi-chunk buffers work too
+            let input = random_signal::<T>(len * 2);
+            let mut control_buffer = input.clone();
+            control.process(&mut control_buffer);
+
+            let mut re: Vec<T> = input.iter().map(|c| c.re).collect();
+            let mut im: Vec<T> = input.iter().map(|c| c.im).collect();
+            let mut scratch = vec![Zero::zero(); fft.get_split_scratch_len()];
+            fft.process_split(&mut re, &mut im, &mut scratch);
+
+            let output: Vec<Complex<T>> = re
+                .iter()
+                .zip(im.iter())
+                .map(|(re, im)| Complex::new(*re, *im))
+                .collect();
+            assert!(
+                compare_vectors(&output, &control_buffer),
+                "length = {}",
+                len
+            );
+        }
+    }
+    check_split::<f32>();
+    check_split::<f64>();
+}